@@ -0,0 +1,61 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+// Measure the insertion and removal of columns in tall grids. The insertion
+// is compared to the former implementation, which took the values from the
+// front of the column one by one and therefore shifted the whole column for
+// each row; the removal didn't change.
+//
+// Run it with `cargo bench --bench columns`.
+
+use std::time::{Duration, Instant};
+use ingrid::{Size, Grid, size};
+
+const HEIGHTS: [usize; 3] = [1_000, 10_000, 100_000];
+const WIDTH: usize = 64;
+const COLUMNS: u32 = 16;
+
+// The former implementation is much slower for tall grids, so it's measured
+// with fewer columns.
+const NAIVE_COLUMNS: u32 = 2;
+
+fn measure<F: FnMut()>(columns: u32, mut function: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..columns {
+        function();
+    }
+
+    start.elapsed() / columns
+}
+
+// Insert a column in rows like the former implementation of insert_column().
+fn insert_column_naive(rows: &mut [Vec<u32>], index: usize, mut column: Vec<u32>) {
+    for row in rows.iter_mut() {
+        row.insert(index, column.remove(0));
+    }
+}
+
+fn main() {
+    for &height in HEIGHTS.iter() {
+        let mut rows = vec![vec![0u32; WIDTH]; height];
+        let naive = measure(NAIVE_COLUMNS, || {
+            insert_column_naive(&mut rows, WIDTH / 2, vec![1; height]);
+        });
+
+        let mut grid = Grid::with_size(size!(WIDTH, height), 0u32);
+        let insertion = measure(COLUMNS, || {
+            grid.insert_column(WIDTH / 2, vec![1; height]);
+        });
+        let removal = measure(COLUMNS, || {
+            grid.remove_column(WIDTH / 2);
+        });
+
+        println!("{:>7} rows: insert_column {:>10.2?} (formerly {:>10.2?}), remove_column {:>10.2?}",
+                 height, insertion, naive, removal);
+    }
+}
@@ -0,0 +1,503 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::grid::Grid;
+use crate::row::Row;
+use crate::column::Column;
+use crate::numeric::Numeric;
+
+/// The extrema of a line of a grid
+///
+/// This structure holds the minimum and the maximum elements of a row or a
+/// column of a grid, along with their coordinates. It's returned by
+/// `Grid::row_minmax()` and `Grid::column_minmax()`.
+///
+/// When several elements are equal, the first one is retained.
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MinMax<'a, T> {
+    pub min: (Coordinate, &'a T),
+    pub max: (Coordinate, &'a T)
+}
+
+impl<T: Clone> Grid<T> {
+
+    /// Reduce each row to a value.
+    ///
+    /// This method calls a function on each row of the grid, from top to
+    /// bottom, and returns the values it computes. It expresses per-row
+    /// reductions such as checksums, emptiness flags or custom statistics
+    /// without looping over the indices of the rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function reducing a row to a value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// let sums = grid.aggregate_rows(|row| row.iterator().sum::<i32>());
+    /// assert_eq!(sums, vec![6, 15]);
+    /// ```
+    ///
+    pub fn aggregate_rows<'a, U, F>(&'a self, function: F) -> Vec<U>
+        where F: FnMut(Row<'a, T>) -> U
+    {
+        (0..self.size().height).map(|index| self.row(index)).map(function).collect()
+    }
+
+    /// Reduce each column to a value.
+    ///
+    /// This method calls a function on each column of the grid, from left to
+    /// right, and returns the values it computes. It expresses per-column
+    /// reductions such as checksums, emptiness flags or custom statistics
+    /// without looping over the indices of the columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function reducing a column to a value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// let sums = grid.aggregate_columns(|column| column.iterator().sum::<i32>());
+    /// assert_eq!(sums, vec![5, 7, 9]);
+    /// ```
+    ///
+    pub fn aggregate_columns<'a, U, F>(&'a self, function: F) -> Vec<U>
+        where F: FnMut(Column<'a, T>) -> U
+    {
+        (0..self.size().width).map(|index| self.column(index)).map(function).collect()
+    }
+}
+
+impl<T: Clone + PartialOrd> Grid<T> {
+
+    /// Return the extrema of each row.
+    ///
+    /// This method returns the minimum and the maximum elements of each row
+    /// of the grid, with their coordinates, from top to bottom. It returns no
+    /// extrema if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![3, 1, 2],
+    ///                                 vec![4, 6, 5]]);
+    ///
+    /// let extrema = grid.row_minmax();
+    /// assert_eq!(extrema[0].min, (coord!(1, 0), &1));
+    /// assert_eq!(extrema[0].max, (coord!(0, 0), &3));
+    /// assert_eq!(extrema[1].min, (coord!(0, 1), &4));
+    /// assert_eq!(extrema[1].max, (coord!(1, 1), &6));
+    /// ```
+    ///
+    pub fn row_minmax(&self) -> Vec<MinMax<'_, T>> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        self.row_vectors().iter().enumerate().map(|(y, row)| {
+            minmax(row.iter().enumerate().map(|(x, value)| (Coordinate::new(x, y), value)))
+        }).collect()
+    }
+
+    /// Return the extrema of each column.
+    ///
+    /// This method returns the minimum and the maximum elements of each column
+    /// of the grid, with their coordinates, from left to right. It returns no
+    /// extrema if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![3, 1],
+    ///                                 vec![4, 6],
+    ///                                 vec![2, 5]]);
+    ///
+    /// let extrema = grid.column_minmax();
+    /// assert_eq!(extrema[0].min, (coord!(0, 2), &2));
+    /// assert_eq!(extrema[0].max, (coord!(0, 1), &4));
+    /// assert_eq!(extrema[1].min, (coord!(1, 0), &1));
+    /// assert_eq!(extrema[1].max, (coord!(1, 1), &6));
+    /// ```
+    ///
+    pub fn column_minmax(&self) -> Vec<MinMax<'_, T>> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        let rows = self.row_vectors();
+        (0..self.size().width).map(|x| {
+            minmax(rows.iter().enumerate().map(|(y, row)| (Coordinate::new(x, y), &row[x])))
+        }).collect()
+    }
+}
+
+impl<T: Clone + Ord> Grid<T> {
+
+    /// Return the number of occurrences of each value.
+    ///
+    /// This method returns a map of the distinct values of the grid to the
+    /// number of elements with that value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec!['a', 'b'],
+    ///                                 vec!['b', 'b']]);
+    ///
+    /// let frequencies = grid.frequencies();
+    /// assert_eq!(frequencies[&'a'], 1);
+    /// assert_eq!(frequencies[&'b'], 3);
+    /// ```
+    ///
+    pub fn frequencies(&self) -> BTreeMap<&T, usize> {
+        let mut frequencies = BTreeMap::new();
+        for value in self.row_vectors().iter().flatten() {
+            *frequencies.entry(value).or_insert(0) += 1;
+        }
+
+        frequencies
+    }
+
+    /// Return the distinct values.
+    ///
+    /// This method returns the set of the distinct values of the grid, to
+    /// enumerate the kinds of tiles of a map for instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec!['#', '.', '#'],
+    ///                                 vec!['.', '.', '~']]);
+    ///
+    /// let values = grid.unique_values();
+    /// assert_eq!(values.into_iter().collect::<Vec<_>>(), vec![&'#', &'.', &'~']);
+    /// ```
+    ///
+    pub fn unique_values(&self) -> BTreeSet<&T> {
+        self.row_vectors().iter().flatten().collect()
+    }
+
+    /// Return the most frequent value.
+    ///
+    /// This method returns the value with the most occurrences in the grid,
+    /// or `None` if the grid is empty. If several values are equally
+    /// frequent, the smallest one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![3, 1, 3],
+    ///                                 vec![1, 2, 1]]);
+    ///
+    /// assert_eq!(grid.mode(), Some(&1));
+    /// ```
+    ///
+    pub fn mode(&self) -> Option<&T> {
+        let mut mode = None;
+        for (value, count) in self.frequencies() {
+            match mode {
+                Some((_, most)) if count <= most => {},
+                _ => mode = Some((value, count))
+            }
+        }
+
+        mode.map(|(value, _)| value)
+    }
+}
+
+impl<T: Numeric> Grid<T> {
+
+    /// Return a percentile of the elements.
+    ///
+    /// This method returns the value below which a given percentage of the
+    /// elements of the grid fall, or `None` if the grid is empty. The value
+    /// is interpolated linearly between the two closest elements, like the
+    /// default method of most statistics packages.
+    ///
+    /// # Arguments
+    ///
+    /// * `percentage` - The percentage, between 0 and 100
+    ///
+    /// # Panics
+    ///
+    /// It panics if the percentage isn't between 0 and 100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.percentile(0.0), Some(1.0));
+    /// assert_eq!(grid.percentile(50.0), Some(3.5));
+    /// assert_eq!(grid.percentile(100.0), Some(6.0));
+    /// ```
+    ///
+    pub fn percentile(&self, percentage: f64) -> Option<f64> {
+        assert!((0.0..=100.0).contains(&percentage), "percentile out of range");
+
+        self.quantiles(&[percentage / 100.0]).map(|quantiles| quantiles[0])
+    }
+
+    /// Return quantiles of the elements.
+    ///
+    /// This method returns the values below which given fractions of the
+    /// elements of the grid fall, or `None` if the grid is empty. The
+    /// elements are sorted once for all the fractions; the values are
+    /// interpolated like with `percentile()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `fractions` - The fractions, between 0 and 1
+    ///
+    /// # Panics
+    ///
+    /// It panics if a fraction isn't between 0 and 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![10, 40],
+    ///                                 vec![20, 30],
+    ///                                 vec![50, 0]]);
+    ///
+    /// assert_eq!(grid.quantiles(&[0.2, 0.5, 0.9]), Some(vec![10.0, 25.0, 45.0]));
+    /// ```
+    ///
+    pub fn quantiles(&self, fractions: &[f64]) -> Option<Vec<f64>> {
+        assert!(fractions.iter().all(|fraction| (0.0..=1.0).contains(fraction)), "quantile out of range");
+
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut values = self.row_vectors().iter().flatten().map(|value| value.to_f64()).collect::<Vec<_>>();
+        values.sort_by(f64::total_cmp);
+
+        let last = (values.len() - 1) as f64;
+        Some(fractions.iter().map(|fraction| {
+            let position = fraction * last;
+            let index = position as usize;
+
+            // Interpolating from or toward an infinity would give NaN; the
+            // infinite endpoint is returned instead.
+            let (value, fraction) = (values[index], position - index as f64);
+            match values.get(index + 1) {
+                Some(&next) if fraction > 0.0 && value.is_finite() => {
+                    if next.is_finite() { value + (next - value) * fraction } else { next }
+                },
+                _ => value
+            }
+        }).collect())
+    }
+}
+
+// Compute the extrema of a non-empty sequence of elements, retaining the first
+// of equal elements.
+fn minmax<'a, T, I>(mut elements: I) -> MinMax<'a, T>
+    where T: PartialOrd, I: Iterator<Item = (Coordinate, &'a T)>
+{
+    let first = elements.next().unwrap();
+
+    elements.fold(MinMax { min: first, max: first }, |mut extrema, element| {
+        if element.1 < extrema.min.1 {
+            extrema.min = element;
+        }
+        if element.1 > extrema.max.1 {
+            extrema.max = element;
+        }
+
+        extrema
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_aggregate_rows() {
+        let grid = Grid::from_rows(vec![vec![0, 0, 0],
+                                        vec![0, 7, 0],
+                                        vec![1, 2, 3]]);
+
+        let empty = grid.aggregate_rows(|row| row.iterator().all(|&value| value == 0));
+        assert_eq!(empty, vec![true, false, false]);
+
+        let indices = grid.aggregate_rows(|row| row.index);
+        assert_eq!(indices, vec![0, 1, 2]);
+
+        // The rows outlive the closure.
+        let rows = grid.aggregate_rows(|row| row);
+        assert_eq!(rows[2].values(), vec![&1, &2, &3]);
+
+        assert_eq!(Grid::with_size(size!(0, 2), 0).aggregate_rows(|row| row.length()), vec![0, 0]);
+        assert!(Grid::<u8>::zero().aggregate_rows(|row| row.index).is_empty());
+    }
+
+    #[test]
+    fn grid_aggregate_columns() {
+        let grid = Grid::from_rows(vec![vec![0, 0, 1],
+                                        vec![0, 7, 2],
+                                        vec![0, 0, 3]]);
+
+        let empty = grid.aggregate_columns(|column| column.iterator().all(|&value| value == 0));
+        assert_eq!(empty, vec![true, false, false]);
+
+        let maximums = grid.aggregate_columns(|column| column.iterator().max());
+        assert_eq!(maximums, vec![Some(&0), Some(&7), Some(&3)]);
+
+        assert_eq!(Grid::with_size(size!(2, 0), 0).aggregate_columns(|column| column.length()), vec![0, 0]);
+        assert!(Grid::<u8>::zero().aggregate_columns(|column| column.index).is_empty());
+    }
+
+    #[test]
+    fn grid_row_minmax() {
+        let grid = Grid::from_rows(vec![vec![2, 1, 3, 1],
+                                        vec![5, 5, 5, 5],
+                                        vec![0, 9, 4, 9]]);
+
+        let extrema = grid.row_minmax();
+        assert_eq!(extrema.len(), 3);
+        assert_eq!(extrema[0], MinMax { min: (coord!(1, 0), &1), max: (coord!(2, 0), &3) });
+        assert_eq!(extrema[1], MinMax { min: (coord!(0, 1), &5), max: (coord!(0, 1), &5) });
+        assert_eq!(extrema[2], MinMax { min: (coord!(0, 2), &0), max: (coord!(1, 2), &9) });
+
+        assert!(Grid::<u8>::zero().row_minmax().is_empty());
+    }
+
+    #[test]
+    fn grid_column_minmax() {
+        let grid = Grid::from_rows(vec![vec![2.0, 1.0],
+                                        vec![0.5, 3.0],
+                                        vec![7.0, 3.0]]);
+
+        let extrema = grid.column_minmax();
+        assert_eq!(extrema.len(), 2);
+        assert_eq!(extrema[0], MinMax { min: (coord!(0, 1), &0.5), max: (coord!(0, 2), &7.0) });
+        assert_eq!(extrema[1], MinMax { min: (coord!(1, 0), &1.0), max: (coord!(1, 1), &3.0) });
+
+        assert!(Grid::<f32>::zero().column_minmax().is_empty());
+    }
+
+    #[test]
+    fn grid_frequencies() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 2],
+                                        vec![3, 3, 3]]);
+
+        let frequencies = grid.frequencies();
+        assert_eq!(frequencies.len(), 3);
+        assert_eq!(frequencies.into_iter().collect::<Vec<_>>(), vec![(&1, 1), (&2, 2), (&3, 3)]);
+
+        assert!(Grid::<u8>::zero().frequencies().is_empty());
+    }
+
+    #[test]
+    fn grid_mode() {
+        let grid = Grid::from_rows(vec![vec!['b', 'a', 'c'],
+                                        vec!['c', 'b', 'a']]);
+        assert_eq!(grid.mode(), Some(&'a'));
+
+        let grid = Grid::from_rows(vec![vec![5, 7, 7]]);
+        assert_eq!(grid.mode(), Some(&7));
+
+        assert_eq!(Grid::<u8>::zero().mode(), None);
+    }
+
+    #[test]
+    fn grid_unique_values() {
+        let grid = Grid::from_rows(vec![vec![3, 1, 3],
+                                        vec![2, 1, 3]]);
+
+        let values = grid.unique_values();
+        assert_eq!(values.len(), 3);
+        assert_eq!(values.into_iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+
+        assert!(Grid::<u8>::zero().unique_values().is_empty());
+    }
+
+    #[test]
+    fn grid_percentile() {
+        let grid = Grid::from_rows(vec![vec![9.0, 1.0],
+                                        vec![5.0, 3.0],
+                                        vec![7.0, 11.0]]);
+
+        assert_eq!(grid.percentile(0.0), Some(1.0));
+        assert_eq!(grid.percentile(20.0), Some(3.0));
+        assert_eq!(grid.percentile(30.0), Some(4.0));
+        assert_eq!(grid.percentile(100.0), Some(11.0));
+
+        let grid = Grid::with_size(size!(1, 1), 42u8);
+        assert_eq!(grid.percentile(95.0), Some(42.0));
+
+        assert_eq!(Grid::<f32>::zero().percentile(50.0), None);
+
+        let grid = Grid::from_rows(vec![vec![f64::NEG_INFINITY, 0.0]]);
+        assert_eq!(grid.percentile(50.0), Some(f64::NEG_INFINITY));
+        assert_eq!(grid.percentile(100.0), Some(0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "percentile out of range")]
+    fn grid_percentile_out_of_range() {
+        Grid::with_size(size!(2, 2), 0).percentile(101.0);
+    }
+
+    #[test]
+    fn grid_quantiles() {
+        let grid = Grid::from_rows(vec![vec![4, 3, 2, 1, 0]]);
+
+        assert_eq!(grid.quantiles(&[0.0, 0.25, 0.5, 0.75, 1.0]), Some(vec![0.0, 1.0, 2.0, 3.0, 4.0]));
+        assert_eq!(grid.quantiles(&[0.125]), Some(vec![0.5]));
+        assert_eq!(grid.quantiles(&[]), Some(vec![]));
+        assert_eq!(Grid::<i32>::zero().quantiles(&[0.5]), None);
+
+        let grid = Grid::from_rows(vec![vec![1.0, f64::INFINITY, f64::INFINITY]]);
+        assert_eq!(grid.quantiles(&[0.5, 0.75, 1.0]), Some(vec![f64::INFINITY; 3]));
+
+        let grid = Grid::from_rows(vec![vec![1.0, 2.0, f64::INFINITY]]);
+        assert_eq!(grid.quantiles(&[0.5, 0.75]), Some(vec![2.0, f64::INFINITY]));
+    }
+
+    #[test]
+    #[should_panic(expected = "quantile out of range")]
+    fn grid_quantiles_out_of_range() {
+        Grid::with_size(size!(2, 2), 0).quantiles(&[0.5, -0.1]);
+    }
+}
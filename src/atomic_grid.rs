@@ -0,0 +1,371 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::sync::atomic::{self, Ordering};
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::grid::Grid;
+
+/// A grid of atomic elements
+///
+/// This structure is a grid of atomic integers or booleans that can be
+/// updated from multiple threads through a shared reference, without locks.
+/// It lets parallel algorithms accumulate counts or raise flags into a shared
+/// grid; once done, it's converted back into a regular grid of plain values.
+///
+/// It's available for all atomic types of the `core::sync::atomic` module
+/// that the target supports. Integers support the arithmetic and bitwise
+/// operations (`fetch_add()`, `fetch_max()`, ...), booleans support the
+/// logical ones (`fetch_or()`, `fetch_and()`, ...), and both support
+/// `load()`, `store()`, `swap()` and `compare_exchange()`.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Grid, AtomicGrid, coord, size};
+/// # use std::sync::atomic::{AtomicU32, Ordering};
+/// #
+/// let grid = AtomicGrid::<AtomicU32>::new(size!(2, 2));
+///
+/// std::thread::scope(|scope| {
+///     for _ in 0..4 {
+///         scope.spawn(|| grid.fetch_add(coord!(1, 0), 1, Ordering::Relaxed));
+///     }
+/// });
+///
+/// assert_eq!(grid.into_grid(), Grid::from_rows(vec![vec![0, 4],
+///                                                   vec![0, 0]]));
+/// ```
+///
+#[derive(Debug)]
+pub struct AtomicGrid<A> {
+    size: Size,
+    elements: Vec<A>
+}
+
+impl<A> AtomicGrid<A> {
+
+    /// Return the size of the grid.
+    ///
+    /// This method returns the size of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, AtomicGrid, size};
+    /// # use std::sync::atomic::AtomicBool;
+    /// #
+    /// let grid = AtomicGrid::<AtomicBool>::new(size!(2, 3));
+    /// assert_eq!(grid.size(), size!(2, 3));
+    /// ```
+    ///
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Return a reference to an atomic element of the grid.
+    ///
+    /// This method returns a reference to an atomic element of the grid from
+    /// its coordinate, to perform operations that this structure doesn't
+    /// forward.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, AtomicGrid, coord, size};
+    /// # use std::sync::atomic::{AtomicU8, Ordering};
+    /// #
+    /// let grid = AtomicGrid::<AtomicU8>::new(size!(2, 2));
+    ///
+    /// grid.value(coord!(0, 1)).fetch_xor(0b101, Ordering::Relaxed);
+    /// assert_eq!(grid.load(coord!(0, 1), Ordering::Relaxed), 0b101);
+    /// ```
+    ///
+    pub fn value(&self, coordinate: Coordinate) -> &A {
+        &self.elements[coordinate.to_index(self.size)]
+    }
+}
+
+// Implement the operations that are common to all atomic types.
+macro_rules! atomic_grid {
+    ($cfg:meta, $atomic:ident, $type:ty) => {
+        #[cfg($cfg)]
+        impl AtomicGrid<atomic::$atomic> {
+            /// Construct a grid filled with the default value.
+            pub fn new(size: Size) -> Self {
+                Self::with_value(size, <$type>::default())
+            }
+
+            /// Construct a grid filled with a given value.
+            pub fn with_value(size: Size, value: $type) -> Self {
+                let elements = (0..size.area()).map(|_| atomic::$atomic::new(value)).collect();
+                AtomicGrid { size, elements }
+            }
+
+            /// Load the value of an element; it panics if the coordinate is out of bounds.
+            pub fn load(&self, coordinate: Coordinate, order: Ordering) -> $type {
+                self.value(coordinate).load(order)
+            }
+
+            /// Store the value of an element; it panics if the coordinate is out of bounds.
+            pub fn store(&self, coordinate: Coordinate, value: $type, order: Ordering) {
+                self.value(coordinate).store(value, order)
+            }
+
+            /// Replace the value of an element and return the previous value; it
+            /// panics if the coordinate is out of bounds.
+            pub fn swap(&self, coordinate: Coordinate, value: $type, order: Ordering) -> $type {
+                self.value(coordinate).swap(value, order)
+            }
+
+            /// Replace the value of an element if it's equal to the current
+            /// value; it panics if the coordinate is out of bounds.
+            pub fn compare_exchange(&self, coordinate: Coordinate, current: $type, new: $type,
+                                    success: Ordering, failure: Ordering) -> Result<$type, $type> {
+                self.value(coordinate).compare_exchange(current, new, success, failure)
+            }
+
+            /// Convert the grid into a grid of plain values.
+            pub fn into_grid(self) -> Grid<$type> {
+                let width = self.size.width;
+                let mut elements = self.elements.into_iter().map(atomic::$atomic::into_inner);
+
+                let rows = (0..self.size.height)
+                    .map(|_| elements.by_ref().take(width).collect())
+                    .collect();
+
+                Grid::from_row_vectors(width, rows)
+            }
+        }
+
+        #[cfg($cfg)]
+        impl From<Grid<$type>> for AtomicGrid<atomic::$atomic> {
+            fn from(grid: Grid<$type>) -> Self {
+                let size = grid.size();
+                let elements = grid.into_row_vectors().into_iter()
+                    .flatten()
+                    .map(atomic::$atomic::new)
+                    .collect();
+
+                AtomicGrid { size, elements }
+            }
+        }
+    };
+}
+
+// Implement the operations of the atomic integer types.
+macro_rules! atomic_grid_integer {
+    ($cfg:meta, $atomic:ident, $type:ty) => {
+        atomic_grid!($cfg, $atomic, $type);
+
+        #[cfg($cfg)]
+        impl AtomicGrid<atomic::$atomic> {
+            /// Add to an element and return the previous value; it panics if
+            /// the coordinate is out of bounds.
+            pub fn fetch_add(&self, coordinate: Coordinate, value: $type, order: Ordering) -> $type {
+                self.value(coordinate).fetch_add(value, order)
+            }
+
+            /// Subtract from an element and return the previous value; it
+            /// panics if the coordinate is out of bounds.
+            pub fn fetch_sub(&self, coordinate: Coordinate, value: $type, order: Ordering) -> $type {
+                self.value(coordinate).fetch_sub(value, order)
+            }
+
+            /// Store the maximum of an element and a value, and return the
+            /// previous value; it panics if the coordinate is out of bounds.
+            pub fn fetch_max(&self, coordinate: Coordinate, value: $type, order: Ordering) -> $type {
+                self.value(coordinate).fetch_max(value, order)
+            }
+
+            /// Store the minimum of an element and a value, and return the
+            /// previous value; it panics if the coordinate is out of bounds.
+            pub fn fetch_min(&self, coordinate: Coordinate, value: $type, order: Ordering) -> $type {
+                self.value(coordinate).fetch_min(value, order)
+            }
+
+            /// Bitwise "or" an element with a value, and return the previous
+            /// value; it panics if the coordinate is out of bounds.
+            pub fn fetch_or(&self, coordinate: Coordinate, value: $type, order: Ordering) -> $type {
+                self.value(coordinate).fetch_or(value, order)
+            }
+
+            /// Bitwise "and" an element with a value, and return the previous
+            /// value; it panics if the coordinate is out of bounds.
+            pub fn fetch_and(&self, coordinate: Coordinate, value: $type, order: Ordering) -> $type {
+                self.value(coordinate).fetch_and(value, order)
+            }
+        }
+    };
+}
+
+atomic_grid_integer!(target_has_atomic = "8", AtomicU8, u8);
+atomic_grid_integer!(target_has_atomic = "8", AtomicI8, i8);
+atomic_grid_integer!(target_has_atomic = "16", AtomicU16, u16);
+atomic_grid_integer!(target_has_atomic = "16", AtomicI16, i16);
+atomic_grid_integer!(target_has_atomic = "32", AtomicU32, u32);
+atomic_grid_integer!(target_has_atomic = "32", AtomicI32, i32);
+atomic_grid_integer!(target_has_atomic = "64", AtomicU64, u64);
+atomic_grid_integer!(target_has_atomic = "64", AtomicI64, i64);
+atomic_grid_integer!(target_has_atomic = "ptr", AtomicUsize, usize);
+atomic_grid_integer!(target_has_atomic = "ptr", AtomicIsize, isize);
+
+atomic_grid!(target_has_atomic = "8", AtomicBool, bool);
+
+#[cfg(target_has_atomic = "8")]
+impl AtomicGrid<atomic::AtomicBool> {
+    /// Logical "or" an element with a value, and return the previous value.
+    ///
+    /// This method raises a flag when the value is `true`, and returns whether
+    /// it was already raised.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    /// * `value` - The value to "or" the element with
+    /// * `order` - The memory ordering of the operation
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, AtomicGrid, coord, size};
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// #
+    /// let grid = AtomicGrid::<AtomicBool>::new(size!(2, 2));
+    ///
+    /// assert_eq!(grid.fetch_or(coord!(1, 1), true, Ordering::Relaxed), false);
+    /// assert_eq!(grid.fetch_or(coord!(1, 1), true, Ordering::Relaxed), true);
+    /// ```
+    ///
+    pub fn fetch_or(&self, coordinate: Coordinate, value: bool, order: Ordering) -> bool {
+        self.value(coordinate).fetch_or(value, order)
+    }
+
+    /// Logical "and" an element with a value, and return the previous value.
+    ///
+    /// This method lowers a flag when the value is `false`, and returns
+    /// whether it was raised.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    /// * `value` - The value to "and" the element with
+    /// * `order` - The memory ordering of the operation
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, AtomicGrid, coord, size};
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// #
+    /// let grid = AtomicGrid::<AtomicBool>::with_value(size!(2, 2), true);
+    ///
+    /// assert_eq!(grid.fetch_and(coord!(0, 0), false, Ordering::Relaxed), true);
+    /// assert_eq!(grid.load(coord!(0, 0), Ordering::Relaxed), false);
+    /// ```
+    ///
+    pub fn fetch_and(&self, coordinate: Coordinate, value: bool, order: Ordering) -> bool {
+        self.value(coordinate).fetch_and(value, order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicU32, AtomicI64, AtomicBool};
+    use crate::rect::Rect;
+    use std::thread;
+
+    #[test]
+    fn atomic_grid_fetch_add() {
+        let grid = AtomicGrid::<AtomicU32>::new(size!(3, 2));
+
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for coordinate in rect!(0, 0, 3, 2).coordinates() {
+                        grid.fetch_add(coordinate, coordinate.x as u32 + 1, Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(grid.into_grid(), Grid::from_rows(vec![vec![4, 8, 12],
+                                                          vec![4, 8, 12]]));
+    }
+
+    #[test]
+    fn atomic_grid_compare_exchange() {
+        let grid = AtomicGrid::from(Grid::from_rows(vec![vec![1i64, 2],
+                                                         vec![3, 4]]));
+
+        let result = grid.compare_exchange(coord!(1, 0), 2, 5, Ordering::SeqCst, Ordering::SeqCst);
+        assert_eq!(result, Ok(2));
+
+        let result = grid.compare_exchange(coord!(1, 0), 2, 6, Ordering::SeqCst, Ordering::SeqCst);
+        assert_eq!(result, Err(5));
+
+        assert_eq!(grid.swap(coord!(0, 1), 0, Ordering::SeqCst), 3);
+        assert_eq!(grid.fetch_max(coord!(0, 0), 7, Ordering::SeqCst), 1);
+        assert_eq!(grid.fetch_sub(coord!(1, 1), 4, Ordering::SeqCst), 4);
+
+        let grid: AtomicGrid<AtomicI64> = grid;
+        assert_eq!(grid.into_grid(), Grid::from_rows(vec![vec![7, 5],
+                                                          vec![0, 0]]));
+    }
+
+    #[test]
+    fn atomic_grid_flags() {
+        let grid = AtomicGrid::<AtomicBool>::new(size!(4, 4));
+
+        thread::scope(|scope| {
+            for y in 0..4 {
+                let grid = &grid;
+                scope.spawn(move || grid.store(coord!(y, y), true, Ordering::Relaxed));
+            }
+        });
+
+        let grid = grid.into_grid();
+        for coordinate in rect!(0, 0, 4, 4).coordinates() {
+            assert_eq!(grid[coordinate], coordinate.x == coordinate.y);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn atomic_grid_value() {
+        let grid = AtomicGrid::<AtomicU32>::new(size!(2, 2));
+        grid.load(coord!(0, 2), Ordering::Relaxed);
+    }
+
+    #[test]
+    fn atomic_grid_empty() {
+        let mut grid = Grid::<u32>::zero();
+        grid.resize(size!(3, 0), 0);
+
+        assert_eq!(AtomicGrid::<AtomicU32>::from(grid).into_grid().size(), size!(3, 0));
+        assert_eq!(AtomicGrid::<AtomicU32>::new(size!(0, 2)).into_grid().size(), size!(0, 2));
+    }
+}
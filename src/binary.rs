@@ -0,0 +1,335 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use std::convert::TryFrom;
+use std::io::{self, Read, Write, ErrorKind};
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::grid::Grid;
+
+// The first bytes of the binary format, to detect garbage input early.
+const MAGIC: [u8; 4] = *b"IGRD";
+
+// The largest dimension of a grid without any element; unlike the others,
+// these grids aren't backed by data that bounds their dimensions.
+const EMPTY_DIMENSION_LIMIT: usize = 1 << 20;
+
+/// An interface to implement elements of the binary format
+///
+/// This trait allows to store the elements of a grid with the compact binary
+/// format of `Grid::write_binary()` and `Grid::read_binary()`. Elements are
+/// plain values with a fixed number of bytes, and an encoding tag which is
+/// stored in the header to detect mismatching element types when reading.
+///
+/// It's implemented for all primitive integer types (except `usize` and
+/// `isize` whose size depends on the platform) and floating-point types; the
+/// values are stored in little-endian byte order.
+///
+pub trait BinaryElement: Sized {
+    /// The tag denoting the encoding of the elements.
+    const ENCODING: u8;
+
+    /// The number of bytes of an element.
+    const SIZE: usize;
+
+    /// The type descriptor of the elements in the NumPy format, if they can
+    /// be stored in it; it's `None` by default.
+    const NPY_DESCR: Option<&'static str> = None;
+
+    /// Write the element to a buffer of exactly `SIZE` bytes.
+    fn write_bytes(&self, bytes: &mut [u8]);
+
+    /// Read the element from a buffer of exactly `SIZE` bytes.
+    fn read_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! binary_element {
+    ($type:ty, $encoding:expr, $descr:expr) => {
+        impl BinaryElement for $type {
+            const ENCODING: u8 = $encoding;
+            const SIZE: usize = core::mem::size_of::<$type>();
+            const NPY_DESCR: Option<&'static str> = Some($descr);
+
+            fn write_bytes(&self, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_bytes(bytes: &[u8]) -> Self {
+                let mut array = [0; core::mem::size_of::<$type>()];
+                array.copy_from_slice(bytes);
+
+                <$type>::from_le_bytes(array)
+            }
+        }
+    };
+}
+
+binary_element!(u8, 1, "|u1");
+binary_element!(i8, 2, "|i1");
+binary_element!(u16, 3, "<u2");
+binary_element!(i16, 4, "<i2");
+binary_element!(u32, 5, "<u4");
+binary_element!(i32, 6, "<i4");
+binary_element!(u64, 7, "<u8");
+binary_element!(i64, 8, "<i8");
+binary_element!(f32, 9, "<f4");
+binary_element!(f64, 10, "<f8");
+
+impl<T: Clone + BinaryElement> Grid<T> {
+
+    /// Write the grid in a compact binary format.
+    ///
+    /// This method writes the grid in a compact binary format which is made
+    /// of a small header (the width, the height and the encoding of the
+    /// elements) followed by the elements in row-major order. It's meant for
+    /// fast saving of big grids; use `read_binary()` to load them back.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The writer to write the grid to
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if writing to the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1u16, 2],
+    ///                                 vec![3, 4]]);
+    ///
+    /// let mut bytes = Vec::new();
+    /// grid.write_binary(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(Grid::<u16>::read_binary(bytes.as_slice()).unwrap(), grid);
+    /// ```
+    ///
+    pub fn write_binary<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let size = self.size();
+
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&[T::ENCODING])?;
+        writer.write_all(&(size.width as u64).to_le_bytes())?;
+        writer.write_all(&(size.height as u64).to_le_bytes())?;
+
+        write_rows(self, &mut writer)
+    }
+
+    /// Read a grid from the compact binary format.
+    ///
+    /// This function reads a grid that was written with `write_binary()`. The
+    /// header is validated before reading the elements, and the encoding of
+    /// the elements must match the element type of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The reader to read the grid from
+    ///
+    /// # Errors
+    ///
+    /// It returns an error of kind `InvalidData` if the header is invalid or
+    /// doesn't match the element type, and an error of kind `UnexpectedEof`
+    /// if the data is truncated. The memory grows with the data that is read,
+    /// therefore, a header with large dimensions but truncated data fails
+    /// without allocating for the whole grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::with_size(size!(3, 2), 0.5f32);
+    ///
+    /// let mut bytes = Vec::new();
+    /// grid.write_binary(&mut bytes).unwrap();
+    ///
+    /// // The encoding of the elements doesn't match.
+    /// assert!(Grid::<f64>::read_binary(bytes.as_slice()).is_err());
+    /// ```
+    ///
+    pub fn read_binary<R: Read>(mut reader: R) -> io::Result<Grid<T>> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(io::Error::new(ErrorKind::InvalidData, "not a binary grid"));
+        }
+
+        let mut encoding = [0; 1];
+        reader.read_exact(&mut encoding)?;
+        if encoding[0] != T::ENCODING {
+            return Err(io::Error::new(ErrorKind::InvalidData, "mismatching element encoding"));
+        }
+
+        let width = read_dimension(&mut reader)?;
+        let height = read_dimension(&mut reader)?;
+
+        let rows = read_rows(&mut reader, width, height)?;
+        Ok(Grid::from_row_vectors(width, rows))
+    }
+}
+
+// Write the elements of a grid in row-major order; it's shared with the other
+// formats storing plain elements.
+pub(crate) fn write_rows<T, W>(grid: &Grid<T>, writer: &mut W) -> io::Result<()>
+    where T: Clone + BinaryElement, W: Write
+{
+    let mut buffer = vec![0; grid.size().width * T::SIZE];
+    for row in grid.row_vectors() {
+        for (value, bytes) in row.iter().zip(buffer.chunks_exact_mut(T::SIZE)) {
+            value.write_bytes(bytes);
+        }
+
+        writer.write_all(&buffer)?;
+    }
+
+    Ok(())
+}
+
+// Read a number of lines of elements of a given length.
+pub(crate) fn read_rows<T, R>(reader: &mut R, width: usize, height: usize) -> io::Result<Vec<Vec<T>>>
+    where T: BinaryElement, R: Read
+{
+    let length = width.checked_mul(T::SIZE)
+        .filter(|length| length.checked_mul(height).is_some())
+        .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "grid is too large"))?;
+
+    if (width == 0 || height == 0) && width.max(height) > EMPTY_DIMENSION_LIMIT {
+        return Err(io::Error::new(ErrorKind::InvalidData, "grid is too large"));
+    }
+
+    // The dimensions come from the input and aren't trusted; the memory grows
+    // with the data that is actually read instead.
+    let mut buffer = Vec::new();
+    let mut rows = Vec::new();
+    for _ in 0..height {
+        buffer.clear();
+        (&mut *reader).take(length as u64).read_to_end(&mut buffer)?;
+        if buffer.len() < length {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "grid is truncated"));
+        }
+
+        rows.push(buffer.chunks_exact(T::SIZE).map(T::read_bytes).collect());
+    }
+
+    Ok(rows)
+}
+
+fn read_dimension<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+
+    usize::try_from(u64::from_le_bytes(bytes))
+        .map_err(|_| io::Error::new(ErrorKind::InvalidData, "grid is too large"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_write_binary() {
+        let grid = Grid::from_rows(vec![vec![1u16, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let mut bytes = Vec::new();
+        grid.write_binary(&mut bytes).unwrap();
+
+        assert_eq!(&bytes[..5], b"IGRD\x03");
+        assert_eq!(&bytes[5..13], &3u64.to_le_bytes());
+        assert_eq!(&bytes[13..21], &2u64.to_le_bytes());
+        assert_eq!(&bytes[21..], &[1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0]);
+    }
+
+    #[test]
+    fn grid_read_binary() {
+        let grid = Grid::from_rows(vec![vec![-1.5f64, 2.0],
+                                        vec![3.25, -4.0],
+                                        vec![5.0, 6.125]]);
+
+        let mut bytes = Vec::new();
+        grid.write_binary(&mut bytes).unwrap();
+        assert_eq!(Grid::<f64>::read_binary(bytes.as_slice()).unwrap(), grid);
+
+        // Empty grids keep their width or height.
+        let mut grid = Grid::<i32>::zero();
+        grid.resize(size!(3, 0), 0);
+
+        let mut bytes = Vec::new();
+        grid.write_binary(&mut bytes).unwrap();
+        assert_eq!(Grid::<i32>::read_binary(bytes.as_slice()).unwrap().size(), size!(3, 0));
+
+        let mut grid = Grid::<i32>::zero();
+        grid.resize(size!(0, 2), 0);
+
+        let mut bytes = Vec::new();
+        grid.write_binary(&mut bytes).unwrap();
+        assert_eq!(Grid::<i32>::read_binary(bytes.as_slice()).unwrap().size(), size!(0, 2));
+    }
+
+    #[test]
+    fn grid_read_binary_invalid() {
+        let grid = Grid::with_size(size!(2, 2), 42u8);
+
+        let mut bytes = Vec::new();
+        grid.write_binary(&mut bytes).unwrap();
+
+        let error = Grid::<i8>::read_binary(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        let error = Grid::<u8>::read_binary(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+        bytes[0] = b'X';
+        let error = Grid::<u8>::read_binary(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        let mut bytes = b"IGRD\x07".to_vec();
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+
+        let error = Grid::<u64>::read_binary(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn grid_read_binary_malformed() {
+        // The dimensions aren't trusted for allocating the rows.
+        let mut bytes = b"IGRD\x01".to_vec();
+        bytes.extend_from_slice(&(1u64 << 40).to_le_bytes());
+        bytes.extend_from_slice(&1u64.to_le_bytes());
+
+        let error = Grid::<u8>::read_binary(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+        let mut bytes = b"IGRD\x01".to_vec();
+        bytes.extend_from_slice(&1u64.to_le_bytes());
+        bytes.extend_from_slice(&(1u64 << 40).to_le_bytes());
+        bytes.extend_from_slice(&[1, 2, 3]);
+
+        let error = Grid::<u8>::read_binary(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+        // Grids without elements have no data bounding their dimensions.
+        let mut bytes = b"IGRD\x01".to_vec();
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend_from_slice(&(1u64 << 34).to_le_bytes());
+
+        let error = Grid::<u8>::read_binary(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        let mut bytes = b"IGRD\x01".to_vec();
+        bytes.extend_from_slice(&(1u64 << 40).to_le_bytes());
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+
+        let error = Grid::<u8>::read_binary(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+}
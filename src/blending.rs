@@ -0,0 +1,125 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+
+impl<T: Numeric> Grid<T> {
+
+    /// Return the weighted combination of the grid with another grid.
+    ///
+    /// This method returns a new grid whose elements are the linear
+    /// interpolation between the elements of the grid and the elements of
+    /// another grid of the same size; a weight of zero gives the grid, and a
+    /// weight of one gives the other grid. It's useful to crossfade heightmaps
+    /// or to composite scalar fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The grid to blend with
+    /// * `alpha` - The weight of the other grid
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grids don't have the same size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let a = Grid::from_rows(vec![vec![0.0, 10.0]]);
+    /// let b = Grid::from_rows(vec![vec![100.0, 20.0]]);
+    ///
+    /// let grid = a.blend(&b, 0.25);
+    /// assert_eq!(grid.row(0).values(), vec![&25.0, &12.5]);
+    /// ```
+    ///
+    pub fn blend(&self, other: &Grid<T>, alpha: f64) -> Grid<T> {
+        self.blend_with(other, |a, b| {
+            T::from_f64(a.to_f64() + (b.to_f64() - a.to_f64()) * alpha)
+        })
+    }
+
+    /// Return the combination of the grid with another grid.
+    ///
+    /// This method returns a new grid whose elements are computed by a
+    /// function from the elements of the grid and the elements of another grid
+    /// of the same size, for custom blending modes.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The grid to blend with
+    /// * `function` - The function combining two elements
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grids don't have the same size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let a = Grid::from_rows(vec![vec![1, 5]]);
+    /// let b = Grid::from_rows(vec![vec![3, 2]]);
+    ///
+    /// let grid = a.blend_with(&b, |a, b| a.max(b));
+    /// assert_eq!(grid.row(0).values(), vec![&3, &5]);
+    /// ```
+    ///
+    pub fn blend_with<F>(&self, other: &Grid<T>, mut function: F) -> Grid<T>
+        where F: FnMut(T, T) -> T
+    {
+        self.zip_with(other, |&a, &b| function(a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_blend() {
+        let a = Grid::from_rows(vec![vec![0u8, 100],
+                                     vec![200, 255]]);
+        let b = Grid::from_rows(vec![vec![255u8, 0],
+                                     vec![200, 0]]);
+
+        assert_eq!(a.blend(&b, 0.0), a);
+        assert_eq!(a.blend(&b, 1.0), b);
+
+        let grid = a.blend(&b, 0.5);
+        assert_eq!(grid.row(0).values(), vec![&128, &50]);
+        assert_eq!(grid.row(1).values(), vec![&200, &128]);
+
+        assert_eq!(Grid::<f32>::zero().blend(&Grid::zero(), 0.5).size(), size!(0, 0));
+    }
+
+    #[test]
+    fn grid_blend_with() {
+        let a = Grid::from_rows(vec![vec![1.0, 2.0],
+                                     vec![3.0, 4.0]]);
+        let b = Grid::from_rows(vec![vec![2.0, 2.0],
+                                     vec![0.5, 0.0]]);
+
+        let grid = a.blend_with(&b, |a, b| a * b);
+        assert_eq!(grid.row(0).values(), vec![&2.0, &4.0]);
+        assert_eq!(grid.row(1).values(), vec![&1.5, &0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid sizes don't match")]
+    fn grid_blend_mismatching_sizes() {
+        let a = Grid::with_size(size!(2, 2), 0.0);
+        let b = Grid::with_size(size!(2, 3), 0.0);
+
+        a.blend(&b, 0.5);
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use crate::coordinate::Coordinate;
+use crate::grid::Grid;
+
+impl<T: Clone> Grid<T> {
+
+    /// Copy a grid onto the grid.
+    ///
+    /// This method copies the elements of another grid onto the grid, with
+    /// the top-left element of the other grid at a given coordinate. The
+    /// parts of the other grid outside of the grid are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The grid to copy
+    /// * `origin` - The coordinate of the top-left element of the copy
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(3, 2), 0);
+    /// let sprite = Grid::with_size(size!(2, 2), 1);
+    ///
+    /// grid.blit(&sprite, coord!(2, 0));
+    /// assert_eq!(grid.row(0).values(), vec![&0, &0, &1]);
+    /// assert_eq!(grid.row(1).values(), vec![&0, &0, &1]);
+    /// ```
+    ///
+    pub fn blit(&mut self, source: &Grid<T>, origin: Coordinate) {
+        self.blit_where(source, origin, |_, _| true);
+    }
+
+    /// Copy a grid onto the grid through a mask.
+    ///
+    /// This method copies the elements of another grid onto the grid like
+    /// `blit()`, except for the elements whose mask is false, which are
+    /// transparent and leave the elements of the grid untouched. It allows to
+    /// paste sprites and stamps without overwriting the background.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The grid to copy
+    /// * `origin` - The coordinate of the top-left element of the copy
+    /// * `mask` - The grid telling which elements are copied
+    ///
+    /// # Panics
+    ///
+    /// It panics if the mask doesn't have the size of the grid to copy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(3, 2), 0);
+    /// let sprite = Grid::with_size(size!(2, 2), 1);
+    /// let mask = Grid::from_rows(vec![vec![true, false],
+    ///                                 vec![false, true]]);
+    ///
+    /// grid.blit_masked(&sprite, coord!(1, 0), &mask);
+    /// assert_eq!(grid.row(0).values(), vec![&0, &1, &0]);
+    /// assert_eq!(grid.row(1).values(), vec![&0, &0, &1]);
+    /// ```
+    ///
+    pub fn blit_masked(&mut self, source: &Grid<T>, origin: Coordinate, mask: &Grid<bool>) {
+        assert_eq!(mask.size(), source.size(), "mask size doesn't match");
+
+        self.blit_where(source, origin, |coordinate, _| mask[coordinate]);
+    }
+
+    /// Copy a grid onto the grid with a transparent value.
+    ///
+    /// This method copies the elements of another grid onto the grid like
+    /// `blit()`, except for the elements equal to a transparent value, which
+    /// leave the elements of the grid untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The grid to copy
+    /// * `origin` - The coordinate of the top-left element of the copy
+    /// * `transparent` - The value of the elements that aren't copied
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(3, 2), '.');
+    /// let sprite = Grid::from_rows(vec![vec![' ', '#'],
+    ///                                   vec!['#', ' ']]);
+    ///
+    /// grid.blit_keyed(&sprite, coord!(0, 0), &' ');
+    /// assert_eq!(grid.row(0).values(), vec![&'.', &'#', &'.']);
+    /// assert_eq!(grid.row(1).values(), vec![&'#', &'.', &'.']);
+    /// ```
+    ///
+    pub fn blit_keyed(&mut self, source: &Grid<T>, origin: Coordinate, transparent: &T)
+        where T: PartialEq
+    {
+        self.blit_where(source, origin, |_, value| value != transparent);
+    }
+
+    // Copy the elements of a grid accepted by a predicate, given their
+    // coordinate in that grid, clipping what falls outside of the grid.
+    fn blit_where<F>(&mut self, source: &Grid<T>, origin: Coordinate, mut predicate: F)
+        where F: FnMut(Coordinate, &T) -> bool
+    {
+        let width = source.size().width.min(self.size().width.saturating_sub(origin.x));
+        let height = source.size().height.min(self.size().height.saturating_sub(origin.y));
+        if width == 0 {
+            return;
+        }
+
+        for y in 0..height {
+            let source_row = source.row_slice(y);
+            let row = &mut self.row_slice_mut(origin.y + y)[origin.x..origin.x + width];
+
+            for (x, (element, value)) in row.iter_mut().zip(source_row).enumerate() {
+                if predicate(Coordinate::new(x, y), value) {
+                    *element = value.clone();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_blit() {
+        let mut grid = Grid::with_size(size!(4, 3), 0);
+        let source = Grid::from_rows(vec![vec![1, 2],
+                                          vec![3, 4]]);
+
+        grid.blit(&source, coord!(1, 1));
+        assert_eq!(grid.row(0).values(), vec![&0, &0, &0, &0]);
+        assert_eq!(grid.row(1).values(), vec![&0, &1, &2, &0]);
+        assert_eq!(grid.row(2).values(), vec![&0, &3, &4, &0]);
+
+        // Clipped on the right and bottom edges.
+        grid.blit(&source, coord!(3, 2));
+        assert_eq!(grid.row(2).values(), vec![&0, &3, &4, &1]);
+
+        // Entirely outside of the grid.
+        grid.blit(&source, coord!(4, 0));
+        grid.blit(&source, coord!(9, 0));
+        grid.blit(&source, coord!(0, 9));
+        assert_eq!(grid.row(0).values(), vec![&0, &0, &0, &0]);
+    }
+
+    #[test]
+    fn grid_blit_masked() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        let source = Grid::from_rows(vec![vec![1, 2],
+                                          vec![3, 4]]);
+        let mask = Grid::from_rows(vec![vec![false, true],
+                                        vec![true, true]]);
+
+        grid.blit_masked(&source, coord!(1, 1), &mask);
+        assert_eq!(grid.row(0).values(), vec![&0, &0, &0]);
+        assert_eq!(grid.row(1).values(), vec![&0, &0, &2]);
+        assert_eq!(grid.row(2).values(), vec![&0, &3, &4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "mask size doesn't match")]
+    fn grid_blit_masked_invalid_mask() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        let source = Grid::with_size(size!(2, 2), 1);
+        let mask = Grid::with_size(size!(2, 1), true);
+
+        grid.blit_masked(&source, coord!(0, 0), &mask);
+    }
+
+    #[test]
+    fn grid_blit_keyed() {
+        let mut grid = Grid::with_size(size!(3, 2), 9);
+        let source = Grid::from_rows(vec![vec![0, 1, 0],
+                                          vec![2, 0, 3]]);
+
+        grid.blit_keyed(&source, coord!(0, 0), &0);
+        assert_eq!(grid.row(0).values(), vec![&9, &1, &9]);
+        assert_eq!(grid.row(1).values(), vec![&2, &9, &3]);
+    }
+}
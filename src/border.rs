@@ -0,0 +1,177 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::convert::TryFrom;
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::grid::Grid;
+
+/// The policies to handle the borders of grids
+///
+/// This enumeration lists the ways the algorithms operating on the
+/// neighborhood of the elements, such as convolutions, stencils, filters and
+/// relaxations, treat the neighbors that are outside of the grid.
+///
+/// Note that policies are indexed along each axis independently; for
+/// instance, with the mirror policy, the element at (-1, -1) is the element
+/// at (1, 1).
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Grid, BorderPolicy};
+/// #
+/// let grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+/// let shift = Grid::from_rows(vec![vec![0.0, 0.0, 1.0]]);
+///
+/// let shifted = grid.convolve_with_border(&shift, BorderPolicy::Clamp);
+/// assert_eq!(shifted.row(0), [2, 3, 3]);
+///
+/// let shifted = grid.convolve_with_border(&shift, BorderPolicy::Wrap);
+/// assert_eq!(shifted.row(0), [2, 3, 1]);
+///
+/// let shifted = grid.convolve_with_border(&shift, BorderPolicy::Mirror);
+/// assert_eq!(shifted.row(0), [2, 3, 2]);
+///
+/// let shifted = grid.convolve_with_border(&shift, BorderPolicy::Constant(9));
+/// assert_eq!(shifted.row(0), [2, 3, 9]);
+/// ```
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BorderPolicy<T> {
+    /// The elements on the edges are repeated indefinitely.
+    Clamp,
+
+    /// The grid is repeated; the elements past an edge are the elements of
+    /// the opposite edge.
+    Wrap,
+
+    /// The grid is reflected at its edges, without repeating the elements on
+    /// the edges.
+    Mirror,
+
+    /// The elements outside of the grid have a given value.
+    Constant(T),
+
+    /// The elements outside of the grid are left out; neighborhoods near the
+    /// edges are smaller.
+    Skip
+}
+
+impl<T> BorderPolicy<T> {
+    // Locate the element at a position along an axis, the position being
+    // possibly outside of the axis; it returns None if there's no element at
+    // this position, the caller either using the constant value or leaving
+    // the element out.
+    pub(crate) fn locate(&self, position: isize, length: usize) -> Option<usize> {
+        let length = length as isize;
+        if (0..length).contains(&position) {
+            return Some(position as usize);
+        }
+
+        match self {
+            BorderPolicy::Clamp => Some(position.clamp(0, length - 1) as usize),
+            BorderPolicy::Wrap => Some(position.rem_euclid(length) as usize),
+            BorderPolicy::Mirror => {
+                // The reflections repeat every two lengths, minus the edges.
+                let period = (2 * (length - 1)).max(1);
+                let position = position.rem_euclid(period);
+
+                Some(if position < length { position } else { period - position } as usize)
+            },
+            BorderPolicy::Constant(_) | BorderPolicy::Skip => None
+        }
+    }
+
+    // Locate the elements along an axis within a radius around an index; the
+    // elements that are left out are omitted, and the elements that have the
+    // constant value are None. Unless they're skipped, there are always
+    // twice the radius plus one elements, so it panics if the radius is too
+    // large for that.
+    pub(crate) fn window(&self, index: usize, radius: usize, length: usize) -> Vec<Option<usize>> {
+        if let BorderPolicy::Skip = self {
+            let (first, last) = (index.saturating_sub(radius), index.saturating_add(radius).min(length - 1));
+            return (first..=last).map(Some).collect();
+        }
+
+        let radius = isize::try_from(radius).ok()
+            .filter(|&radius| radius.checked_mul(2).and_then(|diameter| diameter.checked_add(index as isize)).is_some())
+            .expect("radius is too large");
+        (-radius..=radius).map(|offset| self.locate(index as isize + offset, length)).collect()
+    }
+
+    // Return the element of a line from the position located along it; it's
+    // the constant value if the position has no element.
+    pub(crate) fn pick<'a>(&'a self, line: &'a [T], index: Option<usize>) -> &'a T {
+        match (index, self) {
+            (Some(index), _) => &line[index],
+            (None, BorderPolicy::Constant(value)) => value,
+            _ => unreachable!()
+        }
+    }
+
+    // Return the element of a grid from the positions located along each
+    // axis; it's the constant value if either position has no element.
+    pub(crate) fn element<'a>(&'a self, grid: &'a Grid<T>, x: Option<usize>, y: Option<usize>) -> &'a T {
+        match (x, y, self) {
+            (Some(x), Some(y), _) => &grid[Coordinate::new(x, y)],
+            (_, _, BorderPolicy::Constant(value)) => value,
+            _ => unreachable!()
+        }
+    }
+
+    // Return the constant value, if any.
+    pub(crate) fn constant(&self) -> Option<&T> {
+        match self {
+            BorderPolicy::Constant(value) => Some(value),
+            _ => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn border_policy_locate() {
+        let positions = -5..9;
+
+        let located = positions.clone().map(|position| BorderPolicy::<u8>::Clamp.locate(position, 4).unwrap());
+        assert_eq!(located.collect::<Vec<_>>(), vec![0, 0, 0, 0, 0, 0, 1, 2, 3, 3, 3, 3, 3, 3]);
+
+        let located = positions.clone().map(|position| BorderPolicy::<u8>::Wrap.locate(position, 4).unwrap());
+        assert_eq!(located.collect::<Vec<_>>(), vec![3, 0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3, 0]);
+
+        let located = positions.clone().map(|position| BorderPolicy::<u8>::Mirror.locate(position, 4).unwrap());
+        assert_eq!(located.collect::<Vec<_>>(), vec![1, 2, 3, 2, 1, 0, 1, 2, 3, 2, 1, 0, 1, 2]);
+
+        // Axes of a single element are mirrored onto themselves.
+        assert_eq!(BorderPolicy::<u8>::Mirror.locate(-3, 1), Some(0));
+        assert_eq!(BorderPolicy::<u8>::Mirror.locate(2, 1), Some(0));
+
+        assert_eq!(BorderPolicy::Constant(0).locate(-1, 4), None);
+        assert_eq!(BorderPolicy::Constant(0).locate(2, 4), Some(2));
+        assert_eq!(BorderPolicy::<u8>::Skip.locate(4, 4), None);
+    }
+
+    #[test]
+    fn border_policy_window() {
+        assert_eq!(BorderPolicy::<u8>::Clamp.window(0, 2, 3), vec![Some(0), Some(0), Some(0), Some(1), Some(2)]);
+        assert_eq!(BorderPolicy::Constant(0).window(2, 1, 3), vec![Some(1), Some(2), None]);
+
+        assert_eq!(BorderPolicy::<u8>::Skip.window(0, 2, 3), vec![Some(0), Some(1), Some(2)]);
+        assert_eq!(BorderPolicy::<u8>::Skip.window(1, usize::MAX, 3), vec![Some(0), Some(1), Some(2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "radius is too large")]
+    fn border_policy_window_too_large() {
+        BorderPolicy::<u8>::Clamp.window(1, usize::MAX, 3);
+    }
+}
@@ -13,6 +13,7 @@ use crate::grid::Grid;
 ///
 /// This structure is not implemented yet.
 ///
+#[allow(dead_code)]
 pub struct Cell<'a, T> {
     grid: &'a Grid<T>,
     coordinate: Coordinate
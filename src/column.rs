@@ -6,11 +6,12 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
-use std::ops::Index;
+use core::ops::{Index, RangeBounds};
+use alloc::vec::Vec;
 use crate::coordinate::Coordinate;
-use crate::grid::Grid;
+use crate::grid::{Grid, range_limits};
+use crate::column_segment::ColumnSegment;
 use crate::iterator_column::IteratorColumn;
-use crate::coord;
 
 /// A view onto a column of a grid
 ///
@@ -63,6 +64,18 @@ use crate::coord;
 /// println!("Last element of last column is {}", grid.column(1)[2]);
 /// ```
 ///
+/// Comparing the elements with an array, a slice or a vector.
+///
+/// ```
+/// # use ingrid::Grid;
+/// #
+/// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+///                                 vec![4, 5, 6]]);
+///
+/// assert_eq!(grid.column(0), [1, 4]);
+/// assert_eq!(grid.column(0), vec![1, 4]);
+/// ```
+///
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Column<'a, T> {
     /// A reference to its grid.
@@ -130,7 +143,9 @@ impl<'a, T: Clone> Column<'a, T> {
     /// ```
     ///
     pub fn value(&self, index: usize) -> &'a T {
-        self.grid.value(coord!(self.index, index))
+        // Go through the row directly; the length of the row is the width of
+        // the grid, so indexing it checks the column index.
+        &self.grid.row_slice(index)[self.index]
     }
 
     /// Return the elements of the column.
@@ -153,6 +168,27 @@ impl<'a, T: Clone> Column<'a, T> {
         self.iterator().collect()
     }
 
+    /// Return the elements of the column as an owned vector.
+    ///
+    /// This method returns the elements of the column as a vector of cloned
+    /// values, unlike `values()` which returns a vector of references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                 vec![3, 4]]);
+    ///
+    /// assert_eq!(grid.column(0).to_vec(), vec![1, 3]);
+    /// assert_eq!(grid.column(1).to_vec(), vec![2, 4]);
+    /// ```
+    ///
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iterator().cloned().collect()
+    }
+
     /// Returns a reference to the first element of the column.
     ///
     /// This method returns a reference to the first element of the column. It's
@@ -252,7 +288,7 @@ impl<'a, T: Clone> Column<'a, T> {
         }
         else {
             // rework this
-            let left_column_index: usize = (self.index - 1) as usize;
+            let left_column_index: usize = self.index - 1;
             Some(self.grid.column(left_column_index)) // remove integer conversation
         }
     }
@@ -282,10 +318,42 @@ impl<'a, T: Clone> Column<'a, T> {
         }
         else {
             // rework this
-            let rigth_column_index: usize = (self.index + 1) as usize;
+            let rigth_column_index: usize = self.index + 1;
             Some(self.grid.column(rigth_column_index)) // remove integer conversation
         }
     }
+
+    /// Returns a view onto a part of the column.
+    ///
+    /// This method returns a segment of the column, which is a view onto a
+    /// range of its elements with the same API as the column.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of the elements
+    ///
+    /// # Panics
+    ///
+    /// It panics if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 5],
+    ///                                 vec![2, 6],
+    ///                                 vec![3, 7],
+    ///                                 vec![4, 8]]);
+    ///
+    /// assert_eq!(grid.column(1).segment(1..3), [6, 7]);
+    /// assert_eq!(grid.column(0).segment(2..), [3, 4]);
+    /// ```
+    ///
+    pub fn segment<R: RangeBounds<usize>>(&self, range: R) -> ColumnSegment<'a, T> {
+        let (start, end) = range_limits(range, self.length());
+        ColumnSegment::new(self.clone(), start, end)
+    }
 }
 
 impl<'a, T: Clone> Index<usize> for Column<'a, T> {
@@ -296,6 +364,25 @@ impl<'a, T: Clone> Index<usize> for Column<'a, T> {
     }
 }
 
+impl<'a, T: Clone + PartialEq> PartialEq<[T]> for Column<'a, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.length() == other.len() &&
+        other.iter().enumerate().all(|(index, value)| self.value(index) == value)
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<Vec<T>> for Column<'a, T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<'a, T: Clone + PartialEq, const N: usize> PartialEq<[T; N]> for Column<'a, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -342,7 +429,17 @@ mod tests {
         assert_eq!(column[0], 2);
         assert_eq!(column[1], 4);
 
-        column[2];
+        let _ = column[2];
+    }
+
+    #[test]
+    fn column_to_vec() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4],
+                                        vec![5, 6]]);
+
+        assert_eq!(grid.column(0).to_vec(), vec![1, 3, 5]);
+        assert_eq!(grid.column(1).to_vec(), vec![2, 4, 6]);
     }
 
     #[test]
@@ -423,4 +520,19 @@ mod tests {
 
         assert!(last_column.right().is_none());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn column_eq() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4],
+                                        vec![5, 6]]);
+
+        assert_eq!(grid.column(1), [2, 4, 6]);
+        assert_eq!(grid.column(1), vec![2, 4, 6]);
+        assert_eq!(grid.column(1), [2, 4, 6][..]);
+
+        assert_ne!(grid.column(1), [2, 4]);
+        assert_ne!(grid.column(1), vec![2, 4, 6, 8]);
+        assert_ne!(grid.column(1), [2, 0, 6][..]);
+    }
+}
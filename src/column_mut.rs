@@ -6,12 +6,14 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
-use std::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut};
+use core::cmp::Ordering;
+use alloc::vec::Vec;
 use crate::coordinate::Coordinate;
 use crate::grid::Grid;
 use crate::column::Column;
 use crate::iterator_column::IteratorColumn;
-use crate::coord;
+use crate::column_segment_mut::ColumnSegmentMut;
 
 /// A mutable view onto a column of a grid
 ///
@@ -132,7 +134,8 @@ impl<'a, T: Clone> ColumnMut<'a, T> {
     /// ```
     ///
     pub fn value(&self, index: usize) -> &T {
-        self.grid.value(coord!(self.index, index))
+        // Go through the row directly, like `Column::value()`.
+        &self.grid.row_slice(index)[self.index]
     }
 
     /// Returns a mutable reference to an element of the column.
@@ -168,7 +171,7 @@ impl<'a, T: Clone> ColumnMut<'a, T> {
     /// ```
     ///
     pub fn value_mut(&mut self, index: usize) -> &mut T {
-        self.grid.value_mut(coord!(self.index, index))
+        &mut self.grid.row_slice_mut(index)[self.index]
     }
 
     /// Replace an element of the column.
@@ -468,7 +471,7 @@ impl<'a, T: Clone> ColumnMut<'a, T> {
         }
         else {
             // rework this
-            let rigth_column_index: usize = (self.index + 1) as usize;
+            let rigth_column_index: usize = self.index + 1;
             Some(self.grid.column(rigth_column_index)) // remove integer conversation
         }
     }
@@ -497,7 +500,7 @@ impl<'a, T: Clone> ColumnMut<'a, T> {
         }
         else {
             // rework this
-            let rigth_column_index: usize = (self.index + 1) as usize;
+            let rigth_column_index: usize = self.index + 1;
             Some(self.grid.column_mut(rigth_column_index)) // remove integer conversation
         }
     }
@@ -576,10 +579,8 @@ impl<'a, T: Clone> ColumnMut<'a, T> {
 
         let length = self.length();
 
-        let mut i = number;
-        for j in 0..length-1 {
+        for (i, j) in (number..).zip(0..length-1) {
             self.swap_value(i % length, j);
-            i += 1
         }
     }
 
@@ -625,10 +626,7 @@ impl<'a, T: Clone> ColumnMut<'a, T> {
         let mut i = number + length;
 
         for j in (1..length).rev() {
-            let foo = i % length;
-            let bar = j;
-
-            self.swap_value(foo, bar);
+            self.swap_value(i % length, j);
             i -= 1;
         }
     }
@@ -669,6 +667,162 @@ impl<'a, T: Clone> ColumnMut<'a, T> {
     pub fn swap(&mut self, a: usize, b: usize) {
         self.grid.swap_value(coord!(self.index, a), coord!(self.index, b));
     }
+
+    /// Swap the elements of the column with the elements of a slice.
+    ///
+    /// This method swaps all the elements of the column with the elements of a
+    /// given slice. The length of the slice must be the same as the length of
+    /// the column.
+    ///
+    /// Note that it's similar to the `swap_with_slice()` method of the slice
+    /// primitive type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The slice to swap the elements with
+    ///
+    /// # Panics
+    ///
+    /// It panics if the length of the slice doesn't equal the length of the
+    /// column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 0],
+    ///                                     vec![3, 0]]);
+    ///
+    /// let mut buffer = [2, 4];
+    /// grid.column_mut(1).swap_with_slice(&mut buffer);
+    ///
+    /// assert_eq!(grid.column(1), [2, 4]);
+    /// assert_eq!(buffer, [0, 0]);
+    /// ```
+    ///
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        assert_eq!(self.length(), other.len(), "destination and source slices have different lengths");
+
+        for (index, value) in other.iter_mut().enumerate() {
+            core::mem::swap(self.value_mut(index), value);
+        }
+    }
+
+    /// Sort the elements.
+    ///
+    /// This method sorts the elements of the column in ascending order, in
+    /// place. The sort is stable.
+    ///
+    /// Note that it's similar to the `sort()` method of the slice primitive
+    /// type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 6],
+    ///                                     vec![3, 4],
+    ///                                     vec![5, 2]]);
+    ///
+    /// grid.column_mut(1).sort();
+    /// assert_eq!(grid.column(1), [2, 4, 6]);
+    /// ```
+    ///
+    pub fn sort(&mut self) where T: Ord {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sort the elements with a comparator function.
+    ///
+    /// This method sorts the elements of the column in the order defined by a
+    /// comparator function, in place. The sort is stable.
+    ///
+    /// Note that it's similar to the `sort_by()` method of the slice primitive
+    /// type.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - The function comparing two elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 6],
+    ///                                     vec![3, 4],
+    ///                                     vec![5, 2]]);
+    ///
+    /// grid.column_mut(0).sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(grid.column(0), [5, 3, 1]);
+    /// ```
+    ///
+    pub fn sort_by<F>(&mut self, mut compare: F) where F: FnMut(&T, &T) -> Ordering {
+        // The elements of a column aren't contiguous; sort their indices
+        // instead, then move them to their sorted position by following the
+        // cycles of the permutation.
+        let mut indices = (0..self.length()).collect::<Vec<_>>();
+        indices.sort_by(|&a, &b| compare(self.value(a), self.value(b)));
+
+        for index in 0..indices.len() {
+            let mut source = indices[index];
+            while source < index {
+                source = indices[source];
+            }
+
+            self.swap(index, source);
+        }
+    }
+
+    /// Divide the column into two mutable segments at an index.
+    ///
+    /// This method divides the column into two disjoint segments that can be
+    /// modified at the same time; the first one contains the elements above
+    /// the index, the second one contains the others.
+    ///
+    /// Note that it's similar to the `split_at_mut()` method of the slice
+    /// primitive type.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index to divide the column at
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is greater than the length of the column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 5],
+    ///                                     vec![2, 6],
+    ///                                     vec![3, 7],
+    ///                                     vec![4, 8]]);
+    ///
+    /// let mut column = grid.column_mut(1);
+    /// let (mut top, mut bottom) = column.split_at_mut(2);
+    /// top.fill(0);
+    /// bottom.reverse();
+    ///
+    /// assert_eq!(grid.column(1), [0, 0, 8, 7]);
+    /// ```
+    ///
+    pub fn split_at_mut(&mut self, index: usize) -> (ColumnSegmentMut<'_, T>, ColumnSegmentMut<'_, T>) {
+        assert!(self.index < self.grid.size().width, "index out of bounds");
+        assert!(index <= self.length(), "index out of bounds");
+
+        let column = self.index;
+        let mut top = self.grid.row_vectors_mut().iter_mut()
+            .map(|row| &mut row[column])
+            .collect::<Vec<_>>();
+        let bottom = top.split_off(index);
+
+        (ColumnSegmentMut::new(top, 0), ColumnSegmentMut::new(bottom, index))
+    }
 }
 
 impl<'a, T: Clone> Index<usize> for ColumnMut<'a, T> {
@@ -685,6 +839,25 @@ impl<'a, T: Clone> IndexMut<usize> for ColumnMut<'a, T> {
     }
 }
 
+impl<'a, T: Clone + PartialEq> PartialEq<[T]> for ColumnMut<'a, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.length() == other.len() &&
+        other.iter().enumerate().all(|(index, value)| self.value(index) == value)
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<Vec<T>> for ColumnMut<'a, T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<'a, T: Clone + PartialEq, const N: usize> PartialEq<[T; N]> for ColumnMut<'a, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -785,7 +958,7 @@ mod tests {
         assert_eq!(column[0], 2);
         assert_eq!(column[1], 4);
 
-        column[2];
+        let _ = column[2];
     }
 
     #[test]
@@ -797,15 +970,15 @@ mod tests {
         let mut column = grid.column_mut(1);
         column[1] = 4;
 
-        let mut column = grid.column_mut(0);
+        let column = grid.column_mut(0);
         assert_eq!(column[0], 1);
         assert_eq!(column[1], 3);
 
-        let mut column = grid.column_mut(1);
+        let column = grid.column_mut(1);
         assert_eq!(column[0], 2);
         assert_eq!(column[1], 4);
 
-        column[2];
+        let _ = column[2];
     }
 
     #[test]
@@ -1062,7 +1235,98 @@ mod tests {
     }
 
     #[test]
+    #[should_panic(expected = "destination and source slices have different lengths")]
     fn column_swap_with_slice() {
-        // Not implemented yet.
+        let mut grid = Grid::from_rows(vec![vec![1, 2],
+                                            vec![3, 4],
+                                            vec![5, 6]]);
+
+        let mut buffer = vec![7, 8, 9];
+        grid.column_mut(0).swap_with_slice(&mut buffer);
+
+        assert_eq!(grid.column(0), [7, 8, 9]);
+        assert_eq!(grid.column(1), [2, 4, 6]);
+        assert_eq!(buffer, vec![1, 3, 5]);
+
+        grid.column_mut(1).swap_with_slice(&mut buffer[..2]);
+    }
+
+    #[test]
+    fn column_eq() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2],
+                                            vec![3, 4],
+                                            vec![5, 6]]);
+
+        assert_eq!(grid.column_mut(1), [2, 4, 6]);
+        assert_eq!(grid.column_mut(1), vec![2, 4, 6]);
+        assert_eq!(grid.column_mut(1), [2, 4, 6][..]);
+
+        assert_ne!(grid.column_mut(1), [2, 4]);
+        assert_ne!(grid.column_mut(1), vec![2, 4, 6, 8]);
+        assert_ne!(grid.column_mut(1), [2, 0, 6][..]);
+    }
+
+    #[test]
+    fn column_sort() {
+        let mut grid = Grid::from_rows(vec![vec![3, 9],
+                                            vec![1, 7],
+                                            vec![2, 8]]);
+
+        grid.column_mut(0).sort();
+        assert_eq!(grid.column(0), [1, 2, 3]);
+        assert_eq!(grid.column(1), [9, 7, 8]);
+
+        grid.column_mut(1).sort_by(|a, b| b.cmp(a));
+        assert_eq!(grid.column(1), [9, 8, 7]);
+
+        // The sort is stable.
+        let mut grid = Grid::from_rows(vec![vec![(1, 'a')],
+                                            vec![(0, 'b')],
+                                            vec![(1, 'c')],
+                                            vec![(0, 'd')]]);
+
+        grid.column_mut(0).sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(grid.column(0), [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+
+        // Longer permutation cycles.
+        let mut grid = Grid::from_columns(vec![vec![4, 0, 5, 1, 6, 2, 7, 3]]);
+
+        grid.column_mut(0).sort();
+        assert_eq!(grid.column(0), [0, 1, 2, 3, 4, 5, 6, 7]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn column_split_at_mut() {
+        let mut grid = Grid::from_rows(vec![vec![1, 5],
+                                            vec![2, 6],
+                                            vec![3, 7],
+                                            vec![4, 8]]);
+
+        let mut column = grid.column_mut(1);
+        let (mut top, mut bottom) = column.split_at_mut(1);
+        assert_eq!(top.range(), 0..1);
+        assert_eq!(bottom.range(), 1..4);
+        assert_eq!(top, [5]);
+        assert_eq!(bottom, vec![6, 7, 8]);
+
+        // Two-pointer swap across both segments.
+        core::mem::swap(&mut top[0], &mut bottom[2]);
+        bottom.set_value(0, 0);
+        bottom.swap(1, 2);
+        assert_eq!(bottom.values(), vec![&0, &5, &7]);
+        assert_eq!(grid.column(0), [1, 2, 3, 4]);
+        assert_eq!(grid.column(1), [8, 0, 5, 7]);
+
+        let mut column = grid.column_mut(0);
+        let (top, bottom) = column.split_at_mut(0);
+        assert_eq!(top.length(), 0);
+        assert_eq!(bottom.length(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn column_split_at_mut_out_of_bounds() {
+        let mut grid = Grid::from_rows(vec![vec![1], vec![2], vec![3]]);
+        grid.column_mut(0).split_at_mut(4);
+    }
+}
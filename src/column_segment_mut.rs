@@ -0,0 +1,334 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::mem;
+use core::ops::{Index, IndexMut, Range};
+use alloc::vec::Vec;
+
+/// A mutable view onto a part of a column of a grid
+///
+/// This structure is a **mutable** view into a range of elements of a column.
+/// It's created by the `split_at_mut()` method of the mutable columns, which
+/// splits a column into two disjoint segments that can be modified at the
+/// same time, just like the slice primitive type does. Index zero corresponds
+/// to the first element of the segment.
+///
+/// Because the elements of a column aren't contiguous in memory, the segment
+/// keeps a reference to each of its elements.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::Grid;
+/// #
+/// let mut grid = Grid::from_rows(vec![vec![1],
+///                                     vec![2],
+///                                     vec![3],
+///                                     vec![4]]);
+///
+/// let mut column = grid.column_mut(0);
+/// let (mut top, mut bottom) = column.split_at_mut(2);
+/// core::mem::swap(&mut top[0], &mut bottom[1]);
+///
+/// assert_eq!(grid.column(0), [4, 2, 3, 1]);
+/// ```
+///
+#[derive(Debug, Eq, PartialEq)]
+pub struct ColumnSegmentMut<'a, T> {
+    elements: Vec<&'a mut T>,
+    start: usize
+}
+
+impl<'a, T: Clone> ColumnSegmentMut<'a, T> {
+    pub(crate) fn new(elements: Vec<&'a mut T>, start: usize) -> ColumnSegmentMut<'a, T> {
+        ColumnSegmentMut { elements, start }
+    }
+
+    /// Return the range of the segment.
+    ///
+    /// This method returns the range of the segment within its column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1], vec![2], vec![3]]);
+    ///
+    /// let mut column = grid.column_mut(0);
+    /// let (top, bottom) = column.split_at_mut(1);
+    /// assert_eq!(top.range(), 0..1);
+    /// assert_eq!(bottom.range(), 1..3);
+    /// ```
+    ///
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.start + self.elements.len()
+    }
+
+    /// Returns the length of the segment.
+    ///
+    /// This method returns the number of elements of the segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1], vec![2], vec![3]]);
+    ///
+    /// let mut column = grid.column_mut(0);
+    /// let (top, bottom) = column.split_at_mut(1);
+    /// assert_eq!(top.length(), 1);
+    /// assert_eq!(bottom.length(), 2);
+    /// ```
+    ///
+    pub fn length(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns a reference to an element of the segment.
+    ///
+    /// This method returns a reference to an element of the segment from its
+    /// index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1], vec![2], vec![3]]);
+    ///
+    /// let mut column = grid.column_mut(0);
+    /// let (_, bottom) = column.split_at_mut(1);
+    /// assert_eq!(bottom.value(0), &2);
+    /// ```
+    ///
+    pub fn value(&self, index: usize) -> &T {
+        assert!(index < self.length(), "index out of bounds");
+        self.elements[index]
+    }
+
+    /// Returns a mutable reference to an element of the segment.
+    ///
+    /// This method returns a mutable reference to an element of the segment
+    /// from its index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1], vec![2], vec![3]]);
+    ///
+    /// let mut column = grid.column_mut(0);
+    /// let (_, mut bottom) = column.split_at_mut(1);
+    /// *bottom.value_mut(0) = 42;
+    ///
+    /// assert_eq!(grid.column(0), [1, 42, 3]);
+    /// ```
+    ///
+    pub fn value_mut(&mut self, index: usize) -> &mut T {
+        assert!(index < self.length(), "index out of bounds");
+        self.elements[index]
+    }
+
+    /// Replace an element of the segment.
+    ///
+    /// This method replaces the value of an element of the segment from its
+    /// index and a new value.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the element
+    /// * `value` - New value of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1], vec![2], vec![3]]);
+    ///
+    /// let mut column = grid.column_mut(0);
+    /// let (_, mut bottom) = column.split_at_mut(1);
+    /// bottom.set_value(1, 42);
+    ///
+    /// assert_eq!(grid.column(0), [1, 2, 42]);
+    /// ```
+    ///
+    pub fn set_value(&mut self, index: usize, value: T) {
+        *self.value_mut(index) = value;
+    }
+
+    /// Return the elements of the segment.
+    ///
+    /// This method returns the elements of the segment as a vector of
+    /// reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1], vec![2], vec![3]]);
+    ///
+    /// let mut column = grid.column_mut(0);
+    /// let (top, bottom) = column.split_at_mut(1);
+    /// assert_eq!(top.values(), vec![&1]);
+    /// assert_eq!(bottom.values(), vec![&2, &3]);
+    /// ```
+    ///
+    pub fn values(&self) -> Vec<&T> {
+        self.elements.iter().map(|element| &**element).collect()
+    }
+
+    /// Fill the segment with a given value.
+    ///
+    /// This method fills the segment with a given value that is cloned for
+    /// all the elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to fill the segment with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1], vec![2], vec![3]]);
+    ///
+    /// let mut column = grid.column_mut(0);
+    /// let (mut top, mut bottom) = column.split_at_mut(1);
+    /// top.fill(0);
+    /// bottom.fill(42);
+    ///
+    /// assert_eq!(grid.column(0), [0, 42, 42]);
+    /// ```
+    ///
+    pub fn fill(&mut self, value: T) {
+        for element in self.elements.iter_mut() {
+            **element = value.clone();
+        }
+    }
+
+    /// Reverse the order of elements in the segment.
+    ///
+    /// This method reverses the order of the elements of the segment,
+    /// in-place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1], vec![2], vec![3]]);
+    ///
+    /// let mut column = grid.column_mut(0);
+    /// let (_, mut bottom) = column.split_at_mut(1);
+    /// bottom.reverse();
+    ///
+    /// assert_eq!(grid.column(0), [1, 3, 2]);
+    /// ```
+    ///
+    pub fn reverse(&mut self) {
+        let length = self.length();
+        for index in 0..length / 2 {
+            self.swap(index, length - index - 1);
+        }
+    }
+
+    /// Swap two elements in the segment.
+    ///
+    /// This method swaps two elements of the segment from their index.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The index of the first element
+    /// * `b` - The index of the second element
+    ///
+    /// # Panics
+    ///
+    /// It panics if `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1], vec![2], vec![3]]);
+    ///
+    /// let mut column = grid.column_mut(0);
+    /// let (_, mut bottom) = column.split_at_mut(1);
+    /// bottom.swap(0, 1);
+    ///
+    /// assert_eq!(grid.column(0), [1, 3, 2]);
+    /// ```
+    ///
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.length() && b < self.length(), "index out of bounds");
+
+        if a != b {
+            let (first, second) = self.elements.split_at_mut(a.max(b));
+            mem::swap(&mut *first[a.min(b)], &mut *second[0]);
+        }
+    }
+}
+
+impl<'a, T: Clone> Index<usize> for ColumnSegmentMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.value(index)
+    }
+}
+
+impl<'a, T: Clone> IndexMut<usize> for ColumnSegmentMut<'a, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.value_mut(index)
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<[T]> for ColumnSegmentMut<'a, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.length() == other.len() &&
+        self.elements.iter().zip(other.iter()).all(|(a, b)| **a == *b)
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<Vec<T>> for ColumnSegmentMut<'a, T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<'a, T: Clone + PartialEq, const N: usize> PartialEq<[T; N]> for ColumnSegmentMut<'a, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
@@ -0,0 +1,378 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+use crate::border::BorderPolicy;
+
+impl<T: Numeric> Grid<T> {
+
+    /// Return the grid convolved with a kernel.
+    ///
+    /// This method returns a new grid whose elements are the weighted sum of
+    /// the surrounding elements of the grid, the weights being given by a
+    /// kernel centered on each element. The kernel is applied as is, without
+    /// flipping it, which is the common convention in image processing.
+    ///
+    /// The grid is extended by repeating its edges, therefore, the elements
+    /// near the edges are computed as if the edges went on indefinitely. Use
+    /// `convolve_with_border()` to extend it differently.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - The weights of the surrounding elements
+    ///
+    /// # Panics
+    ///
+    /// It panics if the width or the height of the kernel is not odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0.0, 0.0, 0.0],
+    ///                                 vec![0.0, 9.0, 0.0],
+    ///                                 vec![0.0, 0.0, 0.0]]);
+    ///
+    /// // A box blur.
+    /// let kernel = Grid::from_rows(vec![vec![1.0 / 9.0; 3]; 3]);
+    ///
+    /// let blurred = grid.convolve(&kernel);
+    /// assert_eq!(blurred.row(0).values(), vec![&1.0, &1.0, &1.0]);
+    /// ```
+    ///
+    pub fn convolve(&self, kernel: &Grid<f64>) -> Grid<T> {
+        self.convolve_as(kernel, &BorderPolicy::Clamp)
+    }
+
+    /// Return the grid convolved with a kernel, with a border policy.
+    ///
+    /// This method is similar to `convolve()`, but the weights of the kernel
+    /// that fall outside of the grid are applied to the elements given by a
+    /// border policy. With the skip policy, these weights are left out and
+    /// the remaining weights aren't normalized.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - The weights of the surrounding elements
+    /// * `border` - The policy for the elements outside of the grid
+    ///
+    /// # Panics
+    ///
+    /// It panics if the width or the height of the kernel is not odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, BorderPolicy};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![3.0, 3.0, 3.0],
+    ///                                 vec![3.0, 3.0, 3.0]]);
+    ///
+    /// let kernel = Grid::from_rows(vec![vec![1.0 / 3.0; 3]]);
+    ///
+    /// let blurred = grid.convolve_with_border(&kernel, BorderPolicy::Constant(0.0));
+    /// assert_eq!(blurred.row(0).values(), vec![&2.0, &3.0, &2.0]);
+    /// ```
+    ///
+    pub fn convolve_with_border(&self, kernel: &Grid<f64>, border: BorderPolicy<T>) -> Grid<T> {
+        self.convolve_as(kernel, &border)
+    }
+
+    // Convolve with a kernel, producing elements of another numeric type;
+    // filters use it to compute with floating-point numbers.
+    pub(crate) fn convolve_as<U: Numeric>(&self, kernel: &Grid<f64>, border: &BorderPolicy<T>) -> Grid<U> {
+        let kernel_size = kernel.size();
+        assert!(kernel_size.width % 2 == 1 && kernel_size.height % 2 == 1, "kernel size must be odd");
+
+        let (width, height) = (self.size().width, self.size().height);
+        let (center_x, center_y) = (kernel_size.width / 2, kernel_size.height / 2);
+        let constant = border.constant().map(|value| value.to_f64());
+
+        let rows = (0..height).map(|y| {
+            (0..width).map(|x| {
+                let mut sum = 0.0;
+                for (ky, weights) in kernel.row_vectors().iter().enumerate() {
+                    let row = border.locate(position(y, ky, center_y), height);
+                    for (kx, weight) in weights.iter().enumerate() {
+                        let value = match (row, border.locate(position(x, kx, center_x), width)) {
+                            (Some(row), Some(column)) => self.row_slice(row)[column].to_f64(),
+                            _ => match constant {
+                                Some(constant) => constant,
+                                None => continue
+                            }
+                        };
+
+                        sum += weight * value;
+                    }
+                }
+
+                U::from_f64(sum)
+            }).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(width, rows)
+    }
+
+    /// Return the grid convolved with a separable kernel.
+    ///
+    /// This method is equivalent to `convolve()` with a kernel that is the
+    /// product of a row vector and a column vector, but computes the
+    /// convolution in two passes, one horizontal and one vertical. It's much
+    /// faster with large kernels as it takes `w + h` operations per element
+    /// instead of `w × h`. Many kernels are separable, such as box and
+    /// Gaussian blurs.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_kernel` - The horizontal weights
+    /// * `column_kernel` - The vertical weights
+    ///
+    /// # Panics
+    ///
+    /// It panics if the length of either kernel is not odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0.0, 0.0, 0.0],
+    ///                                 vec![0.0, 16.0, 0.0],
+    ///                                 vec![0.0, 0.0, 0.0]]);
+    ///
+    /// // A Gaussian blur.
+    /// let kernel = [0.25, 0.5, 0.25];
+    ///
+    /// let blurred = grid.convolve_separable(&kernel, &kernel);
+    /// assert_eq!(blurred.row(0).values(), vec![&1.0, &2.0, &1.0]);
+    /// assert_eq!(blurred.row(1).values(), vec![&2.0, &4.0, &2.0]);
+    /// ```
+    ///
+    pub fn convolve_separable(&self, row_kernel: &[f64], column_kernel: &[f64]) -> Grid<T> {
+        self.convolve_separable_as(row_kernel, column_kernel, &BorderPolicy::Clamp)
+    }
+
+    /// Return the grid convolved with a separable kernel, with a border
+    /// policy.
+    ///
+    /// This method is equivalent to `convolve_with_border()` with a kernel
+    /// that is the product of a row vector and a column vector, but computes
+    /// the convolution in two passes, like `convolve_separable()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_kernel` - The horizontal weights
+    /// * `column_kernel` - The vertical weights
+    /// * `border` - The policy for the elements outside of the grid
+    ///
+    /// # Panics
+    ///
+    /// It panics if the length of either kernel is not odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, BorderPolicy};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![4.0, 0.0, 0.0, 8.0]]);
+    ///
+    /// let kernel = [0.25, 0.5, 0.25];
+    ///
+    /// let blurred = grid.convolve_separable_with_border(&kernel, &[1.0], BorderPolicy::Wrap);
+    /// assert_eq!(blurred.row(0).values(), vec![&4.0, &1.0, &2.0, &5.0]);
+    /// ```
+    ///
+    pub fn convolve_separable_with_border(&self, row_kernel: &[f64], column_kernel: &[f64], border: BorderPolicy<T>) -> Grid<T> {
+        self.convolve_separable_as(row_kernel, column_kernel, &border)
+    }
+
+    // Convolve with a separable kernel, producing elements of another numeric
+    // type; filters use it to compute with floating-point numbers.
+    pub(crate) fn convolve_separable_as<U: Numeric>(&self, row_kernel: &[f64], column_kernel: &[f64], border: &BorderPolicy<T>) -> Grid<U> {
+        assert!(row_kernel.len() % 2 == 1 && column_kernel.len() % 2 == 1, "kernel size must be odd");
+
+        let (width, height) = (self.size().width, self.size().height);
+        let (center_x, center_y) = (row_kernel.len() / 2, column_kernel.len() / 2);
+        let constant = border.constant().map(|value| value.to_f64());
+
+        // The horizontal pass; the intermediate values aren't rounded.
+        let horizontal = (0..height).map(|y| {
+            let row = self.row_slice(y);
+
+            (0..width).map(|x| {
+                row_kernel.iter().enumerate()
+                    .filter_map(|(kx, weight)| {
+                        let value = match border.locate(position(x, kx, center_x), width) {
+                            Some(column) => row[column].to_f64(),
+                            None => constant?
+                        };
+
+                        Some(weight * value)
+                    })
+                    .sum::<f64>()
+            }).collect::<Vec<_>>()
+        }).collect::<Vec<_>>();
+
+        // The rows outside of the grid have the constant value, which the
+        // horizontal pass would scale by the sum of its weights.
+        let outside = constant.map(|constant| constant * row_kernel.iter().sum::<f64>());
+
+        let rows = (0..height).map(|y| {
+            (0..width).map(|x| {
+                let sum = column_kernel.iter().enumerate()
+                    .filter_map(|(ky, weight)| {
+                        let value = match border.locate(position(y, ky, center_y), height) {
+                            Some(row) => horizontal[row][x],
+                            None => outside?
+                        };
+
+                        Some(weight * value)
+                    })
+                    .sum();
+
+                U::from_f64(sum)
+            }).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(width, rows)
+    }
+}
+
+// Compute the position of the element under a weight of the kernel, which is
+// possibly outside of the axis.
+fn position(index: usize, offset: usize, center: usize) -> isize {
+    index as isize + offset as isize - center as isize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_convolve() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let identity = Grid::from_rows(vec![vec![0.0, 0.0, 0.0],
+                                            vec![0.0, 1.0, 0.0],
+                                            vec![0.0, 0.0, 0.0]]);
+        assert_eq!(grid.convolve(&identity), grid);
+
+        // The kernel isn't flipped and the edges are repeated.
+        let shift = Grid::from_rows(vec![vec![0.0, 0.0, 1.0]]);
+        let shifted = grid.convolve(&shift);
+        assert_eq!(shifted.row(0).values(), vec![&2, &3, &3]);
+        assert_eq!(shifted.row(1).values(), vec![&5, &6, &6]);
+
+        let shift = Grid::from_rows(vec![vec![1.0],
+                                         vec![0.0],
+                                         vec![0.0]]);
+        let shifted = grid.convolve(&shift);
+        assert_eq!(shifted.row(0).values(), vec![&1, &2, &3]);
+        assert_eq!(shifted.row(1).values(), vec![&1, &2, &3]);
+
+        assert_eq!(Grid::<f32>::zero().convolve(&identity).size(), size!(0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "kernel size must be odd")]
+    fn grid_convolve_even_kernel() {
+        let grid = Grid::with_size(size!(3, 3), 1.0);
+        grid.convolve(&Grid::with_size(size!(2, 3), 1.0));
+    }
+
+    #[test]
+    fn grid_convolve_separable() {
+        let grid = Grid::from_rows(vec![vec![1.0f64, 7.0, 3.0, 4.0],
+                                        vec![5.0, 2.0, 8.0, 6.0],
+                                        vec![9.0, 3.0, 1.0, 2.0]]);
+
+        let row_kernel = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let column_kernel = [0.5, -1.0, 2.0];
+
+        let kernel = Grid::from_rows(column_kernel.iter()
+            .map(|a| row_kernel.iter().map(|b| a * b).collect())
+            .collect());
+
+        // Both are equivalent, up to floating-point errors.
+        let expected = grid.convolve(&kernel);
+        let separable = grid.convolve_separable(&row_kernel, &column_kernel);
+
+        for (a, b) in expected.iterator().zip(separable.iterator()) {
+            assert!((a - b).abs() < 1e-9);
+        }
+
+        // Intermediate values aren't rounded with integers.
+        let grid = Grid::from_rows(vec![vec![0, 1, 0]]);
+        let blurred = grid.convolve_separable(&[0.5, 0.0, 0.5], &[1.0]);
+        assert_eq!(blurred.row(0).values(), vec![&1, &0, &1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "kernel size must be odd")]
+    fn grid_convolve_separable_even_kernel() {
+        let grid = Grid::with_size(size!(3, 3), 1.0);
+        grid.convolve_separable(&[1.0], &[0.5, 0.5]);
+    }
+
+    #[test]
+    fn grid_convolve_with_border() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let shift = Grid::from_rows(vec![vec![1.0, 0.0, 0.0]]);
+        assert_eq!(grid.convolve_with_border(&shift, BorderPolicy::Clamp), grid.convolve(&shift));
+
+        let shifted = grid.convolve_with_border(&shift, BorderPolicy::Wrap);
+        assert_eq!(shifted, vec![vec![3, 1, 2], vec![6, 4, 5]]);
+
+        let shifted = grid.convolve_with_border(&shift, BorderPolicy::Mirror);
+        assert_eq!(shifted, vec![vec![2, 1, 2], vec![5, 4, 5]]);
+
+        let shifted = grid.convolve_with_border(&shift, BorderPolicy::Constant(-1));
+        assert_eq!(shifted, vec![vec![-1, 1, 2], vec![-1, 4, 5]]);
+
+        // The weights outside of the grid are left out.
+        let sum = Grid::from_rows(vec![vec![1.0; 3]; 3]);
+        let sums = grid.convolve_with_border(&sum, BorderPolicy::Skip);
+        assert_eq!(sums, vec![vec![12, 21, 16], vec![12, 21, 16]]);
+
+        assert_eq!(Grid::<f32>::zero().convolve_with_border(&sum, BorderPolicy::Wrap).size(), size!(0, 0));
+    }
+
+    #[test]
+    fn grid_convolve_separable_with_border() {
+        let grid = Grid::from_rows(vec![vec![1.0f64, 7.0, 3.0, 4.0],
+                                        vec![5.0, 2.0, 8.0, 6.0],
+                                        vec![9.0, 3.0, 1.0, 2.0]]);
+
+        let row_kernel = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let column_kernel = [0.5, -1.0, 2.0];
+
+        let kernel = Grid::from_rows(column_kernel.iter()
+            .map(|a| row_kernel.iter().map(|b| a * b).collect())
+            .collect());
+
+        // Both are equivalent with all policies, up to floating-point errors.
+        let borders = [BorderPolicy::Clamp, BorderPolicy::Wrap, BorderPolicy::Mirror,
+                       BorderPolicy::Constant(2.5), BorderPolicy::Skip];
+
+        for &border in &borders {
+            let expected = grid.convolve_with_border(&kernel, border);
+            let separable = grid.convolve_separable_with_border(&row_kernel, &column_kernel, border);
+
+            for (a, b) in expected.iterator().zip(separable.iterator()) {
+                assert!((a - b).abs() < 1e-9);
+            }
+        }
+    }
+}
@@ -6,6 +6,13 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::num::TryFromIntError;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use crate::offset::Offset;
+use crate::size::Size;
+
 /// A two-dimensional coordinate
 ///
 /// This structure defines a basic two-dimensional coordinate to index grids. It
@@ -32,7 +39,43 @@
 /// let coord3 = Coordinate::zero();
 /// ```
 ///
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// Coordinates are ordered the same way grids are iterated, which is row by
+/// row from the top-left corner; they can be sorted or used as keys of ordered
+/// collections.
+///
+/// ```
+/// # use ingrid::{Coordinate, coord};
+/// #
+/// let mut coords = vec![coord!(0, 1), coord!(1, 0), coord!(0, 0)];
+/// coords.sort();
+///
+/// assert_eq!(coords, vec![coord!(0, 0), coord!(1, 0), coord!(0, 1)]);
+/// ```
+///
+/// Coordinates can be shifted by offsets, and the difference of two
+/// coordinates is an offset.
+///
+/// ```
+/// # use ingrid::{Coordinate, Offset, coord, offset};
+/// #
+/// let coord = coord!(1, 1) + offset!(1, -1);
+/// assert_eq!(coord, coord!(2, 0));
+///
+/// assert_eq!(coord!(0, 2) - coord!(2, 0), offset!(-2, 2));
+/// ```
+///
+/// Signed positions, as commonly found in physics or game code, can be
+/// converted into coordinates as long as they're not negative.
+///
+/// ```
+/// # use ingrid::{Coordinate, coord};
+/// # use std::convert::TryFrom;
+/// #
+/// assert_eq!(Coordinate::try_from((1, 2)), Ok(coord!(1, 2)));
+/// assert!(Coordinate::try_from((-1, 2)).is_err());
+/// ```
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Coordinate {
     /// The coordinate on the X axis.
     pub x: usize,
@@ -80,6 +123,248 @@ impl Coordinate {
     pub fn zero() -> Coordinate {
         Coordinate { x: 0, y: 0 }
     }
+
+    /// Apply an offset within the bounds of a grid.
+    ///
+    /// This method shifts the coordinate by a given offset and returns the
+    /// resulting coordinate, or `None` if it falls outside a grid of the given
+    /// size (either because it goes below zero, or because it exceeds the
+    /// width or the height).
+    ///
+    /// Unlike the `+` operator which panics when going below zero, this method
+    /// is the safe way to compute the adjacent coordinates of an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The offset to apply
+    /// * `size` - The size of the grid the coordinate must be within
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Offset, Size, coord, offset, size};
+    /// #
+    /// let coord = coord!(1, 0);
+    ///
+    /// assert_eq!(coord.checked_apply(offset!(1, 1), size!(3, 3)), Some(coord!(2, 1)));
+    /// assert_eq!(coord.checked_apply(offset!(0, -1), size!(3, 3)), None);
+    /// assert_eq!(coord.checked_apply(offset!(2, 0), size!(3, 3)), None);
+    /// ```
+    ///
+    pub fn checked_apply(self, offset: Offset, size: Size) -> Option<Coordinate> {
+        let x = self.x.checked_add_signed(offset.x)?;
+        let y = self.y.checked_add_signed(offset.y)?;
+
+        let coordinate = Coordinate::new(x, y);
+        if size.contains(coordinate) {
+            Some(coordinate)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Convert the coordinate to a linear index.
+    ///
+    /// This method returns the position of the element at the coordinate when
+    /// the elements of a grid of the given size are laid out row by row in a
+    /// single dimension, which is `y * width + x`. It's the index into flat
+    /// buffers, bitsets or the memory of external libraries.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the grid
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, coord, size};
+    /// #
+    /// assert_eq!(coord!(0, 0).to_index(size!(3, 2)), 0);
+    /// assert_eq!(coord!(2, 0).to_index(size!(3, 2)), 2);
+    /// assert_eq!(coord!(1, 1).to_index(size!(3, 2)), 4);
+    /// ```
+    ///
+    pub fn to_index(self, size: Size) -> usize {
+        self.checked_to_index(size).expect("index out of bounds")
+    }
+
+    /// Convert the coordinate to a linear index, if it's within bounds.
+    ///
+    /// This method is similar to `to_index()`, but returns `None` instead of
+    /// panicking if the coordinate is outside a grid of the given size.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, coord, size};
+    /// #
+    /// assert_eq!(coord!(1, 1).checked_to_index(size!(3, 2)), Some(4));
+    /// assert_eq!(coord!(3, 0).checked_to_index(size!(3, 2)), None);
+    /// assert_eq!(coord!(0, 2).checked_to_index(size!(3, 2)), None);
+    /// ```
+    ///
+    pub fn checked_to_index(self, size: Size) -> Option<usize> {
+        if size.contains(self) {
+            Some(self.y * size.width + self.x)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Construct a coordinate from a linear index.
+    ///
+    /// This function returns the coordinate of the element at a position when
+    /// the elements of a grid of the given size are laid out row by row in a
+    /// single dimension; it's the inverse of `to_index()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the element
+    /// * `size` - The size of the grid
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds (greater or equal to the area
+    /// of the size).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, coord, size};
+    /// #
+    /// assert_eq!(Coordinate::from_index(0, size!(3, 2)), coord!(0, 0));
+    /// assert_eq!(Coordinate::from_index(2, size!(3, 2)), coord!(2, 0));
+    /// assert_eq!(Coordinate::from_index(4, size!(3, 2)), coord!(1, 1));
+    /// ```
+    ///
+    pub fn from_index(index: usize, size: Size) -> Coordinate {
+        Coordinate::checked_from_index(index, size).expect("index out of bounds")
+    }
+
+    /// Construct a coordinate from a linear index, if it's within bounds.
+    ///
+    /// This function is similar to `from_index()`, but returns `None` instead
+    /// of panicking if the index is outside a grid of the given size.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The position of the element
+    /// * `size` - The size of the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, coord, size};
+    /// #
+    /// assert_eq!(Coordinate::checked_from_index(5, size!(3, 2)), Some(coord!(2, 1)));
+    /// assert_eq!(Coordinate::checked_from_index(6, size!(3, 2)), None);
+    /// assert_eq!(Coordinate::checked_from_index(0, size!(0, 2)), None);
+    /// ```
+    ///
+    pub fn checked_from_index(index: usize, size: Size) -> Option<Coordinate> {
+        if index < size.area() {
+            Some(Coordinate::new(index % size.width, index / size.width))
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl TryFrom<(i32, i32)> for Coordinate {
+    type Error = TryFromIntError;
+
+    fn try_from((x, y): (i32, i32)) -> Result<Self, Self::Error> {
+        Ok(Coordinate::new(usize::try_from(x)?, usize::try_from(y)?))
+    }
+}
+
+impl TryFrom<(isize, isize)> for Coordinate {
+    type Error = TryFromIntError;
+
+    fn try_from((x, y): (isize, isize)) -> Result<Self, Self::Error> {
+        Ok(Coordinate::new(usize::try_from(x)?, usize::try_from(y)?))
+    }
+}
+
+impl Ord for Coordinate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Row-major order; the Y axis has precedence over the X axis.
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Coordinate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<Offset> for Coordinate {
+    type Output = Coordinate;
+
+    fn add(self, offset: Offset) -> Self::Output {
+        // A coordinate can't be negative; going below zero is a logic error
+        // just like an integer overflow.
+        let x = self.x.checked_add_signed(offset.x).expect("coordinate overflow");
+        let y = self.y.checked_add_signed(offset.y).expect("coordinate overflow");
+
+        Coordinate::new(x, y)
+    }
+}
+
+impl Sub<Offset> for Coordinate {
+    type Output = Coordinate;
+
+    fn sub(self, offset: Offset) -> Self::Output {
+        // The offset isn't negated as its minimum value has no opposite.
+        let x = checked_sub_signed(self.x, offset.x).expect("coordinate overflow");
+        let y = checked_sub_signed(self.y, offset.y).expect("coordinate overflow");
+
+        Coordinate::new(x, y)
+    }
+}
+
+// Subtract a signed value from an unsigned one, returning None if the result
+// is out of range.
+fn checked_sub_signed(value: usize, offset: isize) -> Option<usize> {
+    if offset >= 0 {
+        value.checked_sub(offset as usize)
+    }
+    else {
+        value.checked_add(offset.unsigned_abs())
+    }
+}
+
+impl AddAssign<Offset> for Coordinate {
+    fn add_assign(&mut self, offset: Offset) {
+        *self = *self + offset;
+    }
+}
+
+impl SubAssign<Offset> for Coordinate {
+    fn sub_assign(&mut self, offset: Offset) {
+        *self = *self - offset;
+    }
+}
+
+impl Sub<Coordinate> for Coordinate {
+    type Output = Offset;
+
+    fn sub(self, other: Coordinate) -> Self::Output {
+        Offset::new(self.x as isize - other.x as isize,
+                    self.y as isize - other.y as isize)
+    }
 }
 
 /// A coordinate instantiation helper.
@@ -98,6 +383,24 @@ impl Coordinate {
 #[macro_export]
 macro_rules! coord {
     ($x:expr, $y:expr) => {
-        Coordinate::new($x, $y);
+        Coordinate::new($x, $y)
     };
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coordinate_sub_offset() {
+        assert_eq!(coord!(2, 3) - Offset::new(1, -1), coord!(1, 4));
+        assert_eq!(coord!(0, 0) - Offset::new(isize::MIN, 0), coord!(isize::MIN.unsigned_abs(), 0));
+        assert_eq!(coord!(usize::MAX, 0) - Offset::new(isize::MAX, 0), coord!(usize::MAX - isize::MAX as usize, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "coordinate overflow")]
+    fn coordinate_sub_offset_overflow() {
+        let _ = coord!(0, 0) - Offset::new(0, 1);
+    }
+}
@@ -0,0 +1,267 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+use crate::border::BorderPolicy;
+
+/// The neighborhoods of the elements of a grid
+///
+/// This enumeration lists the sets of elements that are considered adjacent
+/// to an element of a grid.
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Neighborhood {
+    /// The four elements sharing an edge with the element (also known as the
+    /// von Neumann neighborhood).
+    Four,
+
+    /// The eight elements sharing an edge or a corner with the element (also
+    /// known as the Moore neighborhood).
+    Eight
+}
+
+impl<T: Numeric> Grid<T> {
+
+    /// Return the gradient of the grid.
+    ///
+    /// This method returns a new grid with the partial derivatives of the
+    /// grid along the horizontal and vertical axes, computed with central
+    /// differences; the derivatives at the edges are computed with the
+    /// differences to the next element inward instead. The vertical axis
+    /// points downward.
+    ///
+    /// The derivatives are floating-point numbers, whatever the type of the
+    /// elements of the grid, as they can be fractional or negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0, 1, 4],
+    ///                                 vec![2, 3, 6]]);
+    ///
+    /// let gradient = grid.gradient();
+    /// assert_eq!(gradient[coord!(0, 0)], (1.0, 2.0));
+    /// assert_eq!(gradient[coord!(1, 0)], (2.0, 2.0));
+    /// assert_eq!(gradient[coord!(2, 1)], (3.0, 2.0));
+    /// ```
+    ///
+    pub fn gradient(&self) -> Grid<(f64, f64)> {
+        let (width, height) = (self.size().width, self.size().height);
+        let value = |x: usize, y: usize| self.row_slice(y)[x].to_f64();
+
+        let rows = (0..height).map(|y| {
+            let (top, bottom) = neighbors(y, height);
+
+            (0..width).map(|x| {
+                let (left, right) = neighbors(x, width);
+
+                let dx = difference(value(right, y) - value(left, y), right - left);
+                let dy = difference(value(x, bottom) - value(x, top), bottom - top);
+
+                (dx, dy)
+            }).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(width, rows)
+    }
+
+    /// Return the Laplacian of the grid.
+    ///
+    /// This method returns a new grid with the discrete Laplacian of the
+    /// grid, which is the sum of the differences between each element and its
+    /// neighbors. It's positive where the element is lower than its
+    /// neighbors, and negative where it's higher. It's a building block for
+    /// diffusion and sharpening.
+    ///
+    /// The grid is extended by repeating its edges; use
+    /// `laplacian_with_border()` to extend it differently. The values are
+    /// floating-point numbers, whatever the type of the elements of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `neighborhood` - The neighbors of the elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, Neighborhood, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0, 0, 0],
+    ///                                 vec![0, 1, 0],
+    ///                                 vec![0, 0, 0]]);
+    ///
+    /// let laplacian = grid.laplacian(Neighborhood::Four);
+    /// assert_eq!(laplacian[coord!(1, 1)], -4.0);
+    /// assert_eq!(laplacian[coord!(1, 0)], 1.0);
+    /// assert_eq!(laplacian[coord!(0, 0)], 0.0);
+    ///
+    /// let laplacian = grid.laplacian(Neighborhood::Eight);
+    /// assert_eq!(laplacian[coord!(1, 1)], -8.0);
+    /// assert_eq!(laplacian[coord!(0, 0)], 1.0);
+    /// ```
+    ///
+    pub fn laplacian(&self, neighborhood: Neighborhood) -> Grid<f64> {
+        self.laplacian_with_border(neighborhood, BorderPolicy::Clamp)
+    }
+
+    /// Return the Laplacian of the grid, with a border policy.
+    ///
+    /// This method is similar to `laplacian()`, but the neighbors outside of
+    /// the grid are given by a border policy. With the skip policy, the
+    /// elements on the edges are only compared to their neighbors inside the
+    /// grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `neighborhood` - The neighbors of the elements
+    /// * `border` - The policy for the elements outside of the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, Neighborhood, BorderPolicy, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 1, 1],
+    ///                                 vec![1, 1, 1]]);
+    ///
+    /// let laplacian = grid.laplacian_with_border(Neighborhood::Four, BorderPolicy::Constant(0));
+    /// assert_eq!(laplacian[coord!(0, 0)], -2.0);
+    /// assert_eq!(laplacian[coord!(1, 0)], -1.0);
+    ///
+    /// // The edges aren't different from their neighbors inside the grid.
+    /// let laplacian = grid.laplacian_with_border(Neighborhood::Four, BorderPolicy::Skip);
+    /// assert_eq!(laplacian[coord!(0, 0)], 0.0);
+    /// ```
+    ///
+    pub fn laplacian_with_border(&self, neighborhood: Neighborhood, border: BorderPolicy<T>) -> Grid<f64> {
+        let kernel = match neighborhood {
+            Neighborhood::Four => Grid::from_rows(vec![vec![0.0, 1.0, 0.0],
+                                                       vec![1.0, -4.0, 1.0],
+                                                       vec![0.0, 1.0, 0.0]]),
+            Neighborhood::Eight => Grid::from_rows(vec![vec![1.0, 1.0, 1.0],
+                                                        vec![1.0, -8.0, 1.0],
+                                                        vec![1.0, 1.0, 1.0]])
+        };
+
+        if let BorderPolicy::Skip = border {
+            // The weight of the element balances the weights of its neighbors
+            // that are inside the grid.
+            let counts = Grid::<f64>::with_size(self.size(), 1.0).convolve_as(&kernel, &BorderPolicy::Constant(0.0));
+            let sums = self.convolve_as::<f64>(&kernel, &border);
+
+            let rows = self.row_vectors().iter().zip(counts.row_vectors()).zip(sums.row_vectors())
+                .map(|((row, counts), sums)| {
+                    row.iter().zip(counts).zip(sums)
+                        .map(|((value, count), sum)| sum - count * value.to_f64())
+                        .collect()
+                })
+                .collect::<Vec<_>>();
+
+            return Grid::from_row_vectors(self.size().width, rows);
+        }
+
+        self.convolve_as(&kernel, &border)
+    }
+}
+
+// Return the neighbors of an element along an axis to compute its derivative;
+// the element itself stands in for its missing neighbor at the edges.
+fn neighbors(index: usize, length: usize) -> (usize, usize) {
+    (index.saturating_sub(1), (index + 1).min(length - 1))
+}
+
+// Compute the derivative from the difference between neighbors at a given
+// distance; there is no variation along an axis of a single element.
+fn difference(delta: f64, distance: usize) -> f64 {
+    if distance == 0 { 0.0 } else { delta / distance as f64 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinate::Coordinate;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_gradient() {
+        let grid = Grid::from_rows(vec![vec![1u8, 2, 4, 8],
+                                        vec![1, 0, 0, 0],
+                                        vec![5, 0, 0, 0]]);
+
+        let gradient = grid.gradient();
+        assert_eq!(gradient.size(), size!(4, 3));
+
+        assert_eq!(gradient.row(0).values(), vec![&(1.0, 0.0), &(1.5, -2.0), &(3.0, -4.0), &(4.0, -8.0)]);
+        assert_eq!(gradient.row(1).values(), vec![&(-1.0, 2.0), &(-0.5, -1.0), &(0.0, -2.0), &(0.0, -4.0)]);
+        assert_eq!(gradient.row(2).values(), vec![&(-5.0, 4.0), &(-2.5, 0.0), &(0.0, 0.0), &(0.0, 0.0)]);
+    }
+
+    #[test]
+    fn grid_gradient_single() {
+        let grid = Grid::from_rows(vec![vec![1.0, 3.0]]);
+        assert_eq!(grid.gradient().row(0).values(), vec![&(2.0, 0.0), &(2.0, 0.0)]);
+
+        let grid = Grid::with_size(size!(1, 1), 42);
+        assert_eq!(grid.gradient()[coord!(0, 0)], (0.0, 0.0));
+
+        assert_eq!(Grid::<i32>::zero().gradient().size(), size!(0, 0));
+    }
+
+    #[test]
+    fn grid_laplacian() {
+        let grid = Grid::from_rows(vec![vec![1u8, 2, 3],
+                                        vec![4, 5, 6],
+                                        vec![7, 8, 9]]);
+
+        // The Laplacian of a linear function is zero, except at the edges
+        // because they're repeated.
+        let laplacian = grid.laplacian(Neighborhood::Four);
+        assert_eq!(laplacian.row(0).values(), vec![&4.0, &3.0, &2.0]);
+        assert_eq!(laplacian.row(1).values(), vec![&1.0, &0.0, &-1.0]);
+        assert_eq!(laplacian.row(2).values(), vec![&-2.0, &-3.0, &-4.0]);
+
+        let laplacian = grid.laplacian(Neighborhood::Eight);
+        assert_eq!(laplacian.row(1).values(), vec![&3.0, &0.0, &-3.0]);
+        assert_eq!(laplacian[coord!(0, 0)], 12.0);
+
+        assert_eq!(Grid::<f32>::zero().laplacian(Neighborhood::Eight).size(), size!(0, 0));
+    }
+
+    #[test]
+    fn grid_laplacian_with_border() {
+        let grid = Grid::from_rows(vec![vec![1u8, 2, 3],
+                                        vec![4, 5, 6],
+                                        vec![7, 8, 9]]);
+
+        assert_eq!(grid.laplacian_with_border(Neighborhood::Eight, BorderPolicy::Clamp),
+                   grid.laplacian(Neighborhood::Eight));
+
+        let laplacian = grid.laplacian_with_border(Neighborhood::Four, BorderPolicy::Wrap);
+        assert_eq!(laplacian.row(0).values(), vec![&12.0, &9.0, &6.0]);
+        assert_eq!(laplacian.row(1).values(), vec![&3.0, &0.0, &-3.0]);
+
+        // The neighbors past the edges are the neighbors inside the grid.
+        let laplacian = grid.laplacian_with_border(Neighborhood::Four, BorderPolicy::Mirror);
+        assert_eq!(laplacian.row(0).values(), vec![&8.0, &6.0, &4.0]);
+
+        let laplacian = grid.laplacian_with_border(Neighborhood::Four, BorderPolicy::Constant(0));
+        assert_eq!(laplacian[coord!(1, 1)], 0.0);
+        assert_eq!(laplacian[coord!(0, 0)], 2.0);
+
+        // The elements are only compared to their neighbors in the grid.
+        let laplacian = grid.laplacian_with_border(Neighborhood::Eight, BorderPolicy::Skip);
+        assert_eq!(laplacian.row(0).values(), vec![&(11.0 - 3.0), &(19.0 - 10.0), &(13.0 - 9.0)]);
+
+        assert_eq!(Grid::<f32>::zero().laplacian_with_border(Neighborhood::Four, BorderPolicy::Skip).size(), size!(0, 0));
+    }
+}
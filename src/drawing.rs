@@ -0,0 +1,625 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::rect::Rect;
+use crate::grid::Grid;
+
+impl<T: Clone> Grid<T> {
+
+    /// Draw a line.
+    ///
+    /// This method sets the elements along the line between two coordinates,
+    /// both included, to a given value. The line is rasterized with the
+    /// Bresenham algorithm; the parts of the line outside of the grid are
+    /// clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The coordinate of one end of the line
+    /// * `b` - The coordinate of the other end of the line
+    /// * `value` - The value of the elements of the line
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(4, 3), 0);
+    /// grid.draw_line(coord!(0, 0), coord!(3, 2), 1);
+    ///
+    /// assert_eq!(grid.row(0).values(), vec![&1, &0, &0, &0]);
+    /// assert_eq!(grid.row(1).values(), vec![&0, &1, &1, &0]);
+    /// assert_eq!(grid.row(2).values(), vec![&0, &0, &0, &1]);
+    /// ```
+    ///
+    pub fn draw_line(&mut self, a: Coordinate, b: Coordinate, value: T) {
+        let steps = a.x.abs_diff(b.x).max(a.y.abs_diff(b.y)) as u128;
+
+        // Only walk the steps where the line is within the grid, so lines
+        // reaching far outside of it are clipped without being walked.
+        let size = self.size();
+        let columns = line_steps(a.x, b.x, steps, size.width);
+        let rows = line_steps(a.y, b.y, steps, size.height);
+
+        if let (Some((first_column, last_column)), Some((first_row, last_row))) = (columns, rows) {
+            for step in first_column.max(first_row)..=last_column.min(last_row) {
+                let x = line_position(a.x, b.x, steps, step);
+                let y = line_position(a.y, b.y, steps, step);
+
+                self[Coordinate::new(x, y)] = value.clone();
+            }
+        }
+    }
+
+    /// Draw the outline of a rectangle.
+    ///
+    /// This method sets the elements on the edges of a rectangle to a given
+    /// value, leaving the elements inside untouched. The parts of the
+    /// rectangle outside of the grid are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The rectangle to draw
+    /// * `value` - The value of the elements of the outline
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Rect, Grid, rect, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(4, 4), 0);
+    /// grid.draw_rect(rect!(0, 0, 4, 3), 1);
+    ///
+    /// assert_eq!(grid.row(0).values(), vec![&1, &1, &1, &1]);
+    /// assert_eq!(grid.row(1).values(), vec![&1, &0, &0, &1]);
+    /// assert_eq!(grid.row(2).values(), vec![&1, &1, &1, &1]);
+    /// assert_eq!(grid.row(3).values(), vec![&0, &0, &0, &0]);
+    /// ```
+    ///
+    pub fn draw_rect(&mut self, rect: Rect, value: T) {
+        if rect.size.is_empty() {
+            return;
+        }
+
+        // The right and bottom edges may be past the largest coordinates, in
+        // which case they're outside of the grid anyway.
+        let (left, top) = (rect.coordinate.x, rect.coordinate.y);
+        let right = left.checked_add(rect.size.width - 1);
+        let bottom = top.checked_add(rect.size.height - 1);
+        let (last_x, last_y) = (right.unwrap_or(usize::MAX), bottom.unwrap_or(usize::MAX));
+
+        self.draw_line(Coordinate::new(left, top), Coordinate::new(last_x, top), value.clone());
+        self.draw_line(Coordinate::new(left, top), Coordinate::new(left, last_y), value.clone());
+        if let Some(bottom) = bottom {
+            self.draw_line(Coordinate::new(left, bottom), Coordinate::new(last_x, bottom), value.clone());
+        }
+        if let Some(right) = right {
+            self.draw_line(Coordinate::new(right, top), Coordinate::new(right, last_y), value);
+        }
+    }
+
+    /// Draw the outline of a circle.
+    ///
+    /// This method sets the elements on the outline of a circle to a given
+    /// value, leaving the elements inside untouched. The circle is rasterized
+    /// with the midpoint algorithm; the parts of the circle outside of the
+    /// grid are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The coordinate of the center of the circle
+    /// * `radius` - The radius of the circle
+    /// * `value` - The value of the elements of the outline
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(5, 5), 0);
+    /// grid.draw_circle(coord!(2, 2), 2, 1);
+    ///
+    /// assert_eq!(grid.row(0).values(), vec![&0, &1, &1, &1, &0]);
+    /// assert_eq!(grid.row(1).values(), vec![&1, &0, &0, &0, &1]);
+    /// assert_eq!(grid.row(2).values(), vec![&1, &0, &0, &0, &1]);
+    /// assert_eq!(grid.row(3).values(), vec![&1, &0, &0, &0, &1]);
+    /// assert_eq!(grid.row(4).values(), vec![&0, &1, &1, &1, &0]);
+    /// ```
+    ///
+    pub fn draw_circle(&mut self, center: Coordinate, radius: usize, value: T) {
+        let center_x = center.x as i128;
+
+        // Each visible row is rasterized on its own, so circles reaching far
+        // outside of the grid aren't walked entirely.
+        for (y, offset) in circle_rows(center, radius, self.size().height) {
+            for &(first, last) in circle_row(radius as u128, offset).iter().flatten() {
+                let (first, last) = (first as i128, last as i128);
+
+                self.fill_span(y as i128, center_x - last, center_x - first, &value);
+                self.fill_span(y as i128, center_x + first, center_x + last, &value);
+            }
+        }
+    }
+
+    /// Draw a filled circle.
+    ///
+    /// This method sets the elements on and inside the outline of a circle to
+    /// a given value. The circle is filled with horizontal spans between the
+    /// points of the outline drawn by `draw_circle()`; the parts of the circle
+    /// outside of the grid are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The coordinate of the center of the circle
+    /// * `radius` - The radius of the circle
+    /// * `value` - The value of the elements of the circle
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(5, 5), 0);
+    /// grid.fill_circle(coord!(2, 2), 2, 1);
+    ///
+    /// assert_eq!(grid.row(0).values(), vec![&0, &1, &1, &1, &0]);
+    /// assert_eq!(grid.row(1).values(), vec![&1, &1, &1, &1, &1]);
+    /// assert_eq!(grid.row(2).values(), vec![&1, &1, &1, &1, &1]);
+    /// assert_eq!(grid.row(3).values(), vec![&1, &1, &1, &1, &1]);
+    /// assert_eq!(grid.row(4).values(), vec![&0, &1, &1, &1, &0]);
+    /// ```
+    ///
+    pub fn fill_circle(&mut self, center: Coordinate, radius: usize, value: T) {
+        let center_x = center.x as i128;
+
+        // Same as drawing the outline, but with a span between the mirrored
+        // elements of each visible row.
+        for (y, offset) in circle_rows(center, radius, self.size().height) {
+            let half = circle_row(radius as u128, offset).iter().flatten().map(|&(_, last)| last).max();
+            if let Some(half) = half {
+                self.fill_span(y as i128, center_x - half as i128, center_x + half as i128, &value);
+            }
+        }
+    }
+
+    /// Draw a filled polygon.
+    ///
+    /// This method sets the elements on and inside a polygon to a given
+    /// value. The polygon is closed, its last vertex being connected to its
+    /// first one, and may be concave or self-intersecting, in which case the
+    /// even-odd rule tells what's inside. It's filled scanline by scanline;
+    /// the parts of the polygon outside of the grid are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - The coordinates of the vertices of the polygon
+    /// * `value` - The value of the elements of the polygon
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(5, 4), 0);
+    /// grid.fill_polygon(&[coord!(0, 0), coord!(4, 0), coord!(0, 3)], 1);
+    ///
+    /// assert_eq!(grid.row(0).values(), vec![&1, &1, &1, &1, &1]);
+    /// assert_eq!(grid.row(1).values(), vec![&1, &1, &1, &1, &0]);
+    /// assert_eq!(grid.row(2).values(), vec![&1, &1, &1, &0, &0]);
+    /// assert_eq!(grid.row(3).values(), vec![&1, &0, &0, &0, &0]);
+    /// ```
+    ///
+    pub fn fill_polygon(&mut self, vertices: &[Coordinate], value: T) {
+        if vertices.is_empty() {
+            return;
+        }
+
+        let edges = || vertices.iter().zip(vertices.iter().cycle().skip(1));
+
+        let bottom = vertices.iter().map(|vertex| vertex.y).max().unwrap();
+        let top = vertices.iter().map(|vertex| vertex.y).min().unwrap();
+
+        // The intersections of the visible scanlines with the edges, as mixed
+        // numbers; an edge includes its top end but not its bottom end so
+        // vertices are counted once.
+        let mut intersections = Vec::new();
+        for y in top..=bottom.min(self.size().height.saturating_sub(1)) {
+            intersections.clear();
+            for (a, b) in edges() {
+                let (a, b) = if a.y < b.y { (a, b) } else { (b, a) };
+                if a.y <= y && y < b.y {
+                    intersections.push(edge_intersection(*a, *b, y));
+                }
+            }
+
+            intersections.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| (a.1 * b.2).cmp(&(b.1 * a.2))));
+            for pair in intersections.chunks_exact(2) {
+                let left = pair[0].0 + i128::from(pair[0].1 > 0);
+                let right = pair[1].0;
+
+                self.fill_span(y as i128, left, right, &value);
+            }
+        }
+
+        // The scanlines leave out some elements on the edges; draw them too.
+        for (&a, &b) in edges() {
+            self.draw_line(a, b, value.clone());
+        }
+    }
+
+    // Set the elements of a row between two positions, both included, to a
+    // value; the parts outside of the grid are clipped.
+    fn fill_span(&mut self, y: i128, left: i128, right: i128, value: &T) {
+        let size = self.size();
+        if y < 0 || y >= size.height as i128 || right < 0 || left >= size.width as i128 {
+            return;
+        }
+
+        let left = left.max(0) as usize;
+        let right = right.min(size.width as i128 - 1) as usize;
+
+        if left <= right {
+            self.row_slice_mut(y as usize)[left..=right].fill(value.clone());
+        }
+    }
+}
+
+// Return the offset, along an axis, of a step of a line from its start; it's
+// the distance covered at this step, rounded half up, which is what the
+// Bresenham algorithm computes incrementally.
+fn line_offset(distance: u128, steps: u128, step: u128) -> u128 {
+    if steps == 0 {
+        return 0;
+    }
+
+    let (quotient, remainder) = (step * distance / steps, step * distance % steps);
+    quotient + u128::from(2 * remainder >= steps)
+}
+
+// Return the position, along an axis, of a step of a line.
+fn line_position(start: usize, end: usize, steps: u128, step: u128) -> usize {
+    let offset = line_offset(start.abs_diff(end) as u128, steps, step) as usize;
+    if start <= end { start + offset } else { start - offset }
+}
+
+// Return the first and last steps of a line whose positions along an axis
+// are within its length, if any.
+fn line_steps(start: usize, end: usize, steps: u128, length: usize) -> Option<(u128, u128)> {
+    let last = length.checked_sub(1)?;
+    let distance = start.abs_diff(end) as u128;
+
+    // The range of offsets that are within the axis.
+    let (minimum, maximum) = if start <= end {
+        (0, last.checked_sub(start)? as u128)
+    }
+    else {
+        (start.saturating_sub(last) as u128, start as u128)
+    };
+
+    // The offsets never decrease along the line, so the steps are found by
+    // bisection.
+    let first_step = |offset: u128| {
+        let (mut low, mut high) = (0, steps + 1);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if line_offset(distance, steps, middle) >= offset {
+                high = middle;
+            }
+            else {
+                low = middle + 1;
+            }
+        }
+
+        low
+    };
+
+    let (first, end) = (first_step(minimum), first_step(maximum + 1));
+    if first < end { Some((first, end - 1)) } else { None }
+}
+
+// Return the position, along the row, of the intersection of an edge with a
+// row between its ends, as a whole part and a fraction with a numerator and a
+// denominator; it's computed without overflowing for any coordinates.
+fn edge_intersection(a: Coordinate, b: Coordinate, y: usize) -> (i128, u128, u128) {
+    let denominator = (b.y - a.y) as u128;
+    let distance = (y - a.y) as u128 * a.x.abs_diff(b.x) as u128;
+    let (quotient, remainder) = ((distance / denominator) as i128, distance % denominator);
+
+    if a.x <= b.x {
+        (a.x as i128 + quotient, remainder, denominator)
+    }
+    else if remainder == 0 {
+        (a.x as i128 - quotient, 0, denominator)
+    }
+    else {
+        (a.x as i128 - quotient - 1, denominator - remainder, denominator)
+    }
+}
+
+// Return the rows of a grid within the radius of a circle, along with their
+// offsets from its center.
+fn circle_rows(center: Coordinate, radius: usize, height: usize) -> impl Iterator<Item = (usize, u128)> {
+    let first = center.y.saturating_sub(radius);
+    let end = center.y.saturating_add(radius).saturating_add(1).min(height);
+
+    (first..end).map(move |y| (y, y.abs_diff(center.y) as u128))
+}
+
+// Return the offset of the outline of a circle along an axis, at an offset
+// along the other axis, in the octant computed by the midpoint algorithm; it's
+// the largest x such that x² - x + y² < r².
+fn circle_offset(radius: u128, offset: u128) -> u128 {
+    match (radius * radius).checked_sub(offset * offset + 1) {
+        Some(limit) => {
+            let root = limit.isqrt();
+            if root * (root + 1) <= limit { root + 1 } else { root }
+        },
+        None => 0
+    }
+}
+
+// Return the ranges of offsets, along a row, of the elements of the outline
+// of a circle, the row being at an offset from its center; the outline is
+// mirrored on both sides of the center.
+fn circle_row(radius: u128, offset: u128) -> [Option<(u128, u128)>; 2] {
+    // The element of the octant that's on this row, if any.
+    let outline = circle_offset(radius, offset);
+    let first = if outline >= offset { Some((outline, outline)) } else { None };
+
+    // The elements of the mirrored octant, which are the offsets whose
+    // outline is on this row; the outline never moves outward, so they're
+    // found by bisection.
+    let bisect = |limit: u128| {
+        let (mut low, mut high) = (0, radius + 1);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if circle_offset(radius, middle) < limit {
+                high = middle;
+            }
+            else {
+                low = middle + 1;
+            }
+        }
+
+        low
+    };
+
+    let (low, high) = (bisect(offset + 1), (bisect(offset) - 1).min(offset));
+    let second = if low <= high { Some((low, high)) } else { None };
+
+    [first, second]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use crate::size::Size;
+    use crate::grid_iterator::GridIterator;
+
+    // Return the coordinates of the elements that are set.
+    fn plotted(grid: &Grid<u8>) -> Vec<Coordinate> {
+        grid.iterator().enumerate_coordinate()
+            .filter(|&(_, &value)| value == 1)
+            .map(|(coordinate, _)| coordinate)
+            .collect()
+    }
+
+    #[test]
+    fn grid_draw_line() {
+        let mut grid = Grid::with_size(size!(5, 5), 0);
+        grid.draw_line(coord!(1, 3), coord!(1, 0), 1);
+        assert_eq!(plotted(&grid), vec![coord!(1, 0), coord!(1, 1), coord!(1, 2), coord!(1, 3)]);
+
+        let mut grid = Grid::with_size(size!(5, 5), 0);
+        grid.draw_line(coord!(4, 4), coord!(0, 0), 1);
+        assert_eq!(plotted(&grid), vec![coord!(0, 0), coord!(1, 1), coord!(2, 2), coord!(3, 3), coord!(4, 4)]);
+
+        let mut grid = Grid::with_size(size!(5, 5), 0);
+        grid.draw_line(coord!(0, 1), coord!(4, 2), 1);
+        assert_eq!(plotted(&grid), vec![coord!(0, 1), coord!(1, 1), coord!(2, 2), coord!(3, 2), coord!(4, 2)]);
+
+        let mut grid = Grid::with_size(size!(5, 5), 0);
+        grid.draw_line(coord!(2, 2), coord!(2, 2), 1);
+        assert_eq!(plotted(&grid), vec![coord!(2, 2)]);
+    }
+
+    #[test]
+    fn grid_draw_line_clipped() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_line(coord!(0, 0), coord!(5, 5), 1);
+        assert_eq!(plotted(&grid), vec![coord!(0, 0), coord!(1, 1), coord!(2, 2)]);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_line(coord!(4, 0), coord!(4, 2), 1);
+        assert!(plotted(&grid).is_empty());
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_line(coord!(0, 0), coord!(usize::MAX, 0), 1);
+        assert_eq!(plotted(&grid), vec![coord!(0, 0), coord!(1, 0), coord!(2, 0)]);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_line(coord!(usize::MAX, usize::MAX), coord!(0, 2), 1);
+        assert_eq!(plotted(&grid), vec![coord!(0, 2)]);
+
+        // Far away ends aren't walked.
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_line(coord!(0, 0), coord!(20_000_000_000, 1), 1);
+        assert_eq!(plotted(&grid), vec![coord!(0, 0), coord!(1, 0), coord!(2, 0)]);
+
+        // The visible part is the same as if the line was walked entirely.
+        let mut grid = Grid::with_size(size!(5, 5), 0);
+        grid.draw_line(coord!(7, 0), coord!(0, 3), 1);
+        assert_eq!(plotted(&grid), vec![coord!(4, 1), coord!(2, 2), coord!(3, 2), coord!(0, 3), coord!(1, 3)]);
+    }
+
+    #[test]
+    fn grid_draw_rect() {
+        let mut grid = Grid::with_size(size!(5, 4), 0);
+        grid.draw_rect(rect!(1, 1, 3, 3), 1);
+
+        assert_eq!(grid.row(0).values(), vec![&0, &0, &0, &0, &0]);
+        assert_eq!(grid.row(1).values(), vec![&0, &1, &1, &1, &0]);
+        assert_eq!(grid.row(2).values(), vec![&0, &1, &0, &1, &0]);
+        assert_eq!(grid.row(3).values(), vec![&0, &1, &1, &1, &0]);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_rect(rect!(1, 1, 1, 1), 1);
+        assert_eq!(plotted(&grid), vec![coord!(1, 1)]);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_rect(rect!(1, 1, 0, 2), 1);
+        assert!(plotted(&grid).is_empty());
+    }
+
+    #[test]
+    fn grid_draw_rect_clipped() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_rect(rect!(1, 1, 5, 5), 1);
+
+        assert_eq!(grid.row(0).values(), vec![&0, &0, &0]);
+        assert_eq!(grid.row(1).values(), vec![&0, &1, &1]);
+        assert_eq!(grid.row(2).values(), vec![&0, &1, &0]);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_rect(rect!(1, 1, usize::MAX, 2), 1);
+
+        assert_eq!(grid.row(0).values(), vec![&0, &0, &0]);
+        assert_eq!(grid.row(1).values(), vec![&0, &1, &1]);
+        assert_eq!(grid.row(2).values(), vec![&0, &1, &1]);
+    }
+
+    #[test]
+    fn grid_draw_circle() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_circle(coord!(1, 1), 0, 1);
+        assert_eq!(plotted(&grid), vec![coord!(1, 1)]);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_circle(coord!(1, 1), 1, 1);
+        assert_eq!(plotted(&grid), vec![coord!(1, 0), coord!(0, 1), coord!(2, 1), coord!(1, 2)]);
+
+        // The outline is symmetric.
+        let mut grid = Grid::with_size(size!(11, 11), 0);
+        grid.draw_circle(coord!(5, 5), 5, 1);
+        assert!(grid.is_symmetric_horizontal());
+        assert!(grid.is_symmetric_vertical());
+        assert_eq!(grid[coord!(5, 5)], 0);
+        assert_eq!(grid[coord!(0, 5)], 1);
+        assert_eq!(grid[coord!(5, 10)], 1);
+    }
+
+    #[test]
+    fn grid_draw_circle_clipped() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_circle(coord!(0, 0), 2, 1);
+
+        assert_eq!(grid.row(0).values(), vec![&0, &0, &1]);
+        assert_eq!(grid.row(1).values(), vec![&0, &0, &1]);
+        assert_eq!(grid.row(2).values(), vec![&1, &1, &0]);
+
+        // Far away outlines aren't walked.
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_circle(coord!(1, 1), 20_000_000_000, 1);
+        assert!(plotted(&grid).is_empty());
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.draw_circle(coord!(20_000_000_000, 1), 19_999_999_999, 1);
+        assert_eq!(plotted(&grid), vec![coord!(1, 0), coord!(1, 1), coord!(1, 2)]);
+    }
+
+    #[test]
+    fn grid_fill_circle() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.fill_circle(coord!(1, 1), 0, 1);
+        assert_eq!(plotted(&grid), vec![coord!(1, 1)]);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.fill_circle(coord!(1, 1), 1, 1);
+        assert_eq!(plotted(&grid), vec![coord!(1, 0), coord!(0, 1), coord!(1, 1), coord!(2, 1), coord!(1, 2)]);
+
+        // The filled circle covers its outline.
+        let mut outline = Grid::with_size(size!(11, 11), 0);
+        outline.draw_circle(coord!(5, 5), 5, 1);
+
+        let mut grid = Grid::with_size(size!(11, 11), 0);
+        grid.fill_circle(coord!(5, 5), 5, 1);
+        assert!(grid.all(|coordinate, &value| outline[coordinate] == 0 || value == 1));
+        assert!(grid.is_symmetric_horizontal());
+        assert!(grid.is_symmetric_vertical());
+        assert_eq!(grid.row(5).values(), vec![&1; 11]);
+    }
+
+    #[test]
+    fn grid_fill_circle_clipped() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.fill_circle(coord!(0, 0), 2, 1);
+
+        assert_eq!(grid.row(0).values(), vec![&1, &1, &1]);
+        assert_eq!(grid.row(1).values(), vec![&1, &1, &1]);
+        assert_eq!(grid.row(2).values(), vec![&1, &1, &0]);
+
+        // Far away outlines aren't walked.
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.fill_circle(coord!(1, 1), 20_000_000_000, 1);
+        assert_eq!(plotted(&grid).len(), 9);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.fill_circle(coord!(20_000_000_001, 1), 20_000_000_000, 1);
+        assert_eq!(plotted(&grid), vec![coord!(1, 0), coord!(2, 0), coord!(1, 1), coord!(2, 1), coord!(1, 2), coord!(2, 2)]);
+    }
+
+    #[test]
+    fn grid_fill_polygon() {
+        let mut grid = Grid::with_size(size!(5, 5), 0);
+        grid.fill_polygon(&[coord!(1, 1), coord!(3, 1), coord!(3, 3), coord!(1, 3)], 1);
+
+        assert_eq!(grid.row(0).values(), vec![&0, &0, &0, &0, &0]);
+        assert_eq!(grid.row(1).values(), vec![&0, &1, &1, &1, &0]);
+        assert_eq!(grid.row(2).values(), vec![&0, &1, &1, &1, &0]);
+        assert_eq!(grid.row(3).values(), vec![&0, &1, &1, &1, &0]);
+        assert_eq!(grid.row(4).values(), vec![&0, &0, &0, &0, &0]);
+
+        // A concave polygon.
+        let mut grid = Grid::with_size(size!(5, 5), 0);
+        grid.fill_polygon(&[coord!(0, 0), coord!(4, 0), coord!(4, 4), coord!(2, 2), coord!(0, 4)], 1);
+
+        assert_eq!(grid.row(2).values(), vec![&1, &1, &1, &1, &1]);
+        assert_eq!(grid.row(3).values(), vec![&1, &1, &0, &1, &1]);
+        assert_eq!(grid.row(4).values(), vec![&1, &0, &0, &0, &1]);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.fill_polygon(&[coord!(1, 1)], 1);
+        assert_eq!(plotted(&grid), vec![coord!(1, 1)]);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.fill_polygon(&[], 1);
+        assert!(plotted(&grid).is_empty());
+    }
+
+    #[test]
+    fn grid_fill_polygon_clipped() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.fill_polygon(&[coord!(1, 1), coord!(9, 1), coord!(9, 9), coord!(1, 9)], 1);
+
+        assert_eq!(grid.row(0).values(), vec![&0, &0, &0]);
+        assert_eq!(grid.row(1).values(), vec![&0, &1, &1]);
+        assert_eq!(grid.row(2).values(), vec![&0, &1, &1]);
+
+        // Far away vertices don't overflow.
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.fill_polygon(&[coord!(0, 0), coord!(1 << 40, 0), coord!(0, 1 << 40)], 1);
+        assert_eq!(plotted(&grid).len(), 9);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.fill_polygon(&[coord!(0, 0), coord!(usize::MAX, usize::MAX), coord!(0, usize::MAX)], 1);
+        assert_eq!(plotted(&grid), vec![coord!(0, 0), coord!(0, 1), coord!(1, 1), coord!(0, 2), coord!(1, 2), coord!(2, 2)]);
+    }
+}
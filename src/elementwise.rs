@@ -0,0 +1,328 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::cmp::Ordering;
+use alloc::vec::Vec;
+use crate::grid::Grid;
+
+impl<T: Clone> Grid<T> {
+
+    /// Compare the elements of the grid with the elements of another grid.
+    ///
+    /// This method returns a grid telling, for each element, whether it's
+    /// equal to the element of another grid of the same size. It's useful to
+    /// visualize the differences between two grids or to build masks.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The grid to compare with
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grids don't have the same size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let a = Grid::from_rows(vec![vec![1, 2],
+    ///                              vec![3, 4]]);
+    /// let b = Grid::from_rows(vec![vec![1, 0],
+    ///                              vec![3, 0]]);
+    ///
+    /// let equal = a.eq_elementwise(&b);
+    /// assert_eq!(equal.row(0).values(), vec![&true, &false]);
+    /// assert_eq!(equal.row(1).values(), vec![&true, &false]);
+    /// ```
+    ///
+    pub fn eq_elementwise(&self, other: &Grid<T>) -> Grid<bool> where T: PartialEq {
+        self.zip_with(other, |a, b| a == b)
+    }
+
+    /// Order the elements of the grid against the elements of another grid.
+    ///
+    /// This method returns a grid with the ordering of each element against
+    /// the element of another grid of the same size.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The grid to compare with
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grids don't have the same size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = Grid::from_rows(vec![vec![1, 2, 3]]);
+    /// let b = Grid::from_rows(vec![vec![2, 2, 2]]);
+    ///
+    /// let ordering = a.cmp_elementwise(&b);
+    /// assert_eq!(ordering.row(0).values(), vec![&Ordering::Less, &Ordering::Equal, &Ordering::Greater]);
+    /// ```
+    ///
+    pub fn cmp_elementwise(&self, other: &Grid<T>) -> Grid<Ordering> where T: Ord {
+        self.zip_with(other, |a, b| a.cmp(b))
+    }
+
+    /// Compare the grid with another grid using a comparator.
+    ///
+    /// This method returns true if the grid and another grid have the same
+    /// size and if a comparator returns true for all pairs of elements at the
+    /// same coordinate. It allows to compare grids whose elements don't
+    /// implement `PartialEq`, or with a tolerance.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The grid to compare with
+    /// * `function` - The function telling whether two elements are equal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let a = Grid::from_rows(vec![vec![0.1f64 + 0.2, 1.0]]);
+    /// let b = Grid::from_rows(vec![vec![0.3, 1.0]]);
+    ///
+    /// assert!(a != b);
+    /// assert!(a.eq_by(&b, |a, b| (a - b).abs() < 1e-9));
+    /// ```
+    ///
+    pub fn eq_by<U, F>(&self, other: &Grid<U>, mut function: F) -> bool
+        where U: Clone, F: FnMut(&T, &U) -> bool
+    {
+        self.size() == other.size() && self.row_vectors().iter().zip(other.row_vectors()).all(|(a, b)| {
+            a.iter().zip(b).all(|(a, b)| function(a, b))
+        })
+    }
+
+    /// Combine the grid with another grid in place.
+    ///
+    /// This method replaces each element of the grid with the result of a
+    /// function given the element and the element of another grid of the same
+    /// size, without allocating an intermediate grid. It's useful to
+    /// accumulate maps, by taking the maximum or summing them for instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The grid to combine with
+    /// * `function` - The function combining two elements
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grids don't have the same size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut damage = Grid::from_rows(vec![vec![1, 0],
+    ///                                       vec![0, 2]]);
+    /// let hits = Grid::from_rows(vec![vec![3, 0],
+    ///                                 vec![1, 0]]);
+    ///
+    /// damage.merge_with(&hits, |mine, theirs| mine + theirs);
+    /// assert_eq!(damage.row(0).values(), vec![&4, &0]);
+    /// assert_eq!(damage.row(1).values(), vec![&1, &2]);
+    /// ```
+    ///
+    pub fn merge_with<U, F>(&mut self, other: &Grid<U>, mut function: F)
+        where U: Clone, F: FnMut(&T, &U) -> T
+    {
+        assert_eq!(self.size(), other.size(), "grid sizes don't match");
+
+        for (index, theirs) in other.row_vectors().iter().enumerate() {
+            for (mine, theirs) in self.row_slice_mut(index).iter_mut().zip(theirs) {
+                *mine = function(mine, theirs);
+            }
+        }
+    }
+
+    /// Construct a grid by picking elements from two grids.
+    ///
+    /// This function constructs a grid whose elements are picked from one
+    /// grid or another according to a mask; an element is taken from the
+    /// first grid where the mask is true, and from the second grid otherwise.
+    /// It's the two-dimensional analogue of a conditional move.
+    ///
+    /// # Arguments
+    ///
+    /// * `mask` - The grid telling which grid each element is picked from
+    /// * `a` - The grid of the elements picked where the mask is true
+    /// * `b` - The grid of the elements picked where the mask is false
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grids don't have the same size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mask = Grid::from_rows(vec![vec![true, false],
+    ///                                 vec![false, true]]);
+    /// let a = Grid::from_rows(vec![vec![1, 2],
+    ///                              vec![3, 4]]);
+    /// let b = Grid::from_rows(vec![vec![5, 6],
+    ///                              vec![7, 8]]);
+    ///
+    /// let grid = Grid::select(&mask, &a, &b);
+    /// assert_eq!(grid.row(0).values(), vec![&1, &6]);
+    /// assert_eq!(grid.row(1).values(), vec![&7, &4]);
+    /// ```
+    ///
+    pub fn select(mask: &Grid<bool>, a: &Grid<T>, b: &Grid<T>) -> Grid<T> {
+        assert_eq!(mask.size(), a.size(), "grid sizes don't match");
+        assert_eq!(mask.size(), b.size(), "grid sizes don't match");
+
+        let rows = mask.row_vectors().iter().zip(a.row_vectors()).zip(b.row_vectors());
+        let rows = rows.map(|((mask, a), b)| {
+            mask.iter().zip(a).zip(b).map(|((&selected, a), b)| {
+                if selected { a.clone() } else { b.clone() }
+            }).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(mask.size().width, rows)
+    }
+
+    // Combine the elements of the grid with the elements of another grid of
+    // the same size into a new grid.
+    pub(crate) fn zip_with<U, V, F>(&self, other: &Grid<U>, mut function: F) -> Grid<V>
+        where U: Clone, V: Clone, F: FnMut(&T, &U) -> V
+    {
+        assert_eq!(self.size(), other.size(), "grid sizes don't match");
+
+        let rows = self.row_vectors().iter().zip(other.row_vectors()).map(|(a, b)| {
+            a.iter().zip(b).map(|(a, b)| function(a, b)).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(self.size().width, rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_eq_elementwise() {
+        let a = Grid::from_rows(vec![vec!['a', 'b', 'c'],
+                                     vec!['d', 'e', 'f']]);
+        let b = Grid::from_rows(vec![vec!['a', 'x', 'c'],
+                                     vec!['x', 'e', 'x']]);
+
+        let equal = a.eq_elementwise(&b);
+        assert_eq!(equal.size(), size!(3, 2));
+        assert_eq!(equal.row(0).values(), vec![&true, &false, &true]);
+        assert_eq!(equal.row(1).values(), vec![&false, &true, &false]);
+
+        assert!(a.eq_elementwise(&a).all(|_, &value| value));
+    }
+
+    #[test]
+    fn grid_cmp_elementwise() {
+        let a = Grid::from_rows(vec![vec![1, 5],
+                                     vec![3, 3]]);
+        let b = Grid::from_rows(vec![vec![2, 4],
+                                     vec![3, 9]]);
+
+        let ordering = a.cmp_elementwise(&b);
+        assert_eq!(ordering.row(0).values(), vec![&Ordering::Less, &Ordering::Greater]);
+        assert_eq!(ordering.row(1).values(), vec![&Ordering::Equal, &Ordering::Less]);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid sizes don't match")]
+    fn grid_eq_elementwise_mismatching_sizes() {
+        let a = Grid::with_size(size!(2, 2), 0);
+        let b = Grid::with_size(size!(3, 2), 0);
+
+        a.eq_elementwise(&b);
+    }
+
+    #[test]
+    fn grid_eq_by() {
+        // An element type without PartialEq.
+        #[derive(Clone)]
+        struct Cell(u8);
+
+        let a = Grid::from_rows(vec![vec![Cell(1), Cell(2)],
+                                     vec![Cell(3), Cell(4)]]);
+        let b = Grid::from_rows(vec![vec![1, 2],
+                                     vec![3, 4]]);
+
+        assert!(a.eq_by(&b, |a, &b| a.0 == b));
+        assert!(!a.eq_by(&b, |a, &b| a.0 == b && b < 4));
+
+        let c = Grid::from_rows(vec![vec![1, 2]]);
+        assert!(!a.eq_by(&c, |_, _| true));
+        assert!(Grid::<u8>::zero().eq_by(&Grid::<u8>::zero(), |_, _| false));
+    }
+
+    #[test]
+    fn grid_merge_with() {
+        let mut grid = Grid::from_rows(vec![vec![1, 5, 3],
+                                            vec![4, 2, 6]]);
+        let other = Grid::from_rows(vec![vec![2, 2, 2],
+                                         vec![2, 2, 7]]);
+
+        grid.merge_with(&other, |&mine, &theirs| mine.max(theirs));
+        assert_eq!(grid.row(0).values(), vec![&2, &5, &3]);
+        assert_eq!(grid.row(1).values(), vec![&4, &2, &7]);
+
+        let labels = Grid::from_rows(vec![vec!["a", "b", "c"],
+                                          vec!["d", "e", "f"]]);
+
+        grid.merge_with(&labels, |&mine, theirs| mine * theirs.len() as i32);
+        assert_eq!(grid.row(0).values(), vec![&2, &5, &3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid sizes don't match")]
+    fn grid_merge_with_mismatching_sizes() {
+        let mut grid = Grid::with_size(size!(2, 2), 0);
+        let other = Grid::with_size(size!(2, 3), 0);
+
+        grid.merge_with(&other, |&mine, &theirs| mine + theirs);
+    }
+
+    #[test]
+    fn grid_select() {
+        let mask = Grid::from_rows(vec![vec![true, true, false],
+                                        vec![false, false, true]]);
+        let a = Grid::with_size(size!(3, 2), 'a');
+        let b = Grid::with_size(size!(3, 2), 'b');
+
+        let grid = Grid::select(&mask, &a, &b);
+        assert_eq!(grid.row(0).values(), vec![&'a', &'a', &'b']);
+        assert_eq!(grid.row(1).values(), vec![&'b', &'b', &'a']);
+
+        let mask = Grid::<bool>::zero();
+        assert_eq!(Grid::<u8>::select(&mask, &Grid::zero(), &Grid::zero()).size(), size!(0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "grid sizes don't match")]
+    fn grid_select_mismatching_sizes() {
+        let mask = Grid::with_size(size!(2, 2), true);
+        let a = Grid::with_size(size!(2, 2), 0);
+        let b = Grid::with_size(size!(2, 1), 0);
+
+        Grid::select(&mask, &a, &b);
+    }
+}
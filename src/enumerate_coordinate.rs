@@ -6,6 +6,7 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
+use core::iter::{ExactSizeIterator, FusedIterator};
 use crate::coordinate::Coordinate;
 use crate::grid_iterator::GridIterator;
 
@@ -52,6 +53,7 @@ use crate::grid_iterator::GridIterator;
 /// assert_eq!(iterator.next(), None);
 /// ```
 ///
+#[derive(Debug, Clone)]
 pub struct EnumerateCoordinate<I> {
     iterator: I
 }
@@ -73,8 +75,16 @@ impl<I: GridIterator> Iterator for EnumerateCoordinate<I> {
 
         Some((coordinate, value))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
 }
 
+impl<I: GridIterator + ExactSizeIterator> ExactSizeIterator for EnumerateCoordinate<I> {}
+
+impl<I: GridIterator + FusedIterator> FusedIterator for EnumerateCoordinate<I> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,4 +158,38 @@ mod tests {
         assert_eq!(enumerator.next(), Some((coord!(1, 2), &6)));
         assert_eq!(enumerator.next(), None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn enumerate_clone() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4]]);
+
+        let mut enumerator = EnumerateCoordinate::new(IteratorGrid::new(&grid));
+        enumerator.next();
+
+        let mut fork = enumerator.clone();
+        assert_eq!(fork.next(), Some((coord!(1, 0), &2)));
+        assert_eq!(fork.next(), Some((coord!(0, 1), &3)));
+        assert_eq!(enumerator.next(), Some((coord!(1, 0), &2)));
+
+        assert!(format!("{:?}", enumerator).starts_with("EnumerateCoordinate"));
+    }
+
+    #[test]
+    fn enumerate_size_hint() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let mut enumerator = EnumerateCoordinate::new(IteratorGrid::new(&grid));
+        assert_eq!(enumerator.len(), 6);
+
+        enumerator.next();
+        assert_eq!(enumerator.size_hint(), (5, Some(5)));
+
+        let enumerator = EnumerateCoordinate::new(IteratorRow::new(grid.row(0)));
+        assert_eq!(enumerator.len(), 3);
+
+        let enumerator = EnumerateCoordinate::new(IteratorColumn::new(grid.column(0)));
+        assert_eq!(enumerator.len(), 2);
+    }
+}
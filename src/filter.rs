@@ -0,0 +1,231 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+use crate::border::BorderPolicy;
+
+// The separable factors of the Sobel kernels; the derivative is taken along
+// one axis and the values are smoothed along the other.
+const SOBEL_DERIVATIVE: [f64; 3] = [-1.0, 0.0, 1.0];
+const SOBEL_SMOOTHING: [f64; 3] = [1.0, 2.0, 1.0];
+
+impl<T: Numeric> Grid<T> {
+
+    /// Return the gradient magnitude of the grid with the Sobel operator.
+    ///
+    /// This method returns a new grid with the magnitude of the gradient of
+    /// the grid, computed with the Sobel operator; it highlights the edges,
+    /// where the values change abruptly. The magnitudes are floating-point
+    /// numbers, whatever the type of the elements of the grid.
+    ///
+    /// The grid is extended by repeating its edges, therefore, the borders of
+    /// the grid are not detected as edges; use `sobel_with_border()` to
+    /// extend it differently.
+    ///
+    /// This method is only available with the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0, 0, 1, 1],
+    ///                                 vec![0, 0, 1, 1]]);
+    ///
+    /// let edges = grid.sobel();
+    /// assert_eq!(edges.row(0).values(), vec![&0.0, &4.0, &4.0, &0.0]);
+    /// ```
+    ///
+    pub fn sobel(&self) -> Grid<f64> {
+        self.sobel_with_border(BorderPolicy::Clamp)
+    }
+
+    /// Return the gradient magnitude of the grid with the Sobel operator, with
+    /// a border policy.
+    ///
+    /// This method is similar to `sobel()`, but the elements outside of the
+    /// grid are given by a border policy; for instance, a constant policy
+    /// detects the borders of the grid as edges.
+    ///
+    /// This method is only available with the `std` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `border` - The policy for the elements outside of the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, BorderPolicy};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 1, 1]]);
+    ///
+    /// let edges = grid.sobel_with_border(BorderPolicy::Constant(0));
+    /// assert_eq!(edges.row(0).values(), vec![&2.0, &0.0, &2.0]);
+    /// ```
+    ///
+    pub fn sobel_with_border(&self, border: BorderPolicy<T>) -> Grid<f64> {
+        let (horizontal, vertical) = self.sobel_derivatives(&border);
+        combine(&horizontal, &vertical, f64::hypot)
+    }
+
+    /// Return the gradient magnitude and direction of the grid with the
+    /// Sobel operator.
+    ///
+    /// This method is similar to `sobel()`, but also returns the direction of
+    /// the gradient, in radians, which points toward the increasing values.
+    /// The angle is measured from the horizontal axis and is positive toward
+    /// the bottom of the grid, as the vertical axis points downward. Use
+    /// `sobel_with_direction_with_border()` to extend the grid differently.
+    ///
+    /// This method is only available with the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0, 0],
+    ///                                 vec![1, 1]]);
+    ///
+    /// let (magnitude, direction) = grid.sobel_with_direction();
+    /// assert_eq!(magnitude.row(0).values(), vec![&4.0, &4.0]);
+    /// assert_eq!(direction.row(0).values(), vec![&std::f64::consts::FRAC_PI_2; 2]);
+    /// ```
+    ///
+    pub fn sobel_with_direction(&self) -> (Grid<f64>, Grid<f64>) {
+        self.sobel_with_direction_with_border(BorderPolicy::Clamp)
+    }
+
+    /// Return the gradient magnitude and direction of the grid with the
+    /// Sobel operator, with a border policy.
+    ///
+    /// This method is similar to `sobel_with_direction()`, but the elements
+    /// outside of the grid are given by a border policy, like with
+    /// `sobel_with_border()`.
+    ///
+    /// This method is only available with the `std` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `border` - The policy for the elements outside of the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, BorderPolicy};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 1, 1]]);
+    ///
+    /// let (magnitude, direction) = grid.sobel_with_direction_with_border(BorderPolicy::Constant(0));
+    /// assert_eq!(magnitude.row(0).values(), vec![&2.0, &0.0, &2.0]);
+    /// assert_eq!(direction.row(0).values(), vec![&0.0, &0.0, &std::f64::consts::PI]);
+    /// ```
+    ///
+    pub fn sobel_with_direction_with_border(&self, border: BorderPolicy<T>) -> (Grid<f64>, Grid<f64>) {
+        let (horizontal, vertical) = self.sobel_derivatives(&border);
+
+        (combine(&horizontal, &vertical, f64::hypot),
+         combine(&vertical, &horizontal, f64::atan2))
+    }
+
+    // Compute the horizontal and vertical derivatives with the Sobel kernels.
+    fn sobel_derivatives(&self, border: &BorderPolicy<T>) -> (Grid<f64>, Grid<f64>) {
+        (self.convolve_separable_as(&SOBEL_DERIVATIVE, &SOBEL_SMOOTHING, border),
+         self.convolve_separable_as(&SOBEL_SMOOTHING, &SOBEL_DERIVATIVE, border))
+    }
+}
+
+// Combine two grids of the same size element by element.
+fn combine<F: Fn(f64, f64) -> f64>(a: &Grid<f64>, b: &Grid<f64>, function: F) -> Grid<f64> {
+    let rows = a.row_vectors().iter().zip(b.row_vectors())
+        .map(|(a, b)| a.iter().zip(b).map(|(a, b)| function(*a, *b)).collect())
+        .collect::<Vec<_>>();
+
+    Grid::from_row_vectors(a.size().width, rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::{FRAC_PI_2, PI};
+    use crate::coordinate::Coordinate;
+
+    #[test]
+    fn grid_sobel() {
+        let grid = Grid::from_rows(vec![vec![0u8, 0, 0, 255, 255],
+                                        vec![0, 0, 0, 255, 255],
+                                        vec![0, 0, 0, 255, 255]]);
+
+        let edges = grid.sobel();
+        for row in 0..3 {
+            assert_eq!(edges.row(row).values(), vec![&0.0, &0.0, &1020.0, &1020.0, &0.0]);
+        }
+
+        let grid = Grid::from_rows(vec![vec![0, 0, 0],
+                                        vec![0, 1, 0],
+                                        vec![0, 0, 0]]);
+
+        let edges = grid.sobel();
+        assert_eq!(edges[coord!(1, 1)], 0.0);
+        assert_eq!(edges[coord!(1, 0)], 2.0);
+        assert_eq!(edges[coord!(0, 0)], 2.0f64.sqrt());
+
+        assert_eq!(Grid::<f32>::zero().sobel().size(), Grid::<f64>::zero().size());
+    }
+
+    #[test]
+    fn grid_sobel_with_direction() {
+        let grid = Grid::from_rows(vec![vec![2, 1, 0],
+                                        vec![2, 1, 0]]);
+
+        let (magnitude, direction) = grid.sobel_with_direction();
+        assert_eq!(magnitude.row(0).values(), vec![&4.0, &8.0, &4.0]);
+        assert_eq!(direction.row(0).values(), vec![&PI, &PI, &PI]);
+
+        let grid = Grid::from_rows(vec![vec![1, 1],
+                                        vec![0, 0]]);
+
+        let (_, direction) = grid.sobel_with_direction();
+        assert_eq!(direction.row(1).values(), vec![&-FRAC_PI_2, &-FRAC_PI_2]);
+    }
+
+    #[test]
+    fn grid_sobel_with_direction_with_border() {
+        let grid = Grid::from_rows(vec![vec![2, 1, 0],
+                                        vec![2, 1, 0]]);
+
+        assert_eq!(grid.sobel_with_direction_with_border(BorderPolicy::Clamp), grid.sobel_with_direction());
+
+        // The borders of the grid are edges, pointing inward.
+        let (magnitude, direction) = grid.sobel_with_direction_with_border(BorderPolicy::Constant(0));
+        assert_eq!(magnitude, grid.sobel_with_border(BorderPolicy::Constant(0)));
+        assert_eq!(direction[coord!(0, 0)].cos().signum(), 1.0);
+        assert_eq!(direction[coord!(2, 0)].cos().signum(), -1.0);
+    }
+
+    #[test]
+    fn grid_sobel_with_border() {
+        let grid = Grid::from_rows(vec![vec![0, 0, 1, 1],
+                                        vec![0, 0, 1, 1]]);
+
+        assert_eq!(grid.sobel_with_border(BorderPolicy::Clamp), grid.sobel());
+
+        // The opposite edges are adjacent.
+        let edges = grid.sobel_with_border(BorderPolicy::Wrap);
+        assert_eq!(edges.row(0).values(), vec![&4.0, &4.0, &4.0, &4.0]);
+
+        let edges = grid.sobel_with_border(BorderPolicy::Mirror);
+        assert_eq!(edges.row(1).values(), vec![&0.0, &4.0, &4.0, &0.0]);
+
+        let edges = grid.sobel_with_border(BorderPolicy::Skip);
+        assert_eq!(edges.row(0).values(), vec![&0.0, &3.0f64.hypot(1.0), &3.0f64.hypot(3.0), &3.0f64.hypot(3.0)]);
+    }
+}
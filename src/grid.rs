@@ -6,16 +6,23 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
-use std::ops::{Index, IndexMut};
-use std::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::mem;
+use core::ops::{Index, IndexMut, RangeBounds, Bound};
+use alloc::vec;
+use alloc::vec::Vec;
+use alloc::string::String;
+use alloc::format;
 use crate::coordinate::Coordinate;
 use crate::size::Size;
+use crate::rect::Rect;
 use crate::row::Row;
 use crate::row_mut::RowMut;
 use crate::column::Column;
 use crate::column_mut::ColumnMut;
+use crate::grid_view::GridView;
 use crate::iterator_grid::IteratorGrid;
-use crate::size;
 
 /// A dynamic two-dimensional array
 ///
@@ -78,6 +85,9 @@ use crate::size;
 /// grid[coord!(1, 0)] = 2;
 /// grid[coord!(0, 1)] = 3;
 /// grid[coord!(1, 1)] = 4;
+///
+/// // A tuple of (x, y) values can be used as well.
+/// assert_eq!(grid[(1, 0)], 2);
 /// ```
 ///
 /// Inserting and removing rows and columns.
@@ -91,7 +101,18 @@ use crate::size;
 /// grid.insert_row(1, vec![3, 4]);
 /// ```
 ///
-#[derive(Debug, Eq, PartialEq)]
+/// Printing the grid with the alternate debug format, one row per line.
+///
+/// ```
+/// # use ingrid::Grid;
+/// #
+/// let grid = Grid::from_rows(vec![vec![1, 20],
+///                                 vec![300, 4]]);
+///
+/// assert_eq!(format!("{:#?}", grid), "Grid(2x2) [\n    [  1,  20],\n    [300,   4],\n]");
+/// ```
+///
+#[derive(Eq)]
 pub struct Grid<T> {
     size: Size,
     rows: Vec<Vec<T>>,
@@ -185,7 +206,7 @@ impl<T: Clone> Grid<T> {
 
         Grid::<T> {
             size: Size::new(0, 0),
-            rows: rows,
+            rows,
             row_capacity: capacity.width
         }
     }
@@ -228,11 +249,11 @@ impl<T: Clone> Grid<T> {
         let width: usize = rows.first().unwrap().len();
         let height: usize = rows.len();
 
-        assert_eq!(rows.iter().all(|row| row.len() == width), true, "vectors don't have the same length");
+        assert!(rows.iter().all(|row| row.len() == width), "vectors don't have the same length");
 
         Grid::<T> {
             size: size!(width, height),
-            rows: rows,
+            rows,
             row_capacity: width
         }
     }
@@ -243,6 +264,9 @@ impl<T: Clone> Grid<T> {
     /// of the grid. All columns of the list must have the same length or it
     /// will panic.
     ///
+    /// Each element is moved once, directly to its final position, and the
+    /// capacity of the grid is exactly its size.
+    ///
     /// Note that this is mostly used to create quick grids on the fly for
     /// testing. In real life situation, you will use the other constructors
     /// which offer better performance and flexibility.
@@ -270,13 +294,27 @@ impl<T: Clone> Grid<T> {
     /// ```
     ///
     pub fn from_columns(columns: Vec<Vec<T>>) -> Grid<T> {
-        // Perhaps not the most efficient implementation.
-        let mut grid = Self::from_rows(columns);
+        let width: usize = columns.len();
+        let height: usize = columns.first().unwrap().len();
 
-        grid.flip_horizontally();
-        grid.rotate_left();
+        assert!(columns.iter().all(|column| column.len() == height), "vectors don't have the same length");
+
+        // Consume the columns in parallel, one element of each per row.
+        let mut columns = columns.into_iter().map(Vec::into_iter).collect::<Vec<_>>();
 
-        grid
+        let mut rows = Vec::with_capacity(height);
+        for _ in 0..height {
+            let mut row = Vec::with_capacity(width);
+            row.extend(columns.iter_mut().map(|column| column.next().unwrap()));
+
+            rows.push(row);
+        }
+
+        Grid::<T> {
+            size: size!(width, height),
+            rows,
+            row_capacity: width
+        }
     }
 
     /// Create an empty grid.
@@ -319,6 +357,43 @@ impl<T: Clone> Grid<T> {
         self.size
     }
 
+    /// Return the number of elements of the grid.
+    ///
+    /// This method returns the number of elements of the grid, which is its
+    /// width multiplied by its height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::with_size(size!(3, 2), 42);
+    /// assert_eq!(grid.len(), 6);
+    /// ```
+    ///
+    pub fn len(&self) -> usize {
+        self.size.area()
+    }
+
+    /// Check whether the grid has no element.
+    ///
+    /// This method returns whether the grid has no element, which is when
+    /// either its width or its height is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// assert!(Grid::<i32>::zero().is_empty());
+    /// assert!(Grid::with_size(size!(3, 0), 42).is_empty());
+    /// assert!(!Grid::with_size(size!(3, 2), 42).is_empty());
+    /// ```
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.size.is_empty()
+    }
+
     /// Resize the grid
     ///
     /// This method resizes the grid, adding more elements to it and/or dropping
@@ -435,6 +510,59 @@ impl<T: Clone> Grid<T> {
         self.size = size!(0, 0);
     }
 
+    /// Take the grid out, leaving an empty grid in its place.
+    ///
+    /// This method moves the grid out and leaves an empty grid with the same
+    /// capacity in its place; it's the grid equivalent of `mem::take()`, except
+    /// that the capacity is preserved so the grid can be refilled without
+    /// reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// let taken = grid.take();
+    /// assert_eq!(taken, Grid::from_rows(vec![vec![1, 2],
+    ///                                        vec![3, 4]]));
+    ///
+    /// assert_eq!(grid.size(), size!(0, 0));
+    /// assert_eq!(grid.capacity(), size!(2, 2));
+    /// ```
+    ///
+    pub fn take(&mut self) -> Grid<T> {
+        let capacity = self.capacity();
+        mem::replace(self, Grid::with_capacity(capacity))
+    }
+
+    /// Replace the grid, returning the previous one.
+    ///
+    /// This method moves a new grid in place of the grid and returns the
+    /// previous one; it's the grid equivalent of `mem::replace()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The new grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(2, 2), 0);
+    ///
+    /// let previous = grid.replace(Grid::with_size(size!(3, 1), 1));
+    /// assert_eq!(previous, Grid::with_size(size!(2, 2), 0));
+    /// assert_eq!(grid, Grid::with_size(size!(3, 1), 1));
+    /// ```
+    ///
+    pub fn replace(&mut self, grid: Grid<T>) -> Grid<T> {
+        mem::replace(self, grid)
+    }
+
     /// Return a reference to an element of the grid.
     ///
     /// This method returns a reference to an element of the grid from its
@@ -502,13 +630,92 @@ impl<T: Clone> Grid<T> {
     /// grid.value(coord!(2, 0)); // It panics here !
     /// ```
     ///
-    pub fn value_mut<'a>(&'a mut self, coordinate: Coordinate) -> &'a mut T {
+    pub fn value_mut(&mut self, coordinate: Coordinate) -> &mut T {
         assert!(coordinate.x < self.size.width, "index out of bounds");
         assert!(coordinate.y < self.size.height, "index out of bounds");
 
         self.rows.get_mut(coordinate.y).unwrap().get_mut(coordinate.x).unwrap()
     }
 
+    /// Return a reference to an element of the grid, without bounds checking.
+    ///
+    /// This method returns a reference to an element of the grid from its
+    /// coordinate, just like `value()`, but without checking whether the
+    /// coordinate is within the bounds of the grid. It's meant for inner loops
+    /// where the bounds are already guaranteed by the caller.
+    ///
+    /// For a safe alternative, see `value()`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with a coordinate that is out of bounds is
+    /// undefined behavior, even if the resulting reference is not used. The
+    /// coordinate is within bounds if `x` is less than the width of the grid
+    /// and `y` is less than its height; the capacity doesn't count.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                 vec![3, 4]]);
+    ///
+    /// unsafe {
+    ///     assert_eq!(grid.get_unchecked(coord!(1, 1)), &4);
+    /// }
+    /// ```
+    ///
+    pub unsafe fn get_unchecked(&self, coordinate: Coordinate) -> &T {
+        debug_assert!(self.size.contains(coordinate), "index out of bounds");
+
+        self.rows.get_unchecked(coordinate.y).get_unchecked(coordinate.x)
+    }
+
+    /// Return a mutable reference to an element of the grid, without bounds
+    /// checking.
+    ///
+    /// This method returns a mutable reference to an element of the grid from
+    /// its coordinate, just like `value_mut()`, but without checking whether
+    /// the coordinate is within the bounds of the grid.
+    ///
+    /// For a safe alternative, see `value_mut()`.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with a coordinate that is out of bounds is
+    /// undefined behavior, even if the resulting reference is not used. See
+    /// `get_unchecked()` for what is considered within bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 0]]);
+    ///
+    /// unsafe {
+    ///     *grid.get_unchecked_mut(coord!(1, 1)) = 4;
+    /// }
+    ///
+    /// assert_eq!(grid.value(coord!(1, 1)), &4);
+    /// ```
+    ///
+    pub unsafe fn get_unchecked_mut(&mut self, coordinate: Coordinate) -> &mut T {
+        debug_assert!(self.size.contains(coordinate), "index out of bounds");
+
+        self.rows.get_unchecked_mut(coordinate.y).get_unchecked_mut(coordinate.x)
+    }
+
     /// Replace an element of the grid.
     ///
     /// This method replaces the value of an element of the grid from its
@@ -584,10 +791,10 @@ impl<T: Clone> Grid<T> {
 
         // checkout: https://stackoverflow.com/questions/30073684/how-to-get-mutable-references-to-two-array-elements-at-the-same-time
         unsafe {
-            let foo = &mut *(self.rows.get_mut(a.y).unwrap().get_unchecked_mut(a.x) as *mut _);
-            let bar = &mut *(self.rows.get_mut(b.y).unwrap().get_unchecked_mut(b.x) as *mut _);
+            let a = self.rows.get_mut(a.y).unwrap().get_unchecked_mut(a.x) as *mut T;
+            let b = self.rows.get_mut(b.y).unwrap().get_unchecked_mut(b.x) as *mut T;
 
-            std::mem::swap(foo, bar);
+            core::ptr::swap(a, b);
         }
     }
 
@@ -633,6 +840,120 @@ impl<T: Clone> Grid<T> {
         IteratorGrid::new(self)
     }
 
+    /// Create a view onto a region of the grid
+    ///
+    /// This method creates a view onto a rectangular region of the grid. The
+    /// view is immutable and its elements are indexed with coordinates relative
+    /// to the top-left corner of the region.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The region of the grid
+    ///
+    /// # Panics
+    ///
+    /// It panics if the region isn't contained in the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Rect, Grid, coord, rect};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// let view = grid.view(rect!(1, 0, 2, 2));
+    /// assert_eq!(view[coord!(0, 0)], 2);
+    /// assert_eq!(view.values(), vec![&2, &3, &5, &6]);
+    /// ```
+    ///
+    pub fn view(&self, rect: Rect) -> GridView<'_, T> {
+        assert!(rect.coordinate.x.checked_add(rect.size.width).is_some_and(|right| right <= self.size.width), "index out of bounds");
+        assert!(rect.coordinate.y.checked_add(rect.size.height).is_some_and(|bottom| bottom <= self.size.height), "index out of bounds");
+
+        GridView {
+            grid: self,
+            rect
+        }
+    }
+
+    /// Create a view onto a region of the grid from ranges
+    ///
+    /// This method creates a view onto the region of the grid delimited by a
+    /// range of columns and a range of rows. It's equivalent to the `view()`
+    /// method but allows to use the idiomatic range syntax instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The range of columns of the region
+    /// * `rows` - The range of rows of the region
+    ///
+    /// # Panics
+    ///
+    /// It panics if the ranges are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6],
+    ///                                 vec![7, 8, 9]]);
+    ///
+    /// assert_eq!(grid.slice(0..2, 1..3).values(), vec![&4, &5, &7, &8]);
+    /// assert_eq!(grid.slice(.., 2..).values(), vec![&7, &8, &9]);
+    /// assert_eq!(grid.slice(1..=1, ..).size(), size!(1, 3));
+    /// ```
+    ///
+    pub fn slice<C, R>(&self, columns: C, rows: R) -> GridView<'_, T>
+        where C: RangeBounds<usize>, R: RangeBounds<usize>
+    {
+        let (left, right) = range_limits(columns, self.size.width);
+        let (top, bottom) = range_limits(rows, self.size.height);
+
+        self.view(Rect::new(Coordinate::new(left, top), Size::new(right - left, bottom - top)))
+    }
+
+    /// Extract a region of the grid
+    ///
+    /// This method returns a new grid with the elements of a rectangular
+    /// region of the grid, which are cloned; the grid itself is left intact.
+    /// Unlike a view, the new grid doesn't borrow the grid and can outlive it.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The region of the grid
+    ///
+    /// # Panics
+    ///
+    /// It panics if the region isn't contained in the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Rect, Grid, coord, rect};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// let region = grid.extract(rect!(1, 0, 2, 2));
+    /// assert_eq!(region, Grid::from_rows(vec![vec![2, 3],
+    ///                                         vec![5, 6]]));
+    /// ```
+    ///
+    pub fn extract(&self, rect: Rect) -> Grid<T> {
+        assert!(rect.coordinate.x.checked_add(rect.size.width).is_some_and(|right| right <= self.size.width), "index out of bounds");
+        assert!(rect.coordinate.y.checked_add(rect.size.height).is_some_and(|bottom| bottom <= self.size.height), "index out of bounds");
+
+        let (left, right) = (rect.coordinate.x, rect.coordinate.x + rect.size.width);
+        let rows = (rect.coordinate.y..rect.coordinate.y + rect.size.height)
+            .map(|row| self.row_slice(row)[left..right].to_vec())
+            .collect();
+
+        Grid::from_row_vectors(rect.size.width, rows)
+    }
+
     /// Create a view onto a given row
     ///
     /// This method creates a view onto a given row of the grid. The row is
@@ -663,7 +984,7 @@ impl<T: Clone> Grid<T> {
 
         Row {
             grid: self,
-            index: index
+            index
         }
     }
 
@@ -701,7 +1022,7 @@ impl<T: Clone> Grid<T> {
 
         RowMut {
             grid: self,
-            index: index
+            index
         }
     }
 
@@ -741,6 +1062,47 @@ impl<T: Clone> Grid<T> {
         self.rows.swap(a, b);
     }
 
+    /// Reorder the rows of the grid.
+    ///
+    /// This method reorders the rows of the grid according to a permutation;
+    /// the row at index `i` is moved to where the index `i` is in the
+    /// permutation, or in other words, the new row `i` is the old row
+    /// `permutation[i]`. It allows deterministic shuffles, pivoting or sorting
+    /// the rows by an external key.
+    ///
+    /// The rows are moved in place by swapping them, without cloning their
+    /// elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `permutation` - The old index of each row, from top to bottom
+    ///
+    /// # Panics
+    ///
+    /// It panics if the indices aren't a permutation of the indices of the
+    /// rows, that is, if there isn't exactly one index per row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4],
+    ///                                     vec![5, 6]]);
+    ///
+    /// grid.permute_rows(&[2, 0, 1]);
+    /// assert_eq!(grid, Grid::from_rows(vec![vec![5, 6],
+    ///                                       vec![1, 2],
+    ///                                       vec![3, 4]]));
+    /// ```
+    ///
+    pub fn permute_rows(&mut self, permutation: &[usize]) {
+        for (a, b) in permutation_swaps(permutation, self.size.height) {
+            self.rows.swap(a, b);
+        }
+    }
+
     /// Return the rows of the grid
     ///
     /// This method returns the rows of the grid as a vector.
@@ -768,6 +1130,38 @@ impl<T: Clone> Grid<T> {
         rows
     }
 
+    /// Visit the rows of the grid mutably
+    ///
+    /// This method calls a function on each row of the grid, from top to
+    /// bottom, with a mutable view onto the row. Unlike the `row_mut()` method
+    /// which borrows the whole grid, it allows to operate on each row in
+    /// sequence without managing indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function to call on each row
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![4, 5, 6]]);
+    ///
+    /// grid.for_each_row_mut(|mut row| row.rotate_left(row.index + 1));
+    /// assert_eq!(grid.row(0).values(), vec![&2, &3, &1]);
+    /// assert_eq!(grid.row(1).values(), vec![&6, &4, &5]);
+    /// ```
+    ///
+    pub fn for_each_row_mut<F>(&mut self, mut function: F)
+        where F: FnMut(RowMut<'_, T>)
+    {
+        for index in 0..self.size.height {
+            function(self.row_mut(index));
+        }
+    }
+
     /// Insert a row into the grid
     ///
     /// This method inserts a row into the grid at position `index`, shifting
@@ -804,7 +1198,7 @@ impl<T: Clone> Grid<T> {
     /// ```
     ///
     pub fn insert_row(&mut self, index: usize, row: Vec<T>) {
-        assert!(!(index > self.size.height), "index out of bounds"); // syntax -- wtf!!
+        assert!(index <= self.size.height, "index out of bounds");
         assert_eq!(row.len(), self.size.width, "row length is invalid");
 
         // The capacity doesn't change unless it's too small
@@ -819,6 +1213,58 @@ impl<T: Clone> Grid<T> {
         self.size.height += 1;
     }
 
+    /// Insert a row copied from another grid
+    ///
+    /// This method inserts a row into the grid at position `index`, shifting
+    /// all rows after it to the bottom, just like `insert_row()`. The elements
+    /// are cloned directly from the row of another grid, without collecting
+    /// them into an intermediate vector first. Its length must be equal to the
+    /// length of the other rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position index of the inserted row
+    /// * `row` - The row of another grid to copy
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds or if the length of the row
+    /// doesn't equal the length of the other rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![7, 8, 9]]);
+    /// let other = Grid::from_rows(vec![vec![4, 5, 6]]);
+    ///
+    /// grid.insert_row_from(1, other.row(0));
+    ///
+    /// assert_eq!(grid.column(0).values(), vec![&1, &4, &7]);
+    /// assert_eq!(grid.column(1).values(), vec![&2, &5, &8]);
+    /// assert_eq!(grid.column(2).values(), vec![&3, &6, &9]);
+    /// ```
+    ///
+    pub fn insert_row_from(&mut self, index: usize, row: Row<'_, T>) {
+        assert!(index <= self.size.height, "index out of bounds");
+        assert_eq!(row.length(), self.size.width, "row length is invalid");
+
+        // The allocated row past the last one is reused if there's one.
+        let mut elements = if self.size.height < self.rows.len() {
+            self.rows.pop().unwrap()
+        }
+        else {
+            Vec::with_capacity(self.row_capacity)
+        };
+
+        elements.extend_from_slice(row.grid.row_slice(row.index));
+        self.rows.insert(index, elements);
+
+        self.size.height += 1;
+    }
+
     /// Remove a row from the grid.
     ///
     /// This method removes a row from the grid at position index, shifting all
@@ -861,7 +1307,54 @@ impl<T: Clone> Grid<T> {
         self.size.height -= 1;
     }
 
-    /// Create a view onto a given column
+    /// Move a row to another grid.
+    ///
+    /// This method removes a row from the grid at position `index` and
+    /// inserts it into another grid at position `destination_index`, shifting
+    /// the rows just like `remove_row()` and `insert_row()` do. The elements
+    /// are moved rather than cloned, which allows repartitioning data between
+    /// grids of the same width.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position index of the row to move
+    /// * `destination` - The grid receiving the row
+    /// * `destination_index` - Position index of the row in the other grid
+    ///
+    /// # Panics
+    ///
+    /// It panics if any of the indices is out of bounds or if the widths of
+    /// the grids don't match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    /// let mut other = Grid::from_rows(vec![vec![5, 6]]);
+    ///
+    /// grid.transfer_row(0, &mut other, 1);
+    ///
+    /// assert_eq!(grid.row(0).values(), vec![&3, &4]);
+    /// assert_eq!(other.row(1).values(), vec![&1, &2]);
+    /// ```
+    ///
+    pub fn transfer_row(&mut self, index: usize, destination: &mut Grid<T>, destination_index: usize) {
+        assert!(index < self.size.height, "index out of bounds");
+        assert!(destination_index <= destination.size.height, "index out of bounds");
+        assert_eq!(self.size.width, destination.size.width, "row length is invalid");
+
+        // Removing a row doesn't change the capacity of the grid.
+        let row = self.rows.remove(index);
+        self.rows.push(Vec::<T>::with_capacity(self.row_capacity));
+        self.size.height -= 1;
+
+        destination.insert_row(destination_index, row);
+    }
+
+    /// Create a view onto a given column
     ///
     /// This method creates a view onto a given column of the grid. The column
     /// is immutable; use `column_mut()` to compute a mutable column.
@@ -891,7 +1384,7 @@ impl<T: Clone> Grid<T> {
 
         Column {
             grid: self,
-            index: index
+            index
         }
     }
 
@@ -929,7 +1422,7 @@ impl<T: Clone> Grid<T> {
 
         ColumnMut {
             grid: self,
-            index: index
+            index
         }
     }
 
@@ -972,6 +1465,49 @@ impl<T: Clone> Grid<T> {
         }
     }
 
+    /// Reorder the columns of the grid.
+    ///
+    /// This method reorders the columns of the grid according to a
+    /// permutation; the column at index `i` is moved to where the index `i` is
+    /// in the permutation, or in other words, the new column `i` is the old
+    /// column `permutation[i]`. It allows deterministic shuffles, pivoting or
+    /// sorting the columns by an external key.
+    ///
+    /// The columns are moved in place by swapping their elements, without
+    /// cloning them.
+    ///
+    /// # Arguments
+    ///
+    /// * `permutation` - The old index of each column, from left to right
+    ///
+    /// # Panics
+    ///
+    /// It panics if the indices aren't a permutation of the indices of the
+    /// columns, that is, if there isn't exactly one index per column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![4, 5, 6]]);
+    ///
+    /// grid.permute_columns(&[2, 0, 1]);
+    /// assert_eq!(grid, Grid::from_rows(vec![vec![3, 1, 2],
+    ///                                       vec![6, 4, 5]]));
+    /// ```
+    ///
+    pub fn permute_columns(&mut self, permutation: &[usize]) {
+        let swaps = permutation_swaps(permutation, self.size.width);
+
+        for row in &mut self.rows[..self.size.height] {
+            for &(a, b) in &swaps {
+                row.swap(a, b);
+            }
+        }
+    }
+
     /// Return the columns of the grid
     ///
     /// This method returns the columns of the grid as a vector.
@@ -999,6 +1535,39 @@ impl<T: Clone> Grid<T> {
         columns
     }
 
+    /// Visit the columns of the grid mutably
+    ///
+    /// This method calls a function on each column of the grid, from left to
+    /// right, with a mutable view onto the column. Unlike the `column_mut()`
+    /// method which borrows the whole grid, it allows to operate on each
+    /// column in sequence without managing indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function to call on each column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4],
+    ///                                     vec![5, 6]]);
+    ///
+    /// grid.for_each_column_mut(|mut column| column.reverse());
+    /// assert_eq!(grid.column(0).values(), vec![&5, &3, &1]);
+    /// assert_eq!(grid.column(1).values(), vec![&6, &4, &2]);
+    /// ```
+    ///
+    pub fn for_each_column_mut<F>(&mut self, mut function: F)
+        where F: FnMut(ColumnMut<'_, T>)
+    {
+        for index in 0..self.size.width {
+            function(self.column_mut(index));
+        }
+    }
+
     /// Insert a column into the grid
     ///
     /// This method inserts a column into the grid at position `index`, shifting
@@ -1035,8 +1604,8 @@ impl<T: Clone> Grid<T> {
     /// assert_eq!(grid.row(2).values(), vec![&7, &8, &9]);
     /// ```
     ///
-    pub fn insert_column(&mut self, index: usize, mut column: Vec<T>) {
-        assert!(!(index > self.size.width), "index out of bounds");
+    pub fn insert_column(&mut self, index: usize, column: Vec<T>) {
+        assert!(index <= self.size.width, "index out of bounds");
         assert_eq!(column.len(), self.size.height, "column length is invalid");
 
         // The capacity doesn't change unless it's too small
@@ -1044,11 +1613,63 @@ impl<T: Clone> Grid<T> {
             self.row_capacity += 1;
         }
 
-        for i in 0..self.size.height {
-            self.rows[i].insert(index, column.remove(0));
+        // The column is consumed from the top, shifting each row only once.
+        for (row, value) in self.rows.iter_mut().zip(column) {
+            row.insert(index, value);
         }
-        assert_eq!(column.len(), 0);
 
+        self.size.width += 1;
+    }
+
+    /// Insert a column copied from another grid
+    ///
+    /// This method inserts a column into the grid at position `index`,
+    /// shifting all columns after it to the right, just like
+    /// `insert_column()`. The elements are cloned directly from the column of
+    /// another grid, without collecting them into an intermediate vector
+    /// first. Its length must be equal to the length of the other columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position index of the inserted column
+    /// * `column` - The column of another grid to copy
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds or if the length of the column
+    /// doesn't equal the length of the other columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 3],
+    ///                                     vec![4, 6],
+    ///                                     vec![7, 9]]);
+    /// let other = Grid::from_rows(vec![vec![0, 2],
+    ///                                  vec![0, 5],
+    ///                                  vec![0, 8]]);
+    ///
+    /// grid.insert_column_from(1, other.column(1));
+    ///
+    /// assert_eq!(grid.row(0).values(), vec![&1, &2, &3]);
+    /// assert_eq!(grid.row(1).values(), vec![&4, &5, &6]);
+    /// assert_eq!(grid.row(2).values(), vec![&7, &8, &9]);
+    /// ```
+    ///
+    pub fn insert_column_from(&mut self, index: usize, column: Column<'_, T>) {
+        assert!(index <= self.size.width, "index out of bounds");
+        assert_eq!(column.length(), self.size.height, "column length is invalid");
+
+        // The capacity doesn't change unless it's too small
+        if self.size.width + 1 > self.row_capacity {
+            self.row_capacity += 1;
+        }
+
+        for (row, value) in self.rows.iter_mut().zip(column.iterator()) {
+            row.insert(index, value.clone());
+        }
 
         self.size.width += 1;
     }
@@ -1096,6 +1717,59 @@ impl<T: Clone> Grid<T> {
         self.size.width -= 1;
     }
 
+    /// Move a column to another grid.
+    ///
+    /// This method removes a column from the grid at position `index` and
+    /// inserts it into another grid at position `destination_index`, shifting
+    /// the columns just like `remove_column()` and `insert_column()` do. The
+    /// elements are moved rather than cloned, which allows repartitioning data
+    /// between grids of the same height.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Position index of the column to move
+    /// * `destination` - The grid receiving the column
+    /// * `destination_index` - Position index of the column in the other grid
+    ///
+    /// # Panics
+    ///
+    /// It panics if any of the indices is out of bounds or if the heights of
+    /// the grids don't match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    /// let mut other = Grid::from_rows(vec![vec![5],
+    ///                                      vec![6]]);
+    ///
+    /// grid.transfer_column(1, &mut other, 0);
+    ///
+    /// assert_eq!(grid.column(0).values(), vec![&1, &3]);
+    /// assert_eq!(other.column(0).values(), vec![&2, &4]);
+    /// ```
+    ///
+    pub fn transfer_column(&mut self, index: usize, destination: &mut Grid<T>, destination_index: usize) {
+        assert!(index < self.size.width, "index out of bounds");
+        assert!(destination_index <= destination.size.width, "index out of bounds");
+        assert_eq!(self.size.height, destination.size.height, "column length is invalid");
+
+        // The capacity doesn't change unless it's too small
+        if destination.size.width + 1 > destination.row_capacity {
+            destination.row_capacity += 1;
+        }
+
+        for (row, destination_row) in self.rows.iter_mut().zip(destination.rows.iter_mut()).take(self.size.height) {
+            destination_row.insert(destination_index, row.remove(index));
+        }
+
+        self.size.width -= 1;
+        destination.size.width += 1;
+    }
+
     /// Flip the grid horizontally
     ///
     /// This method flips the grid horizontally, reversing the order of the
@@ -1144,6 +1818,54 @@ impl<T: Clone> Grid<T> {
         }
     }
 
+    /// Sort the elements of each row
+    ///
+    /// This method sorts the elements of each row in ascending order, one by
+    /// one. The rows themselves aren't reordered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![2, 1],
+    ///                                     vec![3, 4]]);
+    ///
+    /// grid.sort_each_row();
+    /// assert_eq!(grid.row(0).values(), vec![&1, &2]);
+    /// assert_eq!(grid.row(1).values(), vec![&3, &4]);
+    /// ```
+    ///
+    pub fn sort_each_row(&mut self) where T: Ord {
+        for index in 0..self.size.height {
+            self.row_mut(index).sort();
+        }
+    }
+
+    /// Sort the elements of each column
+    ///
+    /// This method sorts the elements of each column in ascending order, one
+    /// by one. The columns themselves aren't reordered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![3, 2],
+    ///                                     vec![1, 4]]);
+    ///
+    /// grid.sort_each_column();
+    /// assert_eq!(grid.row(0).values(), vec![&1, &2]);
+    /// assert_eq!(grid.row(1).values(), vec![&3, &4]);
+    /// ```
+    ///
+    pub fn sort_each_column(&mut self) where T: Ord {
+        for index in 0..self.size.width {
+            self.column_mut(index).sort();
+        }
+    }
+
     /// Rotate the grid to the left
     ///
     /// This method rotate the grid to the left, rearranging its elements.
@@ -1166,19 +1888,21 @@ impl<T: Clone> Grid<T> {
     ///
     pub fn rotate_left(&mut self) {
         // Rotation cannot be done in-place, therefore, the strategy is to
-        // create another grid, then swap them
+        // create another grid, then swap them; the element at (x, y) moves
+        // directly to (y, width - x - 1), in a single pass over the rows.
         let size = size!(self.size.height, self.size.width);
-        let mut grid = Self::with_capacity(size);
+        let mut grid = self.rotated_with_capacity();
 
-        for i in 0..self.size.height {
-            for j in 0..self.size.width {
-                grid.rows[j].push(self.rows[i].pop().unwrap());
+        let rows = core::mem::take(&mut self.rows);
+        for row in rows.into_iter().take(self.size.height) {
+            for (x, value) in row.into_iter().enumerate() {
+                grid.rows[size.height - x - 1].push(value);
             }
         }
 
         grid.size = size;
 
-        std::mem::swap(self, &mut grid);
+        core::mem::swap(self, &mut grid);
     }
 
     /// Rotate the grid to the right
@@ -1203,19 +1927,71 @@ impl<T: Clone> Grid<T> {
     ///
     pub fn rotate_right(&mut self) {
         // Rotation cannot be done in-place, therefore, the strategy is to
-        // create another grid, then swap them
+        // create another grid, then swap them; the element at (x, y) moves
+        // directly to (height - y - 1, x), in a single pass over the rows.
         let size = size!(self.size.height, self.size.width);
-        let mut grid = Self::with_capacity(size);
+        let mut grid = self.rotated_with_capacity();
 
-        for i in (0..self.size.height).rev() {
-            for j in (0..self.size.width).rev() {
-                grid.rows[j].push(self.rows[i].pop().unwrap());
+        let rows = core::mem::take(&mut self.rows);
+        for row in rows.into_iter().take(self.size.height).rev() {
+            for (x, value) in row.into_iter().enumerate() {
+                grid.rows[x].push(value);
             }
         }
 
         grid.size = size;
 
-        std::mem::swap(self, &mut grid);
+        core::mem::swap(self, &mut grid);
+    }
+
+    /// Rotate the grid by a number of quarter turns
+    ///
+    /// This method rotates the grid by a number of quarter turns, to the right
+    /// if the number is positive and to the left if it's negative. The number
+    /// is normalized first so at most one rotation is actually done; a half
+    /// turn is done by flipping the grid in both directions, in place.
+    ///
+    /// Note that the capacity of the grid is rotated along with the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `quarter_turns` - The number of quarter turns to the right
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// grid.rotate(2);
+    /// assert_eq!(grid, Grid::from_rows(vec![vec![4, 3],
+    ///                                       vec![2, 1]]));
+    ///
+    /// // Three quarter turns to the left is one to the right.
+    /// grid.rotate(-3);
+    /// assert_eq!(grid, Grid::from_rows(vec![vec![2, 4],
+    ///                                       vec![1, 3]]));
+    /// ```
+    ///
+    pub fn rotate(&mut self, quarter_turns: i32) {
+        match quarter_turns.rem_euclid(4) {
+            1 => self.rotate_right(),
+            2 => {
+                self.flip_horizontally();
+                self.flip_vertically();
+            },
+            3 => self.rotate_left(),
+            _ => ()
+        }
+    }
+
+    // Create an empty grid with the capacity of the grid rotated, to receive
+    // its elements.
+    fn rotated_with_capacity(&self) -> Grid<T> {
+        let capacity = self.capacity();
+        Self::with_capacity(size!(capacity.height, capacity.width))
     }
 
     /// Return the number of elements the grid can hold without reallocating.
@@ -1270,17 +2046,168 @@ impl<T: Clone> Grid<T> {
         self.row_capacity += additional.width;
 
         self.rows.reserve_exact(additional.height);
-        let foobar = self.rows.capacity().clone();
+        let height_capacity = self.rows.capacity();
 
         let row_capacity = self.row_capacity;
-        self.rows.resize_with(foobar, || Vec::<T>::with_capacity(row_capacity));
+        self.rows.resize_with(height_capacity, || Vec::<T>::with_capacity(row_capacity));
+    }
+
+    // Return the elements of a row as a slice; rows are contiguous in memory
+    // and their views rely on this to benefit from the slice operations.
+    pub(crate) fn row_slice(&self, row: usize) -> &[T] {
+        assert!(row < self.size.height, "index out of bounds");
+        self.rows[row].as_slice()
     }
 
-    // unfinished
-    pub fn row_slice(&mut self, row: usize) -> &mut [T] {
+    pub(crate) fn row_slice_mut(&mut self, row: usize) -> &mut [T] {
         assert!(row < self.size.height, "index out of bounds");
         self.rows[row].as_mut_slice()
     }
+
+    // Return the rows that are actually in use (the others are only there to
+    // retain capacity); iterators rely on this to avoid bounds checks.
+    pub(crate) fn row_vectors(&self) -> &[Vec<T>] {
+        &self.rows[..self.size.height]
+    }
+
+    pub(crate) fn row_vectors_mut(&mut self) -> &mut [Vec<T>] {
+        &mut self.rows[..self.size.height]
+    }
+
+    // Create a grid from rows that all have the given width; unlike
+    // `from_rows()`, it allows grids with no row at all. The accesses through
+    // the rows rely on their width.
+    pub(crate) fn from_row_vectors(width: usize, rows: Vec<Vec<T>>) -> Grid<T> {
+        assert!(rows.iter().all(|row| row.len() == width), "row length is invalid");
+
+        Grid::<T> {
+            size: size!(width, rows.len()),
+            rows,
+            row_capacity: width
+        }
+    }
+
+    // Take the rows that are actually in use out of the grid.
+    pub(crate) fn into_row_vectors(mut self) -> Vec<Vec<T>> {
+        self.rows.truncate(self.size.height);
+        self.rows
+    }
+}
+
+// Decompose a permutation of a given length into the swaps that apply it in
+// place, following its cycles; the new element at index `i` is the element at
+// index `permutation[i]`.
+fn permutation_swaps(permutation: &[usize], length: usize) -> Vec<(usize, usize)> {
+    assert!(permutation.len() == length, "permutation is invalid");
+
+    let mut visited = vec![false; length];
+    for &index in permutation {
+        assert!(index < length && !visited[index], "permutation is invalid");
+        visited[index] = true;
+    }
+
+    let mut swaps = Vec::new();
+    for start in 0..length {
+        if !visited[start] {
+            continue;
+        }
+
+        let mut index = start;
+        visited[index] = false;
+        while permutation[index] != start {
+            swaps.push((index, permutation[index]));
+            index = permutation[index];
+            visited[index] = false;
+        }
+    }
+
+    swaps
+}
+
+// Resolve a range into its start and end limits, within a given length.
+pub(crate) fn range_limits<R: RangeBounds<usize>>(range: R, length: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => length
+    };
+
+    assert!(start <= end, "range start is greater than range end");
+    assert!(end <= length, "index out of bounds");
+
+    (start, end)
+}
+
+impl<T: Clone> Default for Grid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !formatter.alternate() {
+            return formatter.debug_struct("Grid")
+                .field("size", &self.size)
+                .field("rows", &self.rows)
+                .field("row_capacity", &self.row_capacity)
+                .finish();
+        }
+
+        // The alternate format lays out the elements in two dimensions, one
+        // row per line, with the elements right-aligned to the widest one.
+        let values = self.rows[..self.size.height].iter()
+            .map(|row| row.iter().map(|value| format!("{:?}", value)).collect::<Vec<String>>())
+            .collect::<Vec<_>>();
+        let width = values.iter().flatten().map(|value| value.chars().count()).max().unwrap_or(0);
+
+        writeln!(formatter, "Grid({}x{}) [", self.size.width, self.size.height)?;
+        for row in &values {
+            formatter.write_str("    [")?;
+            for (index, value) in row.iter().enumerate() {
+                if index > 0 {
+                    formatter.write_str(", ")?;
+                }
+                write!(formatter, "{:>width$}", value, width = width)?;
+            }
+            formatter.write_str("],\n")?;
+        }
+        formatter.write_str("]")
+    }
+}
+
+impl<T: Hash> Hash for Grid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Only the size and the elements matter; the rows beyond the height
+        // are only there to retain the capacity.
+        self.size.hash(state);
+
+        for row in &self.rows[..self.size.height] {
+            row.hash(state);
+        }
+    }
+}
+
+// Grids are equal if they have the same size and elements; like for hashing,
+// their capacities don't matter.
+impl<T: PartialEq> PartialEq for Grid<T> {
+    fn eq(&self, other: &Grid<T>) -> bool {
+        self.size == other.size &&
+        self.rows[..self.size.height] == other.rows[..other.size.height]
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq<Vec<Vec<T>>> for Grid<T> {
+    fn eq(&self, other: &Vec<Vec<T>>) -> bool {
+        self.size.height == other.len() &&
+        other.iter().enumerate().all(|(row, values)| self.row_slice(row) == values.as_slice())
+    }
 }
 
 impl<T> Index<Coordinate> for Grid<T> {
@@ -1297,6 +2224,20 @@ impl<T> IndexMut<Coordinate> for Grid<T> {
     }
 }
 
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self[Coordinate::new(x, y)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self[Coordinate::new(x, y)]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1313,7 +2254,7 @@ mod tests {
         let grid = Grid::with_size(size!(2, 3), 42);
 
         assert_eq!(grid.size(), size!(2, 3));
-        assert!(grid.iterator().all(|item| { *item == 42 }), true);
+        assert!(grid.iterator().all(|item| { *item == 42 }));
 
         assert_eq!(grid.capacity(), size!(2, 3));
     }
@@ -1361,17 +2302,62 @@ mod tests {
         assert_eq!(grid.value(coord!(0, 3)), &42);
         assert_eq!(grid.value(coord!(0, 4)), &42);
 
-        // Capacity doesn't change unless it's too small.
-        assert_eq!(grid.capacity(), size!(3, 5));
+        // Capacity doesn't change unless it's too small.
+        assert_eq!(grid.capacity(), size!(3, 5));
+    }
+
+    #[test]
+    fn grid_fill() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        assert!(!grid.iterator().all(|item| { *item == 42 }));
+
+        grid.fill(42);
+        assert!(grid.iterator().all(|item| { *item == 42 }));
+    }
+
+    #[test]
+    fn grid_take() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6]]);
+        grid.reserve(size!(1, 1));
+        let capacity = grid.capacity();
+
+        let taken = grid.take();
+        assert_eq!(taken.size(), size!(3, 2));
+        assert_eq!(taken.capacity(), capacity);
+        assert_eq!(taken.row(1), [4, 5, 6]);
+
+        assert_eq!(grid.size(), size!(0, 0));
+        assert_eq!(grid.capacity(), capacity);
+
+        assert_eq!(grid.take(), Grid::with_capacity(capacity));
     }
 
     #[test]
-    fn grid_fill() {
-        let mut grid = Grid::with_size(size!(3, 3), 0);
-        assert_eq!(grid.iterator().all(|item| { *item == 42 }), false);
+    fn grid_replace() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2],
+                                            vec![3, 4]]);
 
-        grid.fill(42);
-        assert_eq!(grid.iterator().all(|item| { *item == 42 }), true);
+        let previous = grid.replace(Grid::from_rows(vec![vec![5, 6, 7]]));
+        assert_eq!(previous, Grid::from_rows(vec![vec![1, 2],
+                                                  vec![3, 4]]));
+        assert_eq!(grid, Grid::from_rows(vec![vec![5, 6, 7]]));
+    }
+
+    #[test]
+    fn grid_len() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6]]);
+        assert_eq!(grid.len(), 6);
+        assert!(!grid.is_empty());
+
+        grid.resize(size!(3, 0), 0);
+        assert_eq!(grid.len(), 0);
+        assert!(grid.is_empty());
+
+        grid.resize(size!(0, 3), 0);
+        assert_eq!(grid.len(), 0);
+        assert!(grid.is_empty());
     }
 
     #[test]
@@ -1430,6 +2416,8 @@ mod tests {
         assert_eq!(grid.value(coord!(1, 2)), &6);
 
         assert_eq!(grid.capacity(), size!(2, 3));
+        assert!(grid.rows.iter().all(|row| row.capacity() == 2));
+        assert_eq!(grid.rows.capacity(), 3);
 
         Grid::from_columns(vec![vec![1, 4, 7], vec![2, 5, 8], vec![3, 6]]);
     }
@@ -1464,6 +2452,21 @@ mod tests {
         grid.value_mut(coord!(0, 2));
     }
 
+    #[test]
+    fn grid_get_unchecked() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2],
+                                            vec![3, 0]]);
+
+        unsafe {
+            *grid.get_unchecked_mut(coord!(1, 1)) = 4;
+
+            assert_eq!(grid.get_unchecked(coord!(0, 0)), &1);
+            assert_eq!(grid.get_unchecked(coord!(1, 0)), &2);
+            assert_eq!(grid.get_unchecked(coord!(0, 1)), &3);
+            assert_eq!(grid.get_unchecked(coord!(1, 1)), &4);
+        }
+    }
+
     #[test]
     #[should_panic(expected = "index out of bounds")]
     fn grid_set_value() {
@@ -1531,6 +2534,94 @@ mod tests {
         grid[coord!(0, 2)];
     }
 
+    #[test]
+    fn grid_debug() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4]]);
+
+        assert_eq!(format!("{:?}", grid), "Grid { size: Size { width: 2, height: 2 }, rows: [[1, 2], [3, 4]], row_capacity: 2 }");
+        assert_eq!(format!("{:#?}", grid), "Grid(2x2) [\n    [1, 2],\n    [3, 4],\n]");
+
+        let grid = Grid::from_rows(vec![vec!["a", "bcd"]]);
+        assert_eq!(format!("{:#?}", grid), "Grid(2x1) [\n    [  \"a\", \"bcd\"],\n]");
+
+        let mut grid = Grid::with_capacity(size!(4, 4));
+        grid.resize(size!(1, 1), 0.5);
+        assert_eq!(format!("{:#?}", grid), "Grid(1x1) [\n    [0.5],\n]");
+
+        assert_eq!(format!("{:#?}", Grid::<u8>::zero()), "Grid(0x0) [\n]");
+    }
+
+    #[test]
+    fn grid_hash() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+
+        assert!(set.insert(Grid::from_rows(vec![vec![1, 2],
+                                                vec![3, 4]])));
+        assert!(set.insert(Grid::from_rows(vec![vec![1, 2, 3, 4]])));
+        assert!(!set.insert(Grid::from_rows(vec![vec![1, 2],
+                                                 vec![3, 4]])));
+
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn grid_eq() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4]]);
+
+        assert_eq!(grid, Grid::from_rows(vec![vec![1, 2],
+                                              vec![3, 4]]));
+        assert_ne!(grid, Grid::from_rows(vec![vec![1, 2, 3, 4]]));
+        assert_ne!(Grid::<i32>::with_size(size!(0, 2), 0), Grid::with_size(size!(2, 0), 0));
+
+        // The capacity of the grid doesn't matter.
+        let mut other = Grid::from_rows(vec![vec![1, 2],
+                                             vec![3, 4]]);
+        other.reserve(size!(4, 4));
+        assert_eq!(grid, other);
+    }
+
+    #[test]
+    fn grid_eq_vec() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4]]);
+
+        assert_eq!(grid, vec![vec![1, 2],
+                              vec![3, 4]]);
+        assert_ne!(grid, vec![vec![1, 2],
+                              vec![3, 5]]);
+        assert_ne!(grid, vec![vec![1, 2]]);
+        assert_ne!(grid, vec![vec![1, 2, 3],
+                              vec![3, 4]]);
+        assert_ne!(grid, vec![vec![1, 2, 3, 4]]);
+
+        // The capacity of the grid doesn't matter.
+        let mut grid = Grid::from_rows(vec![vec![1, 2]]);
+        grid.reserve(size!(2, 2));
+        assert_eq!(grid, vec![vec![1, 2]]);
+
+        assert_eq!(Grid::<i32>::zero(), Vec::<Vec<i32>>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_index_tuple() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2],
+                                            vec![3, 0]]);
+
+        grid[(1, 1)] = 4;
+
+        assert_eq!(grid[(0, 0)], 1);
+        assert_eq!(grid[(1, 0)], 2);
+        assert_eq!(grid[(0, 1)], 3);
+        assert_eq!(grid[(1, 1)], 4);
+
+        let _ = grid[(0, 2)];
+    }
+
     #[test]
     fn grid_iterator() {
         let grid = Grid::from_rows(vec![vec![1, 2],
@@ -1544,6 +2635,63 @@ mod tests {
         assert_eq!(iterator.next(), None);
     }
 
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_view() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        assert_eq!(grid.view(rect!(0, 0, 3, 2)).values(), grid.values());
+        assert_eq!(grid.view(rect!(1, 1, 2, 1)).values(), vec![&5, &6]);
+        assert_eq!(grid.view(rect!(3, 2, 0, 0)).size(), size!(0, 0));
+
+        grid.view(rect!(1, 1, 2, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_view_overflow() {
+        let grid = Grid::with_size(size!(3, 2), 0);
+        grid.view(rect!(1, 0, usize::MAX, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_slice() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        assert_eq!(grid.slice(.., ..).values(), grid.values());
+        assert_eq!(grid.slice(1.., 1..).values(), vec![&5, &6]);
+        assert_eq!(grid.slice(..=0, ..).values(), vec![&1, &4]);
+        assert_eq!(grid.slice(2..2, 0..0).size(), size!(0, 0));
+
+        grid.slice(0..4, ..);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_extract() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        assert_eq!(grid.extract(rect!(0, 0, 3, 2)), grid);
+        assert_eq!(grid.extract(rect!(1, 1, 2, 1)), Grid::from_rows(vec![vec![5, 6]]));
+        assert_eq!(grid.extract(rect!(2, 0, 1, 2)), Grid::from_rows(vec![vec![3],
+                                                                         vec![6]]));
+        assert_eq!(grid.extract(rect!(1, 2, 2, 0)).size(), size!(2, 0));
+        assert_eq!(grid.extract(rect!(3, 2, 0, 0)), Grid::zero());
+
+        grid.extract(rect!(2, 0, 2, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_extract_overflow() {
+        let grid = Grid::with_size(size!(3, 2), 0);
+        grid.extract(rect!(0, 1, 1, usize::MAX));
+    }
+
     #[test]
     #[should_panic(expected = "index out of bounds")]
     fn grid_row() {
@@ -1580,6 +2728,52 @@ mod tests {
         grid.swap_row(1, 2);
     }
 
+    #[test]
+    fn grid_permute_rows() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2],
+                                            vec![3, 4],
+                                            vec![5, 6],
+                                            vec![7, 8],
+                                            vec![9, 10]]);
+
+        // Two cycles, (0 3 1) and (2 4).
+        grid.permute_rows(&[3, 0, 4, 1, 2]);
+        assert_eq!(grid, vec![vec![7, 8],
+                              vec![1, 2],
+                              vec![9, 10],
+                              vec![3, 4],
+                              vec![5, 6]]);
+
+        grid.permute_rows(&[0, 1, 2, 3, 4]);
+        assert_eq!(grid.column(0), [7, 1, 9, 3, 5]);
+
+        // The inverse permutation restores the grid.
+        grid.permute_rows(&[1, 3, 4, 0, 2]);
+        assert_eq!(grid.column(0), [1, 3, 5, 7, 9]);
+
+        let mut grid = Grid::<u8>::zero();
+        grid.permute_rows(&[]);
+        assert_eq!(grid, Grid::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "permutation is invalid")]
+    fn grid_permute_rows_duplicate() {
+        let mut grid = Grid::from_rows(vec![vec![1],
+                                            vec![2]]);
+
+        grid.permute_rows(&[1, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "permutation is invalid")]
+    fn grid_permute_rows_length() {
+        let mut grid = Grid::from_rows(vec![vec![1],
+                                            vec![2]]);
+
+        grid.permute_rows(&[0, 1, 2]);
+    }
+
     #[test]
     fn grid_rows() {
         let grid = Grid::from_rows(vec![vec![1, 2],
@@ -1588,6 +2782,29 @@ mod tests {
         assert_eq!(grid.rows(), vec![grid.row(0), grid.row(1)]);
     }
 
+    #[test]
+    fn grid_for_each_row_mut() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6],
+                                            vec![7, 8, 9]]);
+
+        let mut indices = Vec::new();
+        grid.for_each_row_mut(|mut row| {
+            indices.push(row.index);
+            if row.index % 2 == 0 {
+                row.reverse();
+            }
+            else {
+                row.fill(0);
+            }
+        });
+
+        assert_eq!(indices, vec![0, 1, 2]);
+        assert_eq!(grid.row(0).values(), vec![&3, &2, &1]);
+        assert_eq!(grid.row(1).values(), vec![&0, &0, &0]);
+        assert_eq!(grid.row(2).values(), vec![&9, &8, &7]);
+    }
+
     #[test]
     fn grid_insert_row() {
         let mut grid = Grid::from_rows(vec![vec![4, 5, 6]]);
@@ -1689,6 +2906,53 @@ mod tests {
         grid.insert_row(2, vec![7, 8]);
     }
 
+    #[test]
+    fn grid_insert_row_from() {
+        let other = Grid::from_rows(vec![vec![1, 2, 3],
+                                         vec![7, 8, 9]]);
+
+        let mut grid = Grid::from_rows(vec![vec![4, 5, 6]]);
+        grid.insert_row_from(0, other.row(0));
+        grid.insert_row_from(2, other.row(1));
+
+        assert_eq!(grid, Grid::from_rows(vec![vec![1, 2, 3],
+                                              vec![4, 5, 6],
+                                              vec![7, 8, 9]]));
+        assert_eq!(grid.capacity(), size!(3, 3));
+
+        // The allocated rows are reused.
+        let mut grid = Grid::from_rows(vec![vec![4, 5, 6]]);
+        grid.reserve(size!(1, 2));
+        grid.insert_row_from(1, other.row(1));
+
+        assert_eq!(grid.size(), size!(3, 2));
+        assert_eq!(grid.row(0), [4, 5, 6]);
+        assert_eq!(grid.row(1), [7, 8, 9]);
+        assert_eq!(grid.capacity(), size!(4, 3));
+
+        let mut grid = Grid::<()>::new();
+        grid.insert_row_from(0, Grid::with_size(size!(0, 1), ()).row(0));
+        assert_eq!(grid.size(), size!(0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_insert_row_from_invalid_index() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+        let other = Grid::from_rows(vec![vec![4, 5, 6]]);
+
+        grid.insert_row_from(2, other.row(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "row length is invalid")]
+    fn grid_insert_row_from_invalid_row() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+        let other = Grid::from_rows(vec![vec![4, 5]]);
+
+        grid.insert_row_from(1, other.row(0));
+    }
+
     #[test]
     fn grid_remove_row() {
         let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
@@ -1724,6 +2988,51 @@ mod tests {
         assert_eq!(grid.capacity(), size!(3, 3));
     }
 
+    #[test]
+    fn grid_transfer_row() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2],
+                                            vec![3, 4],
+                                            vec![5, 6]]);
+        let mut other = Grid::from_rows(vec![vec![7, 8]]);
+
+        grid.transfer_row(1, &mut other, 0);
+        grid.transfer_row(1, &mut other, 2);
+
+        assert_eq!(grid.size(), size!(2, 1));
+        assert_eq!(grid.row(0), [1, 2]);
+        assert_eq!(grid.capacity(), size!(2, 3));
+
+        assert_eq!(other, Grid::from_rows(vec![vec![3, 4],
+                                               vec![7, 8],
+                                               vec![5, 6]]));
+
+        // The elements are moved, not cloned.
+        let mut grid = Grid::from_rows(vec![vec![String::from("a")]]);
+        let mut other = Grid::with_size(size!(1, 0), String::new());
+
+        grid.transfer_row(0, &mut other, 0);
+        assert_eq!(grid.size(), size!(1, 0));
+        assert_eq!(other.value(coord!(0, 0)), "a");
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_transfer_row_invalid_index() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2]]);
+        let mut other = Grid::from_rows(vec![vec![3, 4]]);
+
+        grid.transfer_row(0, &mut other, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "row length is invalid")]
+    fn grid_transfer_row_invalid_row() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2]]);
+        let mut other = Grid::from_rows(vec![vec![3]]);
+
+        grid.transfer_row(0, &mut other, 0);
+    }
+
     #[test]
     #[should_panic(expected = "index out of bounds")]
     fn grid_column() {
@@ -1761,6 +3070,33 @@ mod tests {
         grid.swap_column(1, 2);
     }
 
+    #[test]
+    fn grid_permute_columns() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3, 4],
+                                            vec![5, 6, 7, 8]]);
+        grid.reserve(size!(0, 1));
+
+        grid.permute_columns(&[1, 3, 0, 2]);
+        assert_eq!(grid, vec![vec![2, 4, 1, 3],
+                              vec![6, 8, 5, 7]]);
+
+        grid.permute_columns(&[2, 0, 3, 1]);
+        assert_eq!(grid, vec![vec![1, 2, 3, 4],
+                              vec![5, 6, 7, 8]]);
+
+        let mut grid = Grid::with_size(size!(0, 2), 0);
+        grid.permute_columns(&[]);
+        assert_eq!(grid.size(), size!(0, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "permutation is invalid")]
+    fn grid_permute_columns_out_of_bounds() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2]]);
+
+        grid.permute_columns(&[0, 2]);
+    }
+
     #[test]
     fn grid_columns() {
         let grid = Grid::from_rows(vec![vec![1, 2],
@@ -1769,6 +3105,22 @@ mod tests {
         assert_eq!(grid.columns(), vec![grid.column(0), grid.column(1)]);
     }
 
+    #[test]
+    fn grid_for_each_column_mut() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6]]);
+
+        let mut indices = Vec::new();
+        grid.for_each_column_mut(|mut column| {
+            indices.push(column.index);
+            column.rotate_top(1);
+        });
+
+        assert_eq!(indices, vec![0, 1, 2]);
+        assert_eq!(grid.row(0).values(), vec![&4, &5, &6]);
+        assert_eq!(grid.row(1).values(), vec![&1, &2, &3]);
+    }
+
     #[test]
     fn grid_insert_column() {
         let mut grid = Grid::from_rows(vec![vec![2],
@@ -1876,6 +3228,46 @@ mod tests {
         grid.insert_column(2, vec![3, 6]);
     }
 
+    #[test]
+    fn grid_insert_column_from() {
+        let other = Grid::from_rows(vec![vec![1, 3],
+                                         vec![4, 6]]);
+
+        let mut grid = Grid::from_rows(vec![vec![2],
+                                            vec![5]]);
+        grid.insert_column_from(0, other.column(0));
+        grid.insert_column_from(2, other.column(1));
+
+        assert_eq!(grid, Grid::from_rows(vec![vec![1, 2, 3],
+                                              vec![4, 5, 6]]));
+        assert_eq!(grid.capacity(), size!(3, 2));
+
+        let mut grid = Grid::<()>::new();
+        grid.insert_column_from(0, Grid::with_size(size!(1, 0), ()).column(0));
+        assert_eq!(grid.size(), size!(1, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_insert_column_from_invalid_index() {
+        let mut grid = Grid::from_rows(vec![vec![1],
+                                            vec![2]]);
+        let other = Grid::from_rows(vec![vec![3],
+                                         vec![4]]);
+
+        grid.insert_column_from(2, other.column(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "column length is invalid")]
+    fn grid_insert_column_from_invalid_column() {
+        let mut grid = Grid::from_rows(vec![vec![1],
+                                            vec![2]]);
+        let other = Grid::from_rows(vec![vec![3]]);
+
+        grid.insert_column_from(1, other.column(0));
+    }
+
     #[test]
     fn grid_remove_column() {
         let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
@@ -1911,6 +3303,51 @@ mod tests {
         assert_eq!(grid.capacity(), size!(3, 3));
     }
 
+    #[test]
+    fn grid_transfer_column() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6]]);
+        let mut other = Grid::from_rows(vec![vec![7],
+                                             vec![8]]);
+
+        grid.transfer_column(1, &mut other, 0);
+        grid.transfer_column(1, &mut other, 2);
+
+        assert_eq!(grid.size(), size!(1, 2));
+        assert_eq!(grid.column(0), [1, 4]);
+        assert_eq!(grid.capacity(), size!(3, 2));
+
+        assert_eq!(other, Grid::from_rows(vec![vec![2, 7, 3],
+                                               vec![5, 8, 6]]));
+        assert_eq!(other.capacity(), size!(3, 2));
+
+        let mut grid = Grid::from_rows(vec![vec![String::from("a")]]);
+        let mut other = Grid::with_size(size!(0, 1), String::new());
+
+        grid.transfer_column(0, &mut other, 0);
+        assert_eq!(grid.size(), size!(0, 1));
+        assert_eq!(other.value(coord!(0, 0)), "a");
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_transfer_column_invalid_index() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2]]);
+        let mut other = Grid::from_rows(vec![vec![3]]);
+
+        grid.transfer_column(2, &mut other, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "column length is invalid")]
+    fn grid_transfer_column_invalid_column() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2]]);
+        let mut other = Grid::from_rows(vec![vec![3],
+                                             vec![4]]);
+
+        grid.transfer_column(0, &mut other, 0);
+    }
+
     #[test]
     fn grid_flip_horizontally() {
         // [1, 2, 3] => [3, 2, 1]
@@ -2069,6 +3506,29 @@ mod tests {
         assert_eq!(grid.capacity(), size!(0, 0));
     }
 
+    #[test]
+    fn grid_sort_each_row() {
+        let mut grid = Grid::from_rows(vec![vec![3, 1, 2],
+                                            vec![6, 5, 4]]);
+
+        grid.sort_each_row();
+
+        assert_eq!(grid.row(0).values(), vec![&1, &2, &3]);
+        assert_eq!(grid.row(1).values(), vec![&4, &5, &6]);
+    }
+
+    #[test]
+    fn grid_sort_each_column() {
+        let mut grid = Grid::from_rows(vec![vec![3, 4],
+                                            vec![1, 6],
+                                            vec![2, 5]]);
+
+        grid.sort_each_column();
+
+        assert_eq!(grid.column(0).values(), vec![&1, &2, &3]);
+        assert_eq!(grid.column(1).values(), vec![&4, &5, &6]);
+    }
+
     #[test]
     fn grid_rotate_left() {
         // [1, 2, 3] => [3, 6, 9]
@@ -2229,6 +3689,56 @@ mod tests {
         assert_eq!(grid.capacity(), size!(0, 0));
     }
 
+    #[test]
+    fn grid_rotate() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        for quarter_turns in -9i32..=9 {
+            let mut expected = Grid::from_rows(vec![vec![1, 2, 3],
+                                                    vec![4, 5, 6]]);
+            for _ in 0..quarter_turns.rem_euclid(4) {
+                expected.rotate_right();
+            }
+
+            let mut rotated = Grid::from_rows(grid.row_vectors().to_vec());
+            rotated.rotate(quarter_turns);
+            assert_eq!(rotated, expected);
+        }
+
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6]]);
+        grid.rotate(i32::MIN);
+        assert_eq!(grid.row(0), [1, 2, 3]);
+
+        grid.rotate(i32::MAX);
+        assert_eq!(grid, Grid::from_rows(vec![vec![3, 6],
+                                              vec![2, 5],
+                                              vec![1, 4]]));
+    }
+
+    #[test]
+    fn grid_rotate_capacity() {
+        let mut grid = Grid::with_capacity(size!(8, 5));
+        grid.resize(size!(4, 3), 0);
+        grid[coord!(3, 0)] = 1;
+
+        grid.rotate_left();
+        assert_eq!(grid.size(), size!(3, 4));
+        assert_eq!(grid.capacity(), size!(5, 8));
+        assert_eq!(grid[coord!(0, 0)], 1);
+
+        grid.rotate_right();
+        assert_eq!(grid.size(), size!(4, 3));
+        assert_eq!(grid.capacity(), size!(8, 5));
+        assert_eq!(grid[coord!(3, 0)], 1);
+
+        let mut grid = Grid::<u8>::zero();
+        grid.rotate_left();
+        grid.rotate_right();
+        assert_eq!(grid.size(), size!(0, 0));
+    }
+
     #[test]
     fn grid_capacity() {
         let grid = Grid::<()>::zero();
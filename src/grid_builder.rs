@@ -0,0 +1,287 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::fmt;
+use alloc::vec::Vec;
+use crate::grid::Grid;
+
+/// A builder to construct grids row by row
+///
+/// This structure accumulates the rows of a grid whose final height doesn't
+/// need to be known in advance, and produces the grid at the end. The width
+/// of the rows is validated as they're pushed; without padding, the first row
+/// determines the width of the grid.
+///
+/// It's convenient to parse grids from streams, line by line, without
+/// collecting the rows first.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Size, Grid, GridBuilder, size};
+/// #
+/// let text = "#..\n.#\n..#";
+///
+/// let mut builder = GridBuilder::new().pad_to(3, '.');
+/// for line in text.lines() {
+///     builder.push_row(line.chars().collect()).unwrap();
+/// }
+///
+/// let grid = builder.build();
+/// assert_eq!(grid.size(), size!(3, 3));
+/// assert_eq!(grid.row(1).values(), vec![&'.', &'#', &'.']);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct GridBuilder<T> {
+    width: Option<usize>,
+    fill: Option<T>,
+    rows: Vec<Vec<T>>
+}
+
+impl<T: Clone> GridBuilder<T> {
+
+    /// Construct a new builder.
+    ///
+    /// This function constructs a builder with no row, and whose width is
+    /// determined by the first row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::GridBuilder;
+    /// #
+    /// let builder = GridBuilder::<i32>::new();
+    /// assert_eq!(builder.height(), 0);
+    /// ```
+    ///
+    pub fn new() -> GridBuilder<T> {
+        GridBuilder { width: None, fill: None, rows: Vec::new() }
+    }
+
+    /// Pad the rows to a given width.
+    ///
+    /// This method sets the width of the grid, and the value that rows
+    /// shorter than that width are padded with. Rows longer than the width
+    /// are still rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width of the grid
+    /// * `fill` - The value to pad the rows with
+    ///
+    /// # Panics
+    ///
+    /// It panics if a row that was already pushed is longer than the width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::GridBuilder;
+    /// #
+    /// let mut builder = GridBuilder::new().pad_to(3, 0);
+    /// builder.push_row(vec![1]).unwrap();
+    ///
+    /// assert_eq!(builder.build().row(0).values(), vec![&1, &0, &0]);
+    /// ```
+    ///
+    pub fn pad_to(mut self, width: usize, fill: T) -> GridBuilder<T> {
+        assert!(self.rows.iter().all(|row| row.len() <= width), "rows are longer than the width");
+
+        for row in &mut self.rows {
+            row.resize(width, fill.clone());
+        }
+
+        self.width = Some(width);
+        self.fill = Some(fill);
+
+        self
+    }
+
+    /// Return the number of rows pushed so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::GridBuilder;
+    /// #
+    /// let mut builder = GridBuilder::new();
+    /// builder.push_row(vec![1, 2]).unwrap();
+    ///
+    /// assert_eq!(builder.height(), 1);
+    /// ```
+    ///
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Push a row.
+    ///
+    /// This method appends a row to the grid being built. If the row is
+    /// shorter than the width and padding is enabled, it's padded; otherwise,
+    /// its length must be equal to the width.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The elements of the row
+    ///
+    /// # Errors
+    ///
+    /// It returns an error if the length of the row doesn't match the width,
+    /// in which case the row is discarded and the builder is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::GridBuilder;
+    /// #
+    /// let mut builder = GridBuilder::new();
+    ///
+    /// builder.push_row(vec![1, 2])
+    ///        .unwrap()
+    ///        .push_row(vec![3, 4])
+    ///        .unwrap();
+    ///
+    /// let error = builder.push_row(vec![5]).unwrap_err();
+    /// assert_eq!(error.row, 2);
+    /// assert_eq!(error.expected, 2);
+    /// assert_eq!(error.found, 1);
+    /// ```
+    ///
+    pub fn push_row(&mut self, mut row: Vec<T>) -> Result<&mut GridBuilder<T>, RowWidthError> {
+        let width = *self.width.get_or_insert(row.len());
+
+        match &self.fill {
+            Some(fill) if row.len() < width => row.resize(width, fill.clone()),
+            _ => {}
+        }
+
+        if row.len() != width {
+            return Err(RowWidthError { row: self.rows.len(), expected: width, found: row.len() });
+        }
+
+        self.rows.push(row);
+        Ok(self)
+    }
+
+    /// Build the grid.
+    ///
+    /// This method consumes the builder and returns the grid made of the rows
+    /// pushed so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, GridBuilder, size};
+    /// #
+    /// let grid = GridBuilder::<i32>::new().pad_to(3, 0).build();
+    /// assert_eq!(grid.size(), size!(3, 0));
+    /// ```
+    ///
+    pub fn build(self) -> Grid<T> {
+        Grid::from_row_vectors(self.width.unwrap_or(0), self.rows)
+    }
+}
+
+impl<T: Clone> Default for GridBuilder<T> {
+    fn default() -> Self {
+        GridBuilder::new()
+    }
+}
+
+/// An error denoting a row of unexpected width
+///
+/// This structure is the error returned by `GridBuilder::push_row()` when the
+/// length of a row doesn't match the width of the grid.
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RowWidthError {
+    /// The index of the rejected row.
+    pub row: usize,
+
+    /// The width of the grid.
+    pub expected: usize,
+
+    /// The length of the rejected row.
+    pub found: usize
+}
+
+impl fmt::Display for RowWidthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row {} has {} elements instead of {}", self.row, self.found, self.expected)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RowWidthError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_builder_push_row() {
+        let mut builder = GridBuilder::new();
+        builder.push_row(vec![1, 2, 3]).unwrap();
+        builder.push_row(vec![4, 5, 6]).unwrap();
+
+        assert_eq!(builder.push_row(vec![7, 8]).unwrap_err(),
+                   RowWidthError { row: 2, expected: 3, found: 2 });
+        assert_eq!(builder.push_row(vec![7, 8, 9, 10]).unwrap_err(),
+                   RowWidthError { row: 2, expected: 3, found: 4 });
+        assert_eq!(builder.height(), 2);
+
+        builder.push_row(vec![7, 8, 9]).unwrap();
+        assert_eq!(builder.build(), Grid::from_rows(vec![vec![1, 2, 3],
+                                                         vec![4, 5, 6],
+                                                         vec![7, 8, 9]]));
+    }
+
+    #[test]
+    fn grid_builder_pad_to() {
+        let mut builder = GridBuilder::new();
+        builder.push_row(vec![1]).unwrap();
+
+        let mut builder = builder.pad_to(3, 0);
+        builder.push_row(vec![]).unwrap();
+        builder.push_row(vec![4, 5, 6]).unwrap();
+        assert_eq!(builder.push_row(vec![7, 8, 9, 10]).unwrap_err(),
+                   RowWidthError { row: 3, expected: 3, found: 4 });
+
+        assert_eq!(builder.build(), Grid::from_rows(vec![vec![1, 0, 0],
+                                                         vec![0, 0, 0],
+                                                         vec![4, 5, 6]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "rows are longer than the width")]
+    fn grid_builder_pad_to_shorter() {
+        let mut builder = GridBuilder::new();
+        builder.push_row(vec![1, 2, 3]).unwrap();
+
+        builder.pad_to(2, 0);
+    }
+
+    #[test]
+    fn grid_builder_build() {
+        assert_eq!(GridBuilder::<i32>::new().build().size(), size!(0, 0));
+        assert_eq!(GridBuilder::new().pad_to(4, 0).build().size(), size!(4, 0));
+
+        let mut builder = GridBuilder::<i32>::new();
+        builder.push_row(vec![]).unwrap();
+        builder.push_row(vec![]).unwrap();
+        assert_eq!(builder.build().size(), size!(0, 2));
+    }
+
+    #[test]
+    fn row_width_error_display() {
+        let error = RowWidthError { row: 2, expected: 3, found: 4 };
+        assert_eq!(format!("{}", error), "row 2 has 4 elements instead of 3");
+    }
+}
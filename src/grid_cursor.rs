@@ -0,0 +1,454 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use crate::coordinate::Coordinate;
+use crate::offset::Offset;
+use crate::grid::Grid;
+
+/// A cursor to navigate a grid
+///
+/// This structure is a cursor pointing at an element of a grid, which can be
+/// moved around to read and write the elements. Moves never leave the grid;
+/// they're refused on the edges instead, which spares turtle-style traversals
+/// (maze walkers, editors) from juggling coordinates and bounds checks.
+///
+/// It's created by the `cursor()` method of the grid and its lifetime is
+/// bound to the lifetime of the grid.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Grid, coord, size};
+/// #
+/// let mut grid = Grid::with_size(size!(3, 2), '.');
+///
+/// let mut cursor = grid.cursor(coord!(0, 0));
+/// while cursor.move_right() {
+///     cursor.set('#');
+/// }
+/// assert!(!cursor.move_up());
+/// assert!(cursor.move_down());
+/// assert_eq!(cursor.coordinate(), coord!(2, 1));
+///
+/// assert_eq!(grid.row(0).values(), vec![&'.', &'#', &'#']);
+/// ```
+///
+#[derive(Debug)]
+pub struct GridCursor<'a, T> {
+    grid: &'a mut Grid<T>,
+    coordinate: Coordinate
+}
+
+impl<T: Clone> Grid<T> {
+
+    /// Create a cursor onto the grid.
+    ///
+    /// This method creates a cursor pointing at an element of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - The coordinate of the element to point at
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// let cursor = grid.cursor(coord!(1, 0));
+    /// assert_eq!(cursor.value(), &2);
+    /// ```
+    ///
+    pub fn cursor(&mut self, coordinate: Coordinate) -> GridCursor<'_, T> {
+        assert!(self.size().contains(coordinate), "index out of bounds");
+
+        GridCursor {
+            grid: self,
+            coordinate
+        }
+    }
+}
+
+impl<'a, T: Clone> GridCursor<'a, T> {
+
+    /// Return the coordinate of the cursor.
+    ///
+    /// This method returns the coordinate of the element the cursor is
+    /// pointing at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(2, 2), 0);
+    ///
+    /// let mut cursor = grid.cursor(coord!(0, 1));
+    /// cursor.move_right();
+    /// assert_eq!(cursor.coordinate(), coord!(1, 1));
+    /// ```
+    ///
+    pub fn coordinate(&self) -> Coordinate {
+        self.coordinate
+    }
+
+    /// Return the element under the cursor.
+    ///
+    /// This method returns a reference to the element the cursor is pointing
+    /// at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// let mut cursor = grid.cursor(coord!(0, 0));
+    /// cursor.move_down();
+    /// assert_eq!(cursor.value(), &3);
+    /// ```
+    ///
+    pub fn value(&self) -> &T {
+        &self.grid[self.coordinate]
+    }
+
+    /// Return the element under the cursor mutably.
+    ///
+    /// This method returns a mutable reference to the element the cursor is
+    /// pointing at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// let mut cursor = grid.cursor(coord!(1, 1));
+    /// *cursor.value_mut() *= 10;
+    /// assert_eq!(grid[coord!(1, 1)], 40);
+    /// ```
+    ///
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.grid[self.coordinate]
+    }
+
+    /// Replace the element under the cursor.
+    ///
+    /// This method replaces the element the cursor is pointing at with a new
+    /// value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The new value of the element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// grid.cursor(coord!(0, 1)).set(0);
+    /// assert_eq!(grid[coord!(0, 1)], 0);
+    /// ```
+    ///
+    pub fn set(&mut self, value: T) {
+        self.grid[self.coordinate] = value;
+    }
+
+    /// Move the cursor by an offset.
+    ///
+    /// This method moves the cursor by an offset if the destination is within
+    /// the grid, and returns whether it moved; otherwise, the cursor stays
+    /// where it is.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The offset to move the cursor by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Offset, Size, Grid, coord, offset, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(3, 3), 0);
+    ///
+    /// let mut cursor = grid.cursor(coord!(0, 0));
+    /// assert!(cursor.move_by(offset!(2, 1)));
+    /// assert!(!cursor.move_by(offset!(1, 1)));
+    /// assert_eq!(cursor.coordinate(), coord!(2, 1));
+    /// ```
+    ///
+    pub fn move_by(&mut self, offset: Offset) -> bool {
+        match self.coordinate.checked_apply(offset, self.grid.size()) {
+            Some(coordinate) => {
+                self.coordinate = coordinate;
+                true
+            },
+            None => false
+        }
+    }
+
+    /// Move the cursor up.
+    ///
+    /// This method moves the cursor to the element above, unless it's on the
+    /// top edge; it returns whether it moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(2, 2), 0);
+    ///
+    /// let mut cursor = grid.cursor(coord!(0, 1));
+    /// assert!(cursor.move_up());
+    /// assert!(!cursor.move_up());
+    /// ```
+    ///
+    pub fn move_up(&mut self) -> bool {
+        self.move_by(Offset::new(0, -1))
+    }
+
+    /// Move the cursor down.
+    ///
+    /// This method moves the cursor to the element below, unless it's on the
+    /// bottom edge; it returns whether it moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(2, 2), 0);
+    ///
+    /// let mut cursor = grid.cursor(coord!(0, 0));
+    /// assert!(cursor.move_down());
+    /// assert!(!cursor.move_down());
+    /// ```
+    ///
+    pub fn move_down(&mut self) -> bool {
+        self.move_by(Offset::new(0, 1))
+    }
+
+    /// Move the cursor left.
+    ///
+    /// This method moves the cursor to the element on the left, unless it's on
+    /// the left edge; it returns whether it moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(2, 2), 0);
+    ///
+    /// let mut cursor = grid.cursor(coord!(1, 0));
+    /// assert!(cursor.move_left());
+    /// assert!(!cursor.move_left());
+    /// ```
+    ///
+    pub fn move_left(&mut self) -> bool {
+        self.move_by(Offset::new(-1, 0))
+    }
+
+    /// Move the cursor right.
+    ///
+    /// This method moves the cursor to the element on the right, unless it's
+    /// on the right edge; it returns whether it moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(2, 2), 0);
+    ///
+    /// let mut cursor = grid.cursor(coord!(0, 0));
+    /// assert!(cursor.move_right());
+    /// assert!(!cursor.move_right());
+    /// ```
+    ///
+    pub fn move_right(&mut self) -> bool {
+        self.move_by(Offset::new(1, 0))
+    }
+
+    /// Return the element at an offset from the cursor.
+    ///
+    /// This method returns the element at an offset from the cursor without
+    /// moving it, or `None` if it's outside of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The offset of the element from the cursor
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Offset, Grid, coord, offset};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// let cursor = grid.cursor(coord!(0, 0));
+    /// assert_eq!(cursor.peek(offset!(1, 1)), Some(&4));
+    /// assert_eq!(cursor.peek(offset!(-1, 0)), None);
+    /// ```
+    ///
+    pub fn peek(&self, offset: Offset) -> Option<&T> {
+        let coordinate = self.coordinate.checked_apply(offset, self.grid.size())?;
+        Some(&self.grid[coordinate])
+    }
+
+    /// Return the element above the cursor.
+    ///
+    /// This method returns the element above the cursor without moving it, or
+    /// `None` if the cursor is on the top edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// assert_eq!(grid.cursor(coord!(1, 1)).peek_up(), Some(&2));
+    /// assert_eq!(grid.cursor(coord!(1, 0)).peek_up(), None);
+    /// ```
+    ///
+    pub fn peek_up(&self) -> Option<&T> {
+        self.peek(Offset::new(0, -1))
+    }
+
+    /// Return the element below the cursor.
+    ///
+    /// This method returns the element below the cursor without moving it, or
+    /// `None` if the cursor is on the bottom edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// assert_eq!(grid.cursor(coord!(0, 0)).peek_down(), Some(&3));
+    /// assert_eq!(grid.cursor(coord!(0, 1)).peek_down(), None);
+    /// ```
+    ///
+    pub fn peek_down(&self) -> Option<&T> {
+        self.peek(Offset::new(0, 1))
+    }
+
+    /// Return the element on the left of the cursor.
+    ///
+    /// This method returns the element on the left of the cursor without
+    /// moving it, or `None` if the cursor is on the left edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// assert_eq!(grid.cursor(coord!(1, 0)).peek_left(), Some(&1));
+    /// assert_eq!(grid.cursor(coord!(0, 0)).peek_left(), None);
+    /// ```
+    ///
+    pub fn peek_left(&self) -> Option<&T> {
+        self.peek(Offset::new(-1, 0))
+    }
+
+    /// Return the element on the right of the cursor.
+    ///
+    /// This method returns the element on the right of the cursor without
+    /// moving it, or `None` if the cursor is on the right edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// assert_eq!(grid.cursor(coord!(0, 1)).peek_right(), Some(&4));
+    /// assert_eq!(grid.cursor(coord!(1, 1)).peek_right(), None);
+    /// ```
+    ///
+    pub fn peek_right(&self) -> Option<&T> {
+        self.peek(Offset::new(1, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_cursor() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6],
+                                            vec![7, 8, 9]]);
+
+        let mut cursor = grid.cursor(coord!(1, 1));
+        assert_eq!(cursor.coordinate(), coord!(1, 1));
+        assert_eq!(cursor.value(), &5);
+
+        assert_eq!(cursor.peek_up(), Some(&2));
+        assert_eq!(cursor.peek_down(), Some(&8));
+        assert_eq!(cursor.peek_left(), Some(&4));
+        assert_eq!(cursor.peek_right(), Some(&6));
+        assert_eq!(cursor.peek(offset!(1, -1)), Some(&3));
+
+        assert!(cursor.move_up());
+        assert!(!cursor.move_up());
+        assert_eq!(cursor.peek_up(), None);
+        assert!(cursor.move_left());
+        assert!(!cursor.move_left());
+        assert_eq!(cursor.peek_left(), None);
+        assert_eq!(cursor.coordinate(), coord!(0, 0));
+
+        assert!(cursor.move_by(offset!(2, 2)));
+        assert!(!cursor.move_down());
+        assert!(!cursor.move_right());
+        assert_eq!(cursor.peek_down(), None);
+        assert_eq!(cursor.peek_right(), None);
+        assert_eq!(cursor.value(), &9);
+
+        cursor.set(0);
+        *cursor.value_mut() += 10;
+        assert!(!cursor.move_by(offset!(-3, 0)));
+        assert_eq!(cursor.coordinate(), coord!(2, 2));
+
+        assert_eq!(grid[coord!(2, 2)], 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_cursor_out_of_bounds() {
+        let mut grid = Grid::with_size(size!(2, 2), 0);
+        grid.cursor(coord!(2, 0));
+    }
+}
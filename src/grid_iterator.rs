@@ -6,7 +6,7 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
-use std::iter::Iterator;
+use core::iter::Iterator;
 use crate::coordinate::Coordinate;
 use crate::enumerate_coordinate::EnumerateCoordinate;
 
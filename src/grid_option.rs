@@ -0,0 +1,267 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::grid::Grid;
+use crate::grid_iterator::GridIterator;
+
+impl<T: Clone> Grid<Option<T>> {
+
+    /// Take an element out of the grid.
+    ///
+    /// This method takes the value of an element out of a grid of optional
+    /// elements, leaving `None` in its place.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![Some(1), None],
+    ///                                     vec![None, Some(4)]]);
+    ///
+    /// assert_eq!(grid.take_value(coord!(0, 0)), Some(1));
+    /// assert_eq!(grid.take_value(coord!(0, 0)), None);
+    /// assert_eq!(grid.value(coord!(0, 0)), &None);
+    /// ```
+    ///
+    pub fn take_value(&mut self, coordinate: Coordinate) -> Option<T> {
+        self.value_mut(coordinate).take()
+    }
+
+    /// Check whether an element is set.
+    ///
+    /// This method returns whether an element of a grid of optional elements
+    /// is set, that is, whether it's not `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![Some(1), None],
+    ///                                 vec![None, Some(4)]]);
+    ///
+    /// assert!(grid.is_set(coord!(0, 0)));
+    /// assert!(!grid.is_set(coord!(1, 0)));
+    /// ```
+    ///
+    pub fn is_set(&self, coordinate: Coordinate) -> bool {
+        self.value(coordinate).is_some()
+    }
+
+    /// Return the coordinates of the elements that are set.
+    ///
+    /// This method returns the coordinates of the elements of a grid of
+    /// optional elements that are set, from left to right and top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![Some(1), None],
+    ///                                 vec![None, Some(4)]]);
+    ///
+    /// assert_eq!(grid.set_positions(), vec![coord!(0, 0), coord!(1, 1)]);
+    /// ```
+    ///
+    pub fn set_positions(&self) -> Vec<Coordinate> {
+        self.iterator()
+            .enumerate_coordinate()
+            .filter(|(_, value)| value.is_some())
+            .map(|(coordinate, _)| coordinate)
+            .collect()
+    }
+
+    /// Fill the elements that aren't set.
+    ///
+    /// This method sets all elements of a grid of optional elements that are
+    /// `None` with a given value; the elements that are set are left
+    /// unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to set the elements with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![Some(1), None],
+    ///                                     vec![None, Some(4)]]);
+    ///
+    /// grid.fill_none(0);
+    /// assert_eq!(grid, Grid::from_rows(vec![vec![Some(1), Some(0)],
+    ///                                       vec![Some(0), Some(4)]]));
+    /// ```
+    ///
+    pub fn fill_none(&mut self, value: T) {
+        for row in 0..self.size().height {
+            for element in self.row_slice_mut(row).iter_mut().filter(|element| element.is_none()) {
+                *element = Some(value.clone());
+            }
+        }
+    }
+
+    /// Remove the rows that are entirely empty.
+    ///
+    /// This method removes the rows of a grid of optional elements whose
+    /// elements are all `None`. The order of the remaining rows is preserved
+    /// and the capacity of the grid is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![None, None],
+    ///                                     vec![Some(1), None],
+    ///                                     vec![None, None]]);
+    ///
+    /// grid.compact_rows();
+    /// assert_eq!(grid.size(), size!(2, 1));
+    /// ```
+    ///
+    pub fn compact_rows(&mut self) {
+        for row in (0..self.size().height).rev() {
+            if self.row_slice(row).iter().all(Option::is_none) {
+                self.remove_row(row);
+            }
+        }
+    }
+
+    /// Remove the columns that are entirely empty.
+    ///
+    /// This method removes the columns of a grid of optional elements whose
+    /// elements are all `None`. The order of the remaining columns is
+    /// preserved and the capacity of the grid is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![None, Some(2), None],
+    ///                                     vec![None, Some(4), Some(5)]]);
+    ///
+    /// grid.compact_columns();
+    /// assert_eq!(grid.row(0).values(), vec![&Some(2), &None]);
+    /// assert_eq!(grid.row(1).values(), vec![&Some(4), &Some(5)]);
+    /// ```
+    ///
+    pub fn compact_columns(&mut self) {
+        for column in (0..self.size().width).rev() {
+            if self.row_vectors().iter().all(|row| row[column].is_none()) {
+                self.remove_column(column);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_take_value() {
+        let mut grid = Grid::from_rows(vec![vec![Some(1), Some(2)],
+                                            vec![None, Some(4)]]);
+
+        assert_eq!(grid.take_value(coord!(1, 0)), Some(2));
+        assert_eq!(grid.take_value(coord!(1, 0)), None);
+        assert_eq!(grid.take_value(coord!(0, 1)), None);
+        assert_eq!(grid, Grid::from_rows(vec![vec![Some(1), None],
+                                              vec![None, Some(4)]]));
+
+        grid.take_value(coord!(2, 0));
+    }
+
+    #[test]
+    fn grid_is_set() {
+        let grid = Grid::from_rows(vec![vec![Some(1), None],
+                                        vec![None, Some(4)]]);
+
+        assert!(grid.is_set(coord!(0, 0)));
+        assert!(!grid.is_set(coord!(1, 0)));
+        assert!(!grid.is_set(coord!(0, 1)));
+        assert!(grid.is_set(coord!(1, 1)));
+    }
+
+    #[test]
+    fn grid_set_positions() {
+        let grid = Grid::from_rows(vec![vec![None, Some(2), None],
+                                        vec![Some(4), None, Some(6)]]);
+
+        assert_eq!(grid.set_positions(), vec![coord!(1, 0), coord!(0, 1), coord!(2, 1)]);
+        assert_eq!(Grid::with_size(size!(2, 2), None::<i32>).set_positions(), vec![]);
+    }
+
+    #[test]
+    fn grid_fill_none() {
+        let mut grid = Grid::from_rows(vec![vec![None, Some(2), None],
+                                            vec![Some(4), None, Some(6)]]);
+
+        grid.fill_none(0);
+        assert_eq!(grid, Grid::from_rows(vec![vec![Some(0), Some(2), Some(0)],
+                                              vec![Some(4), Some(0), Some(6)]]));
+    }
+
+    #[test]
+    fn grid_compact_rows() {
+        let mut grid = Grid::from_rows(vec![vec![None, None],
+                                            vec![Some(1), None],
+                                            vec![None, None],
+                                            vec![None, Some(2)],
+                                            vec![None, None]]);
+
+        grid.compact_rows();
+        assert_eq!(grid.size(), size!(2, 2));
+        assert_eq!(grid.row(0).values(), vec![&Some(1), &None]);
+        assert_eq!(grid.row(1).values(), vec![&None, &Some(2)]);
+
+        let mut grid = Grid::with_size(size!(2, 2), None::<i32>);
+        grid.compact_rows();
+        assert_eq!(grid.size(), size!(2, 0));
+    }
+
+    #[test]
+    fn grid_compact_columns() {
+        let mut grid = Grid::from_rows(vec![vec![None, Some(1), None, None],
+                                            vec![None, None, None, Some(2)]]);
+
+        grid.compact_columns();
+        assert_eq!(grid.size(), size!(2, 2));
+        assert_eq!(grid.row(0).values(), vec![&Some(1), &None]);
+        assert_eq!(grid.row(1).values(), vec![&None, &Some(2)]);
+
+        let mut grid = Grid::with_size(size!(2, 2), None::<i32>);
+        grid.compact_columns();
+        assert_eq!(grid.size(), size!(0, 2));
+    }
+}
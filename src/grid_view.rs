@@ -0,0 +1,296 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::ops::Index;
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::rect::Rect;
+use crate::grid::Grid;
+use crate::iterator_grid_view::IteratorGridView;
+
+/// A view onto a rectangular region of a grid
+///
+/// This structure is an **immutable** view into a rectangular region of a grid
+/// and its **lifetime is bound** to the lifetime of the grid. It's a
+/// **lightweight** construct that allows to operate on sub-regions of grids as
+/// if they were grids on their own.
+///
+/// Elements are accessed with coordinates that are **relative** to the view,
+/// therefore, coordinate (0, 0) corresponds to the top-left element of the
+/// region, and not the top-left element of the grid. Note that views are
+/// indexable.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Grid, coord, size};
+/// #
+/// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+///                                 vec![4, 5, 6],
+///                                 vec![7, 8, 9]]);
+///
+/// let view = grid.slice(1..3, 1..3);
+/// assert_eq!(view.size(), size!(2, 2));
+/// assert_eq!(view[coord!(0, 0)], 5);
+/// assert_eq!(view.values(), vec![&5, &6, &8, &9]);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct GridView<'a, T> {
+    /// A reference to its grid.
+    pub grid: &'a Grid<T>,
+
+    /// The region of the grid.
+    pub rect: Rect
+}
+
+impl<'a, T: Clone> GridView<'a, T> {
+
+    /// Return the size of the view.
+    ///
+    /// This method returns the size of the view, which is the size of the
+    /// region of the grid it's looking at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::with_size(size!(3, 3), 42);
+    ///
+    /// assert_eq!(grid.slice(0..2, 1..3).size(), size!(2, 2));
+    /// ```
+    ///
+    pub fn size(&self) -> Size {
+        self.rect.size
+    }
+
+    /// Returns a reference to an element of the view.
+    ///
+    /// This method returns a reference to an element of the view from its
+    /// coordinate, which is relative to the top-left corner of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// let view = grid.slice(1..3, 0..2);
+    /// assert_eq!(view.value(coord!(0, 0)), &2);
+    /// assert_eq!(view.value(coord!(1, 1)), &6);
+    ///
+    /// view.value(coord!(2, 0)); // It panics here !
+    /// ```
+    ///
+    pub fn value(&self, coordinate: Coordinate) -> &'a T {
+        assert!(self.rect.size.contains(coordinate), "index out of bounds");
+
+        self.grid.value(Coordinate::new(self.rect.coordinate.x + coordinate.x,
+                                        self.rect.coordinate.y + coordinate.y))
+    }
+
+    /// Return the elements of the view.
+    ///
+    /// This method returns the elements of the view as a vector of reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.slice(0..2, ..).values(), vec![&1, &2, &4, &5]);
+    /// ```
+    ///
+    pub fn values(&self) -> Vec<&T> {
+        self.iterator().collect()
+    }
+
+    /// Returns an iterator over the view.
+    ///
+    /// This method returns an iterator over the view. Note that the
+    /// coordinates it yields are relative to the view.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                 vec![3, 4]]);
+    ///
+    /// let mut iterator = grid.slice(1..2, ..).iterator();
+    /// assert_eq!(iterator.next(), Some(&2));
+    /// assert_eq!(iterator.next(), Some(&4));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    ///
+    pub fn iterator(&self) -> IteratorGridView<'a, T> {
+        IteratorGridView::new(self.clone())
+    }
+
+    // Return the elements of a row of the view as a slice.
+    fn row_slice(&self, row: usize) -> &'a [T] {
+        let (left, right) = (self.rect.coordinate.x, self.rect.coordinate.x + self.rect.size.width);
+        &self.grid.row_slice(self.rect.coordinate.y + row)[left..right]
+    }
+}
+
+impl<'a, T: Clone> Index<Coordinate> for GridView<'a, T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &Self::Output {
+        self.value(coordinate)
+    }
+}
+
+// Views are equal if they have the same elements, whatever their grids and
+// their positions in them.
+impl<'a, 'b, T: Clone + PartialEq> PartialEq<GridView<'b, T>> for GridView<'a, T> {
+    fn eq(&self, other: &GridView<'b, T>) -> bool {
+        self.size() == other.size() &&
+        (0..self.rect.size.height).all(|row| self.row_slice(row) == other.row_slice(row))
+    }
+}
+
+impl<'a, T: Clone + Eq> Eq for GridView<'a, T> {}
+
+impl<'a, T: Clone + PartialEq> PartialEq<Grid<T>> for GridView<'a, T> {
+    fn eq(&self, other: &Grid<T>) -> bool {
+        self.size() == other.size() &&
+        (0..self.rect.size.height).all(|row| self.row_slice(row) == other.row_slice(row))
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<GridView<'a, T>> for Grid<T> {
+    fn eq(&self, other: &GridView<'a, T>) -> bool {
+        *other == *self
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<Vec<Vec<T>>> for GridView<'a, T> {
+    fn eq(&self, other: &Vec<Vec<T>>) -> bool {
+        self.rect.size.height == other.len() &&
+        other.iter().enumerate().all(|(row, values)| self.row_slice(row) == values.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_view_size() {
+        let grid = Grid::with_size(size!(4, 3), 0);
+
+        assert_eq!(grid.slice(.., ..).size(), size!(4, 3));
+        assert_eq!(grid.slice(1.., ..2).size(), size!(3, 2));
+        assert_eq!(grid.slice(1..1, 0..3).size(), size!(0, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_view_value() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6],
+                                        vec![7, 8, 9]]);
+
+        let view = grid.slice(1..3, 1..3);
+        assert_eq!(view.value(coord!(0, 0)), &5);
+        assert_eq!(view.value(coord!(1, 0)), &6);
+        assert_eq!(view.value(coord!(0, 1)), &8);
+        assert_eq!(view.value(coord!(1, 1)), &9);
+
+        view.value(coord!(0, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_view_index() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6],
+                                        vec![7, 8, 9]]);
+
+        let view = grid.slice(0..2, 0..2);
+        assert_eq!(view[coord!(0, 0)], 1);
+        assert_eq!(view[coord!(1, 0)], 2);
+        assert_eq!(view[coord!(0, 1)], 4);
+        assert_eq!(view[coord!(1, 1)], 5);
+
+        let _ = view[coord!(2, 1)];
+    }
+
+    #[test]
+    fn grid_view_values() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6],
+                                        vec![7, 8, 9]]);
+
+        assert_eq!(grid.slice(1..2, ..).values(), vec![&2, &5, &8]);
+        assert_eq!(grid.slice(.., 2..).values(), vec![&7, &8, &9]);
+        assert_eq!(grid.slice(2..2, ..).values(), Vec::<&i32>::new());
+    }
+
+    #[test]
+    fn grid_view_eq() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 1],
+                                        vec![3, 4, 3]]);
+
+        // The positions of the views don't matter, only their elements.
+        assert_eq!(grid.slice(..1, ..), grid.slice(2.., ..));
+        assert_ne!(grid.slice(..1, ..), grid.slice(1..2, ..));
+        assert_ne!(grid.slice(..1, ..), grid.slice(..1, ..1));
+
+        let other = Grid::from_rows(vec![vec![4, 3]]);
+        assert_eq!(grid.slice(1.., 1..), other.slice(.., ..));
+        assert_ne!(grid.slice(1..1, ..), grid.slice(.., 2..2));
+    }
+
+    #[test]
+    fn grid_view_eq_grid() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+        let other = Grid::from_rows(vec![vec![2, 3],
+                                         vec![5, 6]]);
+
+        assert_eq!(grid.slice(1.., ..), other);
+        assert_eq!(other, grid.slice(1.., ..));
+        assert_ne!(grid.slice(..2, ..), other);
+        assert_ne!(grid.slice(1.., ..1), other);
+
+        assert_eq!(grid.slice(.., ..), grid);
+        assert_eq!(grid.slice(1..1, 2..2), Grid::zero());
+    }
+
+    #[test]
+    fn grid_view_eq_vec() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        assert_eq!(grid.slice(1.., ..), vec![vec![2, 3],
+                                             vec![5, 6]]);
+        assert_eq!(grid.slice(..1, 1..), vec![vec![4]]);
+        assert_ne!(grid.slice(..1, 1..), vec![vec![4, 5]]);
+        assert_ne!(grid.slice(..1, ..), vec![vec![1]]);
+        assert_eq!(grid.slice(.., 2..), Vec::<Vec<i32>>::new());
+    }
+}
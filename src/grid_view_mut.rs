@@ -0,0 +1,398 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::ops::{Index, IndexMut};
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::rect::Rect;
+use crate::grid::Grid;
+
+/// A mutable view onto a rectangular region of a grid
+///
+/// This structure is a **mutable** view into a rectangular region of a grid
+/// and its **lifetime is bound** to the lifetime of the grid. It's created by
+/// the `split_tiles_mut()` method of the grid, which guarantees the views it
+/// returns don't overlap.
+///
+/// Unlike an immutable view, it only borrows the elements of its region, so
+/// several mutable views onto the same grid can coexist. It's `Send` when the
+/// elements are, and views can therefore be modified from different threads.
+///
+/// Elements are accessed with coordinates that are **relative** to the view,
+/// therefore, coordinate (0, 0) corresponds to the top-left element of the
+/// region, and not the top-left element of the grid. Note that views are
+/// indexable.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Grid, coord, size};
+/// #
+/// let mut grid = Grid::with_size(size!(4, 2), 0);
+///
+/// let mut tiles = grid.split_tiles_mut(size!(2, 2));
+/// tiles[1][coord!(0, 1)] = 42;
+///
+/// assert_eq!(tiles[1].rect.coordinate, coord!(2, 0));
+/// assert_eq!(grid.value(coord!(2, 1)), &42);
+/// ```
+///
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    rows: Vec<&'a mut [T]>,
+
+    /// The region of the grid.
+    pub rect: Rect
+}
+
+impl<'a, T: Clone> GridViewMut<'a, T> {
+
+    /// Return the size of the view.
+    ///
+    /// This method returns the size of the view, which is the size of the
+    /// region of the grid it's modifying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(3, 3), 42);
+    /// let tiles = grid.split_tiles_mut(size!(2, 2));
+    ///
+    /// assert_eq!(tiles[0].size(), size!(2, 2));
+    /// assert_eq!(tiles[3].size(), size!(1, 1));
+    /// ```
+    ///
+    pub fn size(&self) -> Size {
+        self.rect.size
+    }
+
+    /// Returns a reference to an element of the view.
+    ///
+    /// This method returns a reference to an element of the view from its
+    /// coordinate, which is relative to the top-left corner of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![4, 5, 6]]);
+    ///
+    /// let tiles = grid.split_tiles_mut(size!(2, 2));
+    /// assert_eq!(tiles[1].value(coord!(0, 1)), &6);
+    ///
+    /// tiles[1].value(coord!(1, 0)); // It panics here !
+    /// ```
+    ///
+    pub fn value(&self, coordinate: Coordinate) -> &T {
+        assert!(self.rect.size.contains(coordinate), "index out of bounds");
+
+        &self.rows[coordinate.y][coordinate.x]
+    }
+
+    /// Returns a mutable reference to an element of the view.
+    ///
+    /// This method returns a mutable reference to an element of the view from
+    /// its coordinate, which is relative to the top-left corner of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(2, 2), 0);
+    ///
+    /// let mut tiles = grid.split_tiles_mut(size!(1, 2));
+    /// *tiles[1].value_mut(coord!(0, 1)) = 42;
+    ///
+    /// assert_eq!(grid.value(coord!(1, 1)), &42);
+    /// ```
+    ///
+    pub fn value_mut(&mut self, coordinate: Coordinate) -> &mut T {
+        assert!(self.rect.size.contains(coordinate), "index out of bounds");
+
+        &mut self.rows[coordinate.y][coordinate.x]
+    }
+
+    /// Change the value of an element of the view.
+    ///
+    /// This method changes the value of an element of the view from its
+    /// coordinate, which is relative to the top-left corner of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    /// * `value` - New value of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(2, 2), 0);
+    ///
+    /// let mut tiles = grid.split_tiles_mut(size!(2, 1));
+    /// tiles[1].set_value(coord!(1, 0), 42);
+    ///
+    /// assert_eq!(grid.value(coord!(1, 1)), &42);
+    /// ```
+    ///
+    pub fn set_value(&mut self, coordinate: Coordinate, value: T) {
+        *self.value_mut(coordinate) = value;
+    }
+
+    /// Return the elements of the view.
+    ///
+    /// This method returns the elements of the view as a vector of reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![4, 5, 6]]);
+    ///
+    /// let tiles = grid.split_tiles_mut(size!(2, 2));
+    /// assert_eq!(tiles[0].values(), vec![&1, &2, &4, &5]);
+    /// ```
+    ///
+    pub fn values(&self) -> Vec<&T> {
+        self.rows.iter().flat_map(|row| row.iter()).collect()
+    }
+
+    /// Fill the view with a value.
+    ///
+    /// This method changes the value of all the elements of the view, leaving
+    /// the rest of the grid untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to fill the view with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(3, 2), 0);
+    ///
+    /// let mut tiles = grid.split_tiles_mut(size!(2, 2));
+    /// tiles[1].fill(1);
+    ///
+    /// assert_eq!(grid, Grid::from_rows(vec![vec![0, 0, 1],
+    ///                                       vec![0, 0, 1]]));
+    /// ```
+    ///
+    pub fn fill(&mut self, value: T) {
+        for row in self.rows.iter_mut() {
+            row.fill(value.clone());
+        }
+    }
+}
+
+impl<'a, T: Clone> Index<Coordinate> for GridViewMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &Self::Output {
+        self.value(coordinate)
+    }
+}
+
+impl<'a, T: Clone> IndexMut<Coordinate> for GridViewMut<'a, T> {
+    fn index_mut(&mut self, coordinate: Coordinate) -> &mut Self::Output {
+        self.value_mut(coordinate)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+
+    /// Split the grid into mutable tiles.
+    ///
+    /// This method splits the grid into tiles of a given size and returns a
+    /// mutable view onto each of them, from left to right and top to bottom.
+    /// The tiles on the right and bottom borders are smaller if the size of
+    /// the grid isn't a multiple of the size of the tiles.
+    ///
+    /// The views are disjoint and they're `Send` when the elements are, so
+    /// the tiles can be processed in parallel, for instance with scoped
+    /// threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the tiles
+    ///
+    /// # Panics
+    ///
+    /// It panics if the size of the tiles is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let mut grid = Grid::with_size(size!(4, 4), 0);
+    ///
+    /// std::thread::scope(|scope| {
+    ///     for (index, mut tile) in grid.split_tiles_mut(size!(2, 2)).into_iter().enumerate() {
+    ///         scope.spawn(move || tile.fill(index));
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(grid, Grid::from_rows(vec![vec![0, 0, 1, 1],
+    ///                                       vec![0, 0, 1, 1],
+    ///                                       vec![2, 2, 3, 3],
+    ///                                       vec![2, 2, 3, 3]]));
+    /// ```
+    ///
+    pub fn split_tiles_mut(&mut self, size: Size) -> Vec<GridViewMut<'_, T>> {
+        assert!(!size.is_empty(), "tile size is empty");
+
+        let grid_size = self.size();
+        let columns = grid_size.width.div_ceil(size.width);
+
+        let mut tiles = Vec::new();
+        for (y, row) in self.row_vectors_mut().iter_mut().enumerate() {
+            // The first row of a band of tiles creates them.
+            if y % size.height == 0 {
+                for column in 0..columns {
+                    let x = column * size.width;
+                    let width = size.width.min(grid_size.width - x);
+                    let height = size.height.min(grid_size.height - y);
+
+                    tiles.push(GridViewMut {
+                        rows: Vec::with_capacity(height),
+                        rect: Rect::new(Coordinate::new(x, y), Size::new(width, height))
+                    });
+                }
+            }
+
+            let band = tiles.len() - columns;
+            for (tile, slice) in tiles[band..].iter_mut().zip(row.chunks_mut(size.width)) {
+                tile.rows.push(slice);
+            }
+        }
+
+        tiles
+    }
+
+    // Return a mutable view onto the whole grid; unlike the grid itself, the
+    // view can't be resized.
+    #[cfg(feature = "std")]
+    pub(crate) fn view_mut(&mut self) -> GridViewMut<'_, T> {
+        let rect = Rect::new(Coordinate::zero(), self.size());
+        let rows = self.row_vectors_mut().iter_mut().map(|row| row.as_mut_slice()).collect();
+
+        GridViewMut { rows, rect }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn grid_split_tiles_mut() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3, 4, 5],
+                                            vec![6, 7, 8, 9, 10],
+                                            vec![11, 12, 13, 14, 15]]);
+
+        let tiles = grid.split_tiles_mut(size!(2, 2));
+        assert_eq!(tiles.len(), 6);
+
+        let rects = tiles.iter().map(|tile| tile.rect).collect::<Vec<_>>();
+        assert_eq!(rects, vec![rect!(0, 0, 2, 2), rect!(2, 0, 2, 2), rect!(4, 0, 1, 2),
+                               rect!(0, 2, 2, 1), rect!(2, 2, 2, 1), rect!(4, 2, 1, 1)]);
+
+        assert_eq!(tiles[0].values(), vec![&1, &2, &6, &7]);
+        assert_eq!(tiles[2].values(), vec![&5, &10]);
+        assert_eq!(tiles[4].values(), vec![&13, &14]);
+        assert_eq!(tiles[5][coord!(0, 0)], 15);
+
+        // The tiles cover each element exactly once.
+        let mut tiles = grid.split_tiles_mut(size!(3, 2));
+        for tile in tiles.iter_mut() {
+            for y in 0..tile.size().height {
+                for x in 0..tile.size().width {
+                    tile[coord!(x, y)] *= 10;
+                }
+            }
+        }
+        assert_eq!(grid, vec![vec![10, 20, 30, 40, 50],
+                              vec![60, 70, 80, 90, 100],
+                              vec![110, 120, 130, 140, 150]]);
+
+        // A tile larger than the grid covers it entirely.
+        let tiles = grid.split_tiles_mut(size!(8, 8));
+        assert_eq!(tiles.len(), 1);
+        assert_eq!(tiles[0].rect, rect!(0, 0, 5, 3));
+
+        assert!(Grid::<i32>::zero().split_tiles_mut(size!(2, 2)).is_empty());
+    }
+
+    #[test]
+    fn grid_split_tiles_mut_threads() {
+        let mut grid = Grid::with_size(size!(7, 5), 0usize);
+
+        thread::scope(|scope| {
+            for mut tile in grid.split_tiles_mut(size!(3, 2)) {
+                scope.spawn(move || {
+                    let origin = tile.rect.coordinate;
+                    for y in 0..tile.size().height {
+                        for x in 0..tile.size().width {
+                            tile.set_value(coord!(x, y), (origin.y + y) * 7 + origin.x + x);
+                        }
+                    }
+                });
+            }
+        });
+
+        assert!(grid.iterator().copied().eq(0..35));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_view_mut_value() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+
+        let mut tiles = grid.split_tiles_mut(size!(2, 2));
+        tiles[1].set_value(coord!(1, 0), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "tile size is empty")]
+    fn grid_split_tiles_mut_empty() {
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid.split_tiles_mut(size!(0, 2));
+    }
+}
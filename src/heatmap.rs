@@ -0,0 +1,230 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::fmt::Write;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+
+/// A renderer of numeric grids as terminal heatmaps
+///
+/// This structure renders numeric grids as heatmaps made of colored blocks,
+/// using ANSI escape codes with 24-bit colors, which can be printed directly
+/// in the terminal. Each element is drawn as two characters wide so the
+/// blocks look square.
+///
+/// Values are mapped to a palette of colors which are interpolated linearly,
+/// the first color corresponding to the lowest value of the range, and the
+/// last color to the highest value. By default, the range is the range of
+/// the values of the grid, and the palette goes from blue to red.
+///
+/// This structure is only available with the `heatmap` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Grid, Heatmap};
+/// #
+/// let grid = Grid::from_rows(vec![vec![0.0, 0.5],
+///                                 vec![0.5, 1.0]]);
+///
+/// let heatmap = Heatmap::new()
+///     .palette(vec![(0, 0, 0), (255, 255, 255)])
+///     .range(0.0, 1.0);
+///
+/// print!("{}", heatmap.render(&grid));
+/// ```
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heatmap {
+    palette: Vec<(u8, u8, u8)>,
+    range: Option<(f64, f64)>
+}
+
+impl Heatmap {
+
+    /// Construct a heatmap renderer.
+    ///
+    /// This function constructs a heatmap renderer with the default palette
+    /// (from blue to red) and the range of the values of the grids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, Heatmap};
+    /// #
+    /// let heatmap = Heatmap::new();
+    /// assert!(heatmap.render(&Grid::from_rows(vec![vec![1, 2]])).contains("\x1b[48;2;"));
+    /// ```
+    ///
+    pub fn new() -> Heatmap {
+        Heatmap {
+            palette: vec![(0, 0, 255), (0, 255, 255), (0, 255, 0), (255, 255, 0), (255, 0, 0)],
+            range: None
+        }
+    }
+
+    /// Set the palette of the heatmap.
+    ///
+    /// This method sets the colors that the values are mapped to, from the
+    /// lowest to the highest value, as RGB triplets.
+    ///
+    /// # Arguments
+    ///
+    /// * `palette` - The colors of the palette
+    ///
+    /// # Panics
+    ///
+    /// It panics if the palette is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, Heatmap};
+    /// #
+    /// let heatmap = Heatmap::new().palette(vec![(255, 0, 0)]);
+    /// assert!(heatmap.render(&Grid::from_rows(vec![vec![1]])).contains("48;2;255;0;0m"));
+    /// ```
+    ///
+    pub fn palette(mut self, palette: Vec<(u8, u8, u8)>) -> Heatmap {
+        assert!(!palette.is_empty(), "palette is empty");
+
+        self.palette = palette;
+        self
+    }
+
+    /// Set the range of values of the heatmap.
+    ///
+    /// This method sets the values that the first and last colors of the
+    /// palette correspond to; values outside of the range are clamped.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The value of the first color
+    /// * `max` - The value of the last color
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, Heatmap};
+    /// #
+    /// let heatmap = Heatmap::new()
+    ///     .palette(vec![(0, 0, 0), (255, 255, 255)])
+    ///     .range(0.0, 10.0);
+    ///
+    /// assert!(heatmap.render(&Grid::from_rows(vec![vec![20]])).contains("48;2;255;255;255m"));
+    /// ```
+    ///
+    pub fn range(mut self, min: f64, max: f64) -> Heatmap {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Render a grid as a heatmap.
+    ///
+    /// This method returns the heatmap of a grid as a string of colored
+    /// blocks, one line per row, ready to be printed in the terminal.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid to render
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, Heatmap};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                 vec![3, 4]]);
+    ///
+    /// let heatmap = Heatmap::new().render(&grid);
+    /// assert_eq!(heatmap.lines().count(), 2);
+    /// ```
+    ///
+    pub fn render<T: Numeric>(&self, grid: &Grid<T>) -> String {
+        let (min, max) = self.range.unwrap_or_else(|| {
+            grid.iterator().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value.to_f64()), max.max(value.to_f64()))
+            })
+        });
+
+        let mut output = String::new();
+        for row in grid.row_vectors() {
+            for value in row {
+                let (red, green, blue) = self.color(value.to_f64(), min, max);
+                write!(output, "\x1b[48;2;{};{};{}m  ", red, green, blue).unwrap();
+            }
+
+            output.push_str("\x1b[0m\n");
+        }
+
+        output
+    }
+
+    // Compute the color of a value, interpolated from the palette.
+    fn color(&self, value: f64, min: f64, max: f64) -> (u8, u8, u8) {
+        // A flat range maps all values to the first color.
+        let t = if max > min { ((value - min) / (max - min)).clamp(0.0, 1.0) } else { 0.0 };
+
+        let position = t * (self.palette.len() - 1) as f64;
+        let index = (position as usize).min(self.palette.len() - 1);
+        let next = (index + 1).min(self.palette.len() - 1);
+        let t = position - index as f64;
+
+        let (a, b) = (self.palette[index], self.palette[next]);
+        let mix = |a: u8, b: u8| u8::from_f64(a as f64 + (b as f64 - a as f64) * t);
+
+        (mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+    }
+}
+
+impl Default for Heatmap {
+    fn default() -> Self {
+        Heatmap::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heatmap_render() {
+        let grid = Grid::from_rows(vec![vec![0, 5],
+                                        vec![10, 20]]);
+
+        let heatmap = Heatmap::new()
+            .palette(vec![(0, 0, 0), (200, 100, 0)])
+            .range(0.0, 10.0);
+
+        assert_eq!(heatmap.render(&grid), "\x1b[48;2;0;0;0m  \x1b[48;2;100;50;0m  \x1b[0m\n\
+                                           \x1b[48;2;200;100;0m  \x1b[48;2;200;100;0m  \x1b[0m\n");
+    }
+
+    #[test]
+    fn heatmap_render_range() {
+        let heatmap = Heatmap::new().palette(vec![(0, 0, 0), (100, 0, 0), (100, 100, 0)]);
+
+        // The range defaults to the range of the values of the grid.
+        let grid = Grid::from_rows(vec![vec![-1.0, 0.0, 1.0]]);
+        assert_eq!(heatmap.render(&grid), "\x1b[48;2;0;0;0m  \x1b[48;2;100;0;0m  \x1b[48;2;100;100;0m  \x1b[0m\n");
+
+        let grid = Grid::from_rows(vec![vec![3, 3]]);
+        assert_eq!(heatmap.render(&grid), "\x1b[48;2;0;0;0m  \x1b[48;2;0;0;0m  \x1b[0m\n");
+
+        assert_eq!(heatmap.render(&Grid::<i32>::zero()), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "palette is empty")]
+    fn heatmap_palette() {
+        Heatmap::new().palette(vec![]);
+    }
+}
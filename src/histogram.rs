@@ -0,0 +1,117 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+
+impl<T: Numeric> Grid<T> {
+
+    /// Equalize the histogram of the grid.
+    ///
+    /// This method redistributes the values of the grid so that their
+    /// histogram is as flat as possible, which enhances the contrast of
+    /// image-like grids. Each value is mapped according to the proportion of
+    /// elements that are lower or equal (the cumulative distribution), onto
+    /// the range between the lowest and the highest values of the grid; the
+    /// order of the values is preserved.
+    ///
+    /// Integers are rounded to the nearest value. A grid whose elements all
+    /// have the same value is left unchanged, and so are the elements that
+    /// aren't finite numbers (infinities and NaN), which are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![  0,  10,  10,  10],
+    ///                                     vec![ 10,  20,  20, 255]]);
+    ///
+    /// grid.equalize_histogram();
+    /// assert_eq!(grid, Grid::from_rows(vec![vec![  0, 146, 146, 146],
+    ///                                       vec![146, 219, 219, 255]]));
+    /// ```
+    ///
+    pub fn equalize_histogram(&mut self) {
+        let mut values = self.iterator().map(|value| value.to_f64()).filter(|value| value.is_finite()).collect::<Vec<_>>();
+        values.sort_by(f64::total_cmp);
+
+        let (low, high) = match (values.first(), values.last()) {
+            (Some(&low), Some(&high)) => (low, high),
+            _ => return
+        };
+
+        // The number of elements lower or equal to a value.
+        let cumulative = |value: f64| values.partition_point(|&other| other <= value);
+
+        let total = values.len();
+        let lowest = cumulative(low);
+        if lowest == total {
+            return;
+        }
+
+        let scale = (high - low) / (total - lowest) as f64;
+        for row in 0..self.size().height {
+            for element in self.row_slice_mut(row) {
+                let value = element.to_f64();
+                if value.is_finite() {
+                    *element = T::from_f64(low + (cumulative(value) - lowest) as f64 * scale);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_equalize_histogram() {
+        // The values are spread evenly according to their rank.
+        let mut grid = Grid::from_rows(vec![vec![0u8, 1, 2, 3],
+                                            vec![4, 5, 6, 100]]);
+        grid.equalize_histogram();
+        assert_eq!(grid, Grid::from_rows(vec![vec![0, 14, 29, 43],
+                                              vec![57, 71, 86, 100]]));
+
+        let mut grid = Grid::from_rows(vec![vec![1.0, 2.0],
+                                            vec![2.0, 5.0]]);
+        grid.equalize_histogram();
+        assert_eq!(grid, Grid::from_rows(vec![vec![1.0, 11.0 / 3.0],
+                                              vec![11.0 / 3.0, 5.0]]));
+
+        let mut grid = Grid::from_rows(vec![vec![-3, -3],
+                                            vec![-3, -3]]);
+        grid.equalize_histogram();
+        assert_eq!(grid, Grid::from_rows(vec![vec![-3, -3],
+                                              vec![-3, -3]]));
+
+        let mut grid = Grid::<f32>::zero();
+        grid.equalize_histogram();
+        assert_eq!(grid, Grid::zero());
+    }
+
+    #[test]
+    fn grid_equalize_histogram_non_finite() {
+        // The values that aren't finite are left unchanged and ignored.
+        let mut grid = Grid::from_rows(vec![vec![0.0, 1.0, f64::INFINITY]]);
+        grid.equalize_histogram();
+        assert_eq!(grid, Grid::from_rows(vec![vec![0.0, 1.0, f64::INFINITY]]));
+
+        let mut grid = Grid::from_rows(vec![vec![f64::NEG_INFINITY, 1.0, 2.0]]);
+        grid.equalize_histogram();
+        assert_eq!(grid, Grid::from_rows(vec![vec![f64::NEG_INFINITY, 1.0, 2.0]]));
+
+        let mut grid = Grid::from_rows(vec![vec![f64::NAN, 0.0, 2.0, 2.0, 4.0]]);
+        grid.equalize_histogram();
+        assert!(grid.row(0)[0].is_nan());
+        assert_eq!(grid.row(0).values()[1..], [&0.0, &(8.0 / 3.0), &(8.0 / 3.0), &4.0]);
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::ops::Index;
+use alloc::collections::btree_map::BTreeMap;
+use alloc::collections::btree_set::BTreeSet;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::grid::Grid;
+use crate::grid_iterator::GridIterator;
+
+/// A grid that indexes the coordinates of its values
+///
+/// This structure is a grid which maintains a reverse index from each value
+/// to the coordinates of the elements holding it. The index is updated on
+/// every write, which is why the elements can't be borrowed mutably, and it
+/// allows to find all the elements with a given value without scanning the
+/// grid; the positions of all the enemies of a game map for instance.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Grid, IndexedGrid, coord, size};
+/// #
+/// let mut grid = IndexedGrid::from(Grid::with_size(size!(3, 3), '.'));
+///
+/// grid.set_value(coord!(0, 1), 'e');
+/// grid.set_value(coord!(2, 2), 'e');
+///
+/// let enemies = grid.coordinates_of(&'e').collect::<Vec<_>>();
+/// assert_eq!(enemies, vec![coord!(0, 1), coord!(2, 2)]);
+/// ```
+///
+#[derive(Debug, Eq, PartialEq)]
+pub struct IndexedGrid<T> {
+    grid: Grid<T>,
+    index: BTreeMap<T, BTreeSet<Coordinate>>
+}
+
+impl<T: Clone + Ord> IndexedGrid<T> {
+
+    /// Return the size of the grid.
+    ///
+    /// This method returns the size of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, IndexedGrid, size};
+    /// #
+    /// let grid = IndexedGrid::from(Grid::with_size(size!(3, 2), 0));
+    /// assert_eq!(grid.size(), size!(3, 2));
+    /// ```
+    ///
+    pub fn size(&self) -> Size {
+        self.grid.size()
+    }
+
+    /// Return the underlying grid.
+    ///
+    /// This method returns a reference to the elements of the grid, as a
+    /// regular grid, to use the read-only API of grids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, IndexedGrid, size};
+    /// #
+    /// let grid = IndexedGrid::from(Grid::with_size(size!(3, 2), 0));
+    /// assert_eq!(grid.grid().row(0).values(), vec![&0, &0, &0]);
+    /// ```
+    ///
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    /// Return an element of the grid.
+    ///
+    /// This method returns a reference to an element of the grid from its
+    /// coordinate.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, IndexedGrid, coord};
+    /// #
+    /// let grid = IndexedGrid::from(Grid::from_rows(vec![vec![1, 2],
+    ///                                                   vec![3, 4]]));
+    ///
+    /// assert_eq!(grid.value(coord!(1, 1)), &4);
+    /// ```
+    ///
+    pub fn value(&self, coordinate: Coordinate) -> &T {
+        self.grid.value(coordinate)
+    }
+
+    /// Replace an element of the grid.
+    ///
+    /// This method replaces the value of an element of the grid from its
+    /// coordinate and a new value, and updates the index accordingly.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    /// * `value` - New value of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, IndexedGrid, coord, size};
+    /// #
+    /// let mut grid = IndexedGrid::from(Grid::with_size(size!(2, 2), 0));
+    ///
+    /// grid.set_value(coord!(1, 0), 42);
+    /// assert_eq!(grid.value(coord!(1, 0)), &42);
+    /// assert_eq!(grid.count_of(&0), 3);
+    /// ```
+    ///
+    pub fn set_value(&mut self, coordinate: Coordinate, value: T) {
+        let previous = self.grid.value(coordinate);
+        if *previous == value {
+            return;
+        }
+
+        if let Some(coordinates) = self.index.get_mut(previous) {
+            coordinates.remove(&coordinate);
+            if coordinates.is_empty() {
+                self.index.remove(previous);
+            }
+        }
+
+        self.index.entry(value.clone()).or_default().insert(coordinate);
+        self.grid.set_value(coordinate, value);
+    }
+
+    /// Return an iterator over the coordinates of a value.
+    ///
+    /// This method returns an iterator over the coordinates of the elements
+    /// holding a given value, in row-major order. It looks the value up in
+    /// the index instead of scanning the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to look up
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, IndexedGrid, coord};
+    /// #
+    /// let grid = IndexedGrid::from(Grid::from_rows(vec![vec![1, 2],
+    ///                                                   vec![2, 1]]));
+    ///
+    /// let coordinates = grid.coordinates_of(&2).collect::<Vec<_>>();
+    /// assert_eq!(coordinates, vec![coord!(1, 0), coord!(0, 1)]);
+    /// assert_eq!(grid.coordinates_of(&3).next(), None);
+    /// ```
+    ///
+    pub fn coordinates_of<'a>(&'a self, value: &T) -> impl Iterator<Item = Coordinate> + 'a {
+        self.index.get(value).into_iter().flat_map(|coordinates| coordinates.iter().copied())
+    }
+
+    /// Return the number of elements holding a value.
+    ///
+    /// This method returns the number of elements holding a given value,
+    /// without scanning the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to look up
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, IndexedGrid};
+    /// #
+    /// let grid = IndexedGrid::from(Grid::from_rows(vec![vec![1, 2],
+    ///                                                   vec![2, 2]]));
+    ///
+    /// assert_eq!(grid.count_of(&2), 3);
+    /// assert_eq!(grid.count_of(&3), 0);
+    /// ```
+    ///
+    pub fn count_of(&self, value: &T) -> usize {
+        self.index.get(value).map_or(0, |coordinates| coordinates.len())
+    }
+
+    /// Check whether the grid holds a value.
+    ///
+    /// This method returns true if at least one element holds a given value,
+    /// without scanning the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to look up
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, IndexedGrid};
+    /// #
+    /// let grid = IndexedGrid::from(Grid::from_rows(vec![vec![1, 2]]));
+    ///
+    /// assert!(grid.contains(&1));
+    /// assert!(!grid.contains(&3));
+    /// ```
+    ///
+    pub fn contains(&self, value: &T) -> bool {
+        self.index.contains_key(value)
+    }
+
+    /// Convert the indexed grid into a regular grid.
+    ///
+    /// This method returns the elements of the grid as a regular grid and
+    /// drops the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, IndexedGrid, size};
+    /// #
+    /// let grid = IndexedGrid::from(Grid::with_size(size!(2, 2), 42));
+    /// assert_eq!(grid.into_grid(), Grid::with_size(size!(2, 2), 42));
+    /// ```
+    ///
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+}
+
+impl<T: Clone + Ord> From<Grid<T>> for IndexedGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        let mut index = BTreeMap::<T, BTreeSet<Coordinate>>::new();
+        for (coordinate, value) in grid.iterator().enumerate_coordinate() {
+            index.entry(value.clone()).or_default().insert(coordinate);
+        }
+
+        IndexedGrid { grid, index }
+    }
+}
+
+impl<T: Clone + Ord> Index<Coordinate> for IndexedGrid<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &Self::Output {
+        self.value(coordinate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn indexed_grid_from() {
+        let grid = IndexedGrid::from(Grid::from_rows(vec![vec!['a', 'b', 'a'],
+                                                          vec!['c', 'a', 'b']]));
+
+        assert_eq!(grid.size(), size!(3, 2));
+        assert_eq!(grid.coordinates_of(&'a').collect::<Vec<_>>(), vec![coord!(0, 0),
+                                                                       coord!(2, 0),
+                                                                       coord!(1, 1)]);
+        assert_eq!(grid.coordinates_of(&'b').collect::<Vec<_>>(), vec![coord!(1, 0),
+                                                                       coord!(2, 1)]);
+        assert_eq!(grid.count_of(&'c'), 1);
+        assert_eq!(grid.count_of(&'d'), 0);
+        assert!(!grid.contains(&'d'));
+    }
+
+    #[test]
+    fn indexed_grid_set_value() {
+        let mut grid = IndexedGrid::from(Grid::from_rows(vec![vec![1, 2],
+                                                              vec![3, 4]]));
+
+        grid.set_value(coord!(0, 0), 4);
+        assert_eq!(grid[coord!(0, 0)], 4);
+        assert!(!grid.contains(&1));
+        assert_eq!(grid.coordinates_of(&4).collect::<Vec<_>>(), vec![coord!(0, 0),
+                                                                     coord!(1, 1)]);
+
+        grid.set_value(coord!(0, 0), 4);
+        assert_eq!(grid.count_of(&4), 2);
+
+        grid.set_value(coord!(1, 1), 2);
+        assert_eq!(grid.coordinates_of(&4).collect::<Vec<_>>(), vec![coord!(0, 0)]);
+        assert_eq!(grid.coordinates_of(&2).collect::<Vec<_>>(), vec![coord!(1, 0),
+                                                                     coord!(1, 1)]);
+
+        assert_eq!(grid.into_grid(), Grid::from_rows(vec![vec![4, 2],
+                                                          vec![3, 2]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn indexed_grid_set_value_out_of_bounds() {
+        let mut grid = IndexedGrid::from(Grid::with_size(size!(2, 2), 0));
+        grid.set_value(coord!(2, 0), 1);
+    }
+}
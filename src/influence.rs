@@ -0,0 +1,160 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use alloc::collections::VecDeque;
+use crate::coordinate::Coordinate;
+use crate::offset::Offset;
+use crate::grid::Grid;
+
+// The 4 directions the influence spreads in.
+const DIRECTIONS: [Offset; 4] = [
+    Offset { x:  1, y:  0 },
+    Offset { x:  0, y:  1 },
+    Offset { x: -1, y:  0 },
+    Offset { x:  0, y: -1 }
+];
+
+impl<T: Clone> Grid<T> {
+
+    /// Compute an influence map.
+    ///
+    /// This method spreads the values of seeds outward over the passable
+    /// elements of the grid and returns the resulting influence map, which
+    /// is commonly used for decision making in games. The influence spreads
+    /// horizontally and vertically, and it's multiplied by the decay at each
+    /// step; where the influences of several seeds meet, the highest one is
+    /// kept.
+    ///
+    /// Elements that aren't passable, or that can't be reached from any seed,
+    /// have no influence (they're zero). Seeds placed on elements that aren't
+    /// passable are ignored, and so are seeds whose value isn't positive.
+    ///
+    /// # Arguments
+    ///
+    /// * `seeds` - The coordinates and the values of the seeds
+    /// * `decay` - The factor the influence is multiplied by at each step
+    /// * `passable` - The function telling whether an element can be crossed
+    ///
+    /// # Panics
+    ///
+    /// It panics if a seed is out of bounds, or if the decay isn't between 0
+    /// and 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec!['.', '.', '#', '.'],
+    ///                                 vec!['.', '.', '.', '.']]);
+    ///
+    /// let influence = grid.propagate(&[(coord!(0, 0), 8.0)], 0.5, |tile| *tile != '#');
+    /// assert_eq!(influence, Grid::from_rows(vec![vec![8.0, 4.0, 0.0, 0.25],
+    ///                                            vec![4.0, 2.0, 1.0, 0.5]]));
+    /// ```
+    ///
+    pub fn propagate<F>(&self, seeds: &[(Coordinate, f32)], decay: f32, mut passable: F) -> Grid<f32>
+        where F: FnMut(&T) -> bool
+    {
+        assert!((0.0..=1.0).contains(&decay), "decay is out of range");
+
+        let size = self.size();
+        let passable = (0..size.height)
+            .map(|row| self.row_slice(row).iter().map(&mut passable).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let mut influence = Grid::with_size(size, 0.0);
+        let mut queue = VecDeque::new();
+
+        for &(coordinate, value) in seeds {
+            assert!(size.contains(coordinate), "index out of bounds");
+
+            if passable[coordinate.y][coordinate.x] && value > influence[coordinate] {
+                influence[coordinate] = value;
+                queue.push_back(coordinate);
+            }
+        }
+
+        // Elements are spread again each time their influence increases,
+        // which can only happen a finite number of times.
+        while let Some(coordinate) = queue.pop_front() {
+            let value = influence[coordinate] * decay;
+
+            for &direction in &DIRECTIONS {
+                if let Some(neighbor) = coordinate.checked_apply(direction, size) {
+                    if passable[neighbor.y][neighbor.x] && value > influence[neighbor] {
+                        influence[neighbor] = value;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+
+        influence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_propagate() {
+        let grid = Grid::with_size(size!(5, 1), true);
+
+        let influence = grid.propagate(&[(coord!(0, 0), 16.0), (coord!(4, 0), 4.0)], 0.5, |&passable| passable);
+        assert_eq!(influence.row(0), [16.0, 8.0, 4.0, 2.0, 4.0]);
+
+        // The seed placed last still loses against a stronger influence.
+        let influence = grid.propagate(&[(coord!(4, 0), 4.0), (coord!(0, 0), 16.0)], 0.5, |&passable| passable);
+        assert_eq!(influence.row(0), [16.0, 8.0, 4.0, 2.0, 4.0]);
+
+        let influence = grid.propagate(&[(coord!(2, 0), 3.0)], 1.0, |&passable| passable);
+        assert_eq!(influence.row(0), [3.0; 5]);
+
+        let influence = grid.propagate(&[(coord!(2, 0), 3.0)], 0.0, |&passable| passable);
+        assert_eq!(influence.row(0), [0.0, 0.0, 3.0, 0.0, 0.0]);
+
+        let influence = grid.propagate(&[(coord!(2, 0), -3.0)], 0.5, |&passable| passable);
+        assert_eq!(influence.row(0), [0.0; 5]);
+    }
+
+    #[test]
+    fn grid_propagate_passable() {
+        // The influence goes around the wall instead of through it.
+        let grid = Grid::from_rows(vec![vec!['.', '#', '.'],
+                                        vec!['.', '#', '.'],
+                                        vec!['.', '.', '.']]);
+
+        let influence = grid.propagate(&[(coord!(0, 0), 64.0)], 0.5, |tile| *tile == '.');
+        assert_eq!(influence, Grid::from_rows(vec![vec![64.0, 0.0, 1.0],
+                                                   vec![32.0, 0.0, 2.0],
+                                                   vec![16.0, 8.0, 4.0]]));
+
+        let influence = grid.propagate(&[(coord!(1, 0), 64.0)], 0.5, |tile| *tile == '.');
+        assert_eq!(influence, Grid::with_size(size!(3, 3), 0.0));
+
+        assert_eq!(Grid::<char>::zero().propagate(&[], 0.5, |_| true), Grid::zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_propagate_out_of_bounds() {
+        let grid = Grid::with_size(size!(2, 2), true);
+        grid.propagate(&[(coord!(0, 2), 1.0)], 0.5, |&passable| passable);
+    }
+
+    #[test]
+    #[should_panic(expected = "decay is out of range")]
+    fn grid_propagate_decay() {
+        let grid = Grid::with_size(size!(2, 2), true);
+        grid.propagate(&[(coord!(0, 0), 1.0)], 1.5, |&passable| passable);
+    }
+}
@@ -6,11 +6,12 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
-use std::iter::Iterator;
+use core::iter::{Iterator, DoubleEndedIterator, ExactSizeIterator, FusedIterator};
+use core::slice::Iter;
+use alloc::vec::Vec;
 use crate::coordinate::Coordinate;
 use crate::column::Column;
 use crate::grid_iterator::GridIterator;
-use crate::coord;
 
 /// An iterator over a column
 ///
@@ -31,14 +32,30 @@ use crate::coord;
 /// assert_eq!(iterator.next(), None);
 /// ```
 ///
+#[derive(Debug, Clone)]
 pub struct IteratorColumn<'a, T> {
     column: Column<'a, T>,
+    rows: Iter<'a, Vec<T>>,
     index: usize
 }
 
-impl<'a, T> IteratorColumn<'a, T> {
+impl<'a, T: Clone> IteratorColumn<'a, T> {
     pub fn new(column: Column<'a, T>) -> IteratorColumn<'a, T> {
-        IteratorColumn { column, index: 0 }
+        // Check the bounds once and for all; the column index never changes
+        // and all rows have the same length.
+        assert!(column.index < column.grid.size().width, "index out of bounds");
+
+        let rows = column.grid.row_vectors().iter();
+        IteratorColumn { column, rows, index: 0 }
+    }
+
+    // Create an iterator over a part of the column only; segments rely on
+    // this.
+    pub(crate) fn with_range(column: Column<'a, T>, start: usize, end: usize) -> IteratorColumn<'a, T> {
+        assert!(column.index < column.grid.size().width, "index out of bounds");
+
+        let rows = column.grid.row_vectors()[start..end].iter();
+        IteratorColumn { column, rows, index: start }
     }
 }
 
@@ -46,17 +63,32 @@ impl<'a, T: Clone> Iterator for IteratorColumn<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index == self.column.length() {
-            None
-        }
-        else {
-            let value = self.column.value(self.index);
-            self.index += 1;
-            Some(value)
-        }
+        let row = self.rows.next()?;
+        self.index += 1;
+
+        // The column index was checked against the width of the grid on
+        // construction, which is the length of every row; the check of the
+        // indexing is hoisted out of the loops by the compiler.
+        Some(&row[self.column.index])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<'a, T: Clone> DoubleEndedIterator for IteratorColumn<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let row = self.rows.next_back()?;
+
+        Some(&row[self.column.index])
     }
 }
 
+impl<'a, T: Clone> ExactSizeIterator for IteratorColumn<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for IteratorColumn<'a, T> {}
+
 impl<'a, T: Clone> GridIterator for IteratorColumn<'a, T> {
     fn coordinate(&self) -> Coordinate {
         coord!(self.column.index, self.index)
@@ -96,4 +128,55 @@ mod tests {
         assert_eq!(iterator.coordinate(), coord!(1, 3));
         assert_eq!(iterator.next(), None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn iterator_column_clone() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4],
+                                        vec![5, 6]]);
+
+        let mut iterator = IteratorColumn::new(grid.column(1));
+        iterator.next();
+
+        let mut fork = iterator.clone();
+        assert_eq!(fork.next(), Some(&4));
+        assert_eq!(fork.next(), Some(&6));
+        assert_eq!(iterator.next(), Some(&4));
+
+        assert!(format!("{:?}", iterator).starts_with("IteratorColumn"));
+    }
+
+    #[test]
+    fn iterator_column_rev() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4],
+                                        vec![5, 6]]);
+
+        let values: Vec<_> = IteratorColumn::new(grid.column(1)).rev().collect();
+        assert_eq!(values, vec![&6, &4, &2]);
+
+        let mut iterator = IteratorColumn::new(grid.column(0));
+        assert_eq!(iterator.next_back(), Some(&5));
+        assert_eq!(iterator.next(), Some(&1));
+        assert_eq!(iterator.next_back(), Some(&3));
+        assert_eq!(iterator.next(), None);
+        assert_eq!(iterator.next_back(), None);
+    }
+
+    #[test]
+    fn iterator_column_size_hint() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4],
+                                        vec![5, 6]]);
+
+        let mut iterator = IteratorColumn::new(grid.column(1));
+        assert_eq!(iterator.len(), 3);
+
+        iterator.next();
+        assert_eq!(iterator.size_hint(), (2, Some(2)));
+
+        iterator.by_ref().for_each(drop);
+        assert_eq!(iterator.len(), 0);
+        assert_eq!(iterator.next(), None);
+    }
+}
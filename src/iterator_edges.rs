@@ -0,0 +1,158 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::iter::{Iterator, FusedIterator};
+use crate::coordinate::Coordinate;
+use crate::grid::Grid;
+
+/// An iterator over the adjacent pairs of elements of a grid
+///
+/// This structure is an iterator over the pairs of elements of a grid that are
+/// horizontally or vertically adjacent, yielding each pair exactly once along
+/// with the coordinates of both elements. It's created by the `edges()` method
+/// of the grid.
+///
+/// The pairs are ordered by their first element, from left to right and top
+/// to bottom; the pair with the element on the right comes before the pair
+/// with the element below.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Grid, coord};
+/// #
+/// let grid = Grid::from_rows(vec![vec![1, 2],
+///                                 vec![3, 4]]);
+///
+/// let mut edges = grid.edges();
+/// assert_eq!(edges.next(), Some(((coord!(0, 0), &1), (coord!(1, 0), &2))));
+/// assert_eq!(edges.next(), Some(((coord!(0, 0), &1), (coord!(0, 1), &3))));
+/// assert_eq!(edges.next(), Some(((coord!(1, 0), &2), (coord!(1, 1), &4))));
+/// assert_eq!(edges.next(), Some(((coord!(0, 1), &3), (coord!(1, 1), &4))));
+/// assert_eq!(edges.next(), None);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct IteratorEdges<'a, T> {
+    grid: &'a Grid<T>,
+    coordinate: Coordinate,
+    vertical: bool
+}
+
+impl<'a, T: Clone> IteratorEdges<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> IteratorEdges<'a, T> {
+        IteratorEdges {
+            grid,
+            coordinate: Coordinate::zero(),
+            vertical: false
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for IteratorEdges<'a, T> {
+    type Item = ((Coordinate, &'a T), (Coordinate, &'a T));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = self.grid.size();
+
+        while self.coordinate.y < size.height && self.coordinate.x < size.width {
+            let coordinate = self.coordinate;
+            let vertical = self.vertical;
+
+            // Move to the next pair, which is the pair below or the pair on
+            // the right of the next element.
+            if vertical {
+                self.vertical = false;
+                self.coordinate.x += 1;
+                if self.coordinate.x == size.width {
+                    self.coordinate.x = 0;
+                    self.coordinate.y += 1;
+                }
+            }
+            else {
+                self.vertical = true;
+            }
+
+            let neighbor = if vertical {
+                Coordinate::new(coordinate.x, coordinate.y + 1)
+            }
+            else {
+                Coordinate::new(coordinate.x + 1, coordinate.y)
+            };
+
+            if size.contains(neighbor) {
+                return Some(((coordinate, &self.grid[coordinate]), (neighbor, &self.grid[neighbor])));
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, T: Clone> FusedIterator for IteratorEdges<'a, T> {}
+
+impl<T: Clone> Grid<T> {
+
+    /// Return an iterator over the adjacent pairs of elements.
+    ///
+    /// This method returns an iterator over the pairs of elements that are
+    /// horizontally or vertically adjacent, each pair being yielded exactly
+    /// once with the coordinates of its elements. It's the natural input for
+    /// smoothing constraints, union-find algorithms, or building a graph out
+    /// of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 1, 2],
+    ///                                 vec![3, 1, 2]]);
+    ///
+    /// // Count the adjacent elements that have the same value.
+    /// let count = grid.edges().filter(|((_, a), (_, b))| a == b).count();
+    /// assert_eq!(count, 3);
+    /// ```
+    ///
+    pub fn edges(&self) -> IteratorEdges<'_, T> {
+        IteratorEdges::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn iterator_edges() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let edges = grid.edges().map(|((_, &a), (_, &b))| (a, b)).collect::<Vec<_>>();
+        assert_eq!(edges, vec![(1, 2), (1, 4), (2, 3), (2, 5), (3, 6), (4, 5), (5, 6)]);
+
+        let mut edges = grid.edges().skip(4);
+        assert_eq!(edges.next(), Some(((coord!(2, 0), &3), (coord!(2, 1), &6))));
+        assert_eq!(edges.next(), Some(((coord!(0, 1), &4), (coord!(1, 1), &5))));
+        assert_eq!(edges.next(), Some(((coord!(1, 1), &5), (coord!(2, 1), &6))));
+        assert_eq!(edges.next(), None);
+        assert_eq!(edges.next(), None);
+
+        // A single row or column only has pairs in one direction.
+        let grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+        assert_eq!(grid.edges().count(), 2);
+
+        let grid = Grid::from_rows(vec![vec![1], vec![2], vec![3]]);
+        let edges = grid.edges().map(|((_, &a), (_, &b))| (a, b)).collect::<Vec<_>>();
+        assert_eq!(edges, vec![(1, 2), (2, 3)]);
+
+        assert_eq!(Grid::from_rows(vec![vec![1]]).edges().next(), None);
+        assert_eq!(Grid::<i32>::zero().edges().next(), None);
+    }
+}
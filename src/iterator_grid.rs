@@ -6,11 +6,10 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
-use std::iter::Iterator;
+use core::iter::{Iterator, DoubleEndedIterator, ExactSizeIterator, FusedIterator};
 use crate::coordinate::Coordinate;
 use crate::grid::Grid;
 use crate::grid_iterator::GridIterator;
-use crate::coord;
 
 /// An iterator over a grid
 ///
@@ -33,14 +32,66 @@ use crate::coord;
 /// assert_eq!(iterator.next(), None);
 /// ```
 ///
+#[derive(Debug, Clone)]
 pub struct IteratorGrid<'a, T> {
     grid: &'a Grid<T>,
-    coordinate: Coordinate
+    coordinate: Coordinate,
+    end: usize
 }
 
-impl<'a, T> IteratorGrid<'a, T> {
+impl<'a, T: Clone> IteratorGrid<'a, T> {
     pub fn new(grid: &'a Grid<T>) -> IteratorGrid<'a, T> {
-        IteratorGrid { grid, coordinate: coord!(0, 0) }
+        // An empty grid yields no element; start right at the end.
+        let coordinate = if grid.is_empty() {
+            coord!(0, grid.size().height)
+        }
+        else {
+            coord!(0, 0)
+        };
+
+        IteratorGrid { grid, coordinate, end: grid.len() }
+    }
+
+    /// Construct an iterator starting at a given coordinate.
+    ///
+    /// This function constructs an iterator over a grid that yields the
+    /// elements from a given coordinate onward, skipping the ones before
+    /// without visiting them.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid to iterate over
+    /// * `coordinate` - Coordinate of the first element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, IteratorGrid, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                 vec![3, 4]]);
+    ///
+    /// let mut iterator = IteratorGrid::starting_at(&grid, coord!(1, 0));
+    /// assert_eq!(iterator.next(), Some(&2));
+    /// assert_eq!(iterator.next(), Some(&3));
+    /// assert_eq!(iterator.next(), Some(&4));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    ///
+    pub fn starting_at(grid: &'a Grid<T>, coordinate: Coordinate) -> IteratorGrid<'a, T> {
+        assert!(grid.size().contains(coordinate), "index out of bounds");
+
+        IteratorGrid { grid, coordinate, end: grid.len() }
+    }
+
+    // Return the position of the front coordinate as if the elements were laid
+    // out in a single dimension.
+    fn index(&self) -> usize {
+        self.coordinate.y * self.grid.size().width + self.coordinate.x
     }
 }
 
@@ -48,7 +99,7 @@ impl<'a, T: Clone> Iterator for IteratorGrid<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.coordinate.y == self.grid.size().height {
+        if self.index() == self.end {
             None
         }
         else {
@@ -63,8 +114,43 @@ impl<'a, T: Clone> Iterator for IteratorGrid<'a, T> {
             Some(value)
         }
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // Jump straight to the n-th element instead of stepping through the
+        // elements before it.
+        let index = self.index().saturating_add(n).min(self.end);
+
+        let width = self.grid.size().width;
+        if width > 0 {
+            self.coordinate = coord!(index % width, index / width);
+        }
+
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.index();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: Clone> DoubleEndedIterator for IteratorGrid<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index() == self.end {
+            None
+        }
+        else {
+            self.end -= 1;
+
+            Some(self.grid.value(Coordinate::from_index(self.end, self.grid.size())))
+        }
+    }
 }
 
+impl<'a, T: Clone> ExactSizeIterator for IteratorGrid<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for IteratorGrid<'a, T> {}
+
 impl<'a, T: Clone> GridIterator for IteratorGrid<'a, T> {
     fn coordinate(&self) -> Coordinate {
         self.coordinate
@@ -74,7 +160,9 @@ impl<'a, T: Clone> GridIterator for IteratorGrid<'a, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
     use crate::grid::Grid;
+    use crate::size::Size;
 
     #[test]
     fn iterator_from_grid() {
@@ -95,4 +183,128 @@ mod tests {
         assert_eq!(iterator.next(), Some(&9));
         assert_eq!(iterator.next(), None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn iterator_grid_clone() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4]]);
+
+        let mut iterator = IteratorGrid::new(&grid);
+        iterator.next();
+
+        // The fork advances independently of the original iterator.
+        let mut fork = iterator.clone();
+        assert_eq!(fork.next(), Some(&2));
+        assert_eq!(fork.next(), Some(&3));
+        assert_eq!(iterator.next(), Some(&2));
+
+        assert!(format!("{:?}", iterator).starts_with("IteratorGrid"));
+    }
+
+    #[test]
+    fn iterator_grid_starting_at() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let mut iterator = IteratorGrid::starting_at(&grid, coord!(2, 0));
+        assert_eq!(iterator.coordinate(), coord!(2, 0));
+        assert_eq!(iterator.len(), 4);
+        assert_eq!(iterator.next(), Some(&3));
+        assert_eq!(iterator.next(), Some(&4));
+        assert_eq!(iterator.next_back(), Some(&6));
+        assert_eq!(iterator.next(), Some(&5));
+        assert_eq!(iterator.next(), None);
+
+        let mut iterator = IteratorGrid::starting_at(&grid, coord!(2, 1));
+        assert_eq!(iterator.next(), Some(&6));
+        assert_eq!(iterator.next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn iterator_grid_starting_at_out_of_bounds() {
+        let grid = Grid::with_size(size!(3, 2), 0);
+        IteratorGrid::starting_at(&grid, coord!(0, 2));
+    }
+
+    #[test]
+    fn iterator_grid_nth() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let mut iterator = IteratorGrid::new(&grid);
+        assert_eq!(iterator.nth(1), Some(&2));
+        assert_eq!(iterator.nth(1), Some(&4));
+        assert_eq!(iterator.coordinate(), coord!(1, 1));
+        assert_eq!(iterator.len(), 2);
+        assert_eq!(iterator.nth(2), None);
+        assert_eq!(iterator.coordinate(), coord!(0, 2));
+        assert_eq!(iterator.next(), None);
+
+        // Skipping never goes past the back of the iterator.
+        let mut iterator = IteratorGrid::new(&grid);
+        iterator.next_back();
+        assert_eq!(iterator.nth(4), Some(&5));
+        assert_eq!(iterator.nth(1), None);
+
+        let mut iterator = IteratorGrid::new(&grid);
+        assert_eq!(iterator.nth(usize::MAX), None);
+        assert_eq!(iterator.len(), 0);
+
+        let mut iterator = IteratorGrid::new(&grid).skip(4);
+        assert_eq!(iterator.next(), Some(&5));
+
+        let grid = Grid::<()>::zero();
+        assert_eq!(IteratorGrid::new(&grid).nth(3), None);
+    }
+
+    #[test]
+    fn iterator_grid_rev() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let values: Vec<_> = IteratorGrid::new(&grid).rev().collect();
+        assert_eq!(values, vec![&6, &5, &4, &3, &2, &1]);
+
+        // Both ends meet in the middle.
+        let mut iterator = IteratorGrid::new(&grid);
+        assert_eq!(iterator.next(), Some(&1));
+        assert_eq!(iterator.next_back(), Some(&6));
+        assert_eq!(iterator.next_back(), Some(&5));
+        assert_eq!(iterator.next(), Some(&2));
+        assert_eq!(iterator.len(), 2);
+        assert_eq!(iterator.next_back(), Some(&4));
+        assert_eq!(iterator.next(), Some(&3));
+        assert_eq!(iterator.next(), None);
+        assert_eq!(iterator.next_back(), None);
+
+        let grid = Grid::<()>::zero();
+        assert_eq!(IteratorGrid::new(&grid).next_back(), None);
+    }
+
+    #[test]
+    fn iterator_grid_size_hint() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let mut iterator = IteratorGrid::new(&grid);
+        assert_eq!(iterator.len(), 6);
+
+        iterator.next();
+        assert_eq!(iterator.size_hint(), (5, Some(5)));
+
+        iterator.nth(2);
+        assert_eq!(iterator.len(), 2);
+
+        iterator.by_ref().for_each(drop);
+        assert_eq!(iterator.len(), 0);
+        assert_eq!(iterator.next(), None);
+
+        let mut grid = Grid::<()>::zero();
+        assert_eq!(IteratorGrid::new(&grid).len(), 0);
+
+        grid.resize(size!(0, 3), ());
+        assert_eq!(IteratorGrid::new(&grid).len(), 0);
+        assert_eq!(IteratorGrid::new(&grid).next(), None);
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::iter::{Iterator, ExactSizeIterator, FusedIterator};
+use crate::coordinate::Coordinate;
+use crate::grid_view::GridView;
+use crate::grid_iterator::GridIterator;
+
+/// An iterator over a grid view
+///
+/// This structure is an iterator over the elements of a grid view. It's
+/// constructed from the view directly. Unlike the other iterators, the
+/// coordinates it reports are relative to the view.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::Grid;
+/// #
+/// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+///                                 vec![4, 5, 6]]);
+///
+/// let mut iterator = grid.slice(1..3, ..).iterator();
+/// assert_eq!(iterator.next(), Some(&2));
+/// assert_eq!(iterator.next(), Some(&3));
+/// assert_eq!(iterator.next(), Some(&5));
+/// assert_eq!(iterator.next(), Some(&6));
+/// assert_eq!(iterator.next(), None);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct IteratorGridView<'a, T> {
+    view: GridView<'a, T>,
+    coordinate: Coordinate
+}
+
+impl<'a, T> IteratorGridView<'a, T> {
+    pub fn new(view: GridView<'a, T>) -> IteratorGridView<'a, T> {
+        // An empty view yields no element; start right at the end.
+        let coordinate = if view.rect.size.is_empty() {
+            Coordinate::new(0, view.rect.size.height)
+        }
+        else {
+            Coordinate::zero()
+        };
+
+        IteratorGridView { view, coordinate }
+    }
+}
+
+impl<'a, T: Clone> Iterator for IteratorGridView<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.coordinate.y == self.view.size().height {
+            None
+        }
+        else {
+            let value = self.view.value(self.coordinate);
+
+            self.coordinate.x += 1;
+            if self.coordinate.x == self.view.size().width {
+                self.coordinate.x = 0;
+                self.coordinate.y += 1;
+            }
+
+            Some(value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.view.size();
+        let remaining = size.area() - (self.coordinate.y * size.width + self.coordinate.x);
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: Clone> ExactSizeIterator for IteratorGridView<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for IteratorGridView<'a, T> {}
+
+impl<'a, T: Clone> GridIterator for IteratorGridView<'a, T> {
+    fn coordinate(&self) -> Coordinate {
+        self.coordinate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+
+    #[test]
+    fn iterator_grid_view() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6],
+                                        vec![7, 8, 9]]);
+
+        let mut iterator = IteratorGridView::new(grid.slice(0..2, 1..3));
+        assert_eq!(iterator.len(), 4);
+
+        assert_eq!(iterator.coordinate(), coord!(0, 0));
+        assert_eq!(iterator.next(), Some(&4));
+        assert_eq!(iterator.coordinate(), coord!(1, 0));
+        assert_eq!(iterator.next(), Some(&5));
+        assert_eq!(iterator.coordinate(), coord!(0, 1));
+        assert_eq!(iterator.next(), Some(&7));
+        assert_eq!(iterator.coordinate(), coord!(1, 1));
+        assert_eq!(iterator.next(), Some(&8));
+        assert_eq!(iterator.next(), None);
+
+        let mut iterator = IteratorGridView::new(grid.slice(1..1, ..));
+        assert_eq!(iterator.next(), None);
+    }
+}
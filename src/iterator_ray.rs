@@ -0,0 +1,158 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::iter::{Iterator, FusedIterator};
+use crate::coordinate::Coordinate;
+use crate::offset::Offset;
+use crate::grid::Grid;
+
+/// An iterator over the elements of a grid in a direction
+///
+/// This structure is an iterator that starts from a coordinate and steps
+/// repeatedly by an offset, yielding the coordinates and the elements it
+/// reaches until it leaves the grid. The starting coordinate itself isn't
+/// yielded. It's created by the `ray()` method of the grid.
+///
+/// Use `take_while()` to stop the ray earlier, on an obstacle for instance.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Offset, Grid, coord, offset};
+/// #
+/// let grid = Grid::from_rows(vec![vec!['.', '.', '.', '#'],
+///                                 vec!['.', '.', '.', '.']]);
+///
+/// // The squares a rook on the left could move to on the first row.
+/// let squares = grid.ray(coord!(0, 0), offset!(1, 0))
+///     .take_while(|&(_, &value)| value != '#')
+///     .map(|(coordinate, _)| coordinate)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(squares, vec![coord!(1, 0), coord!(2, 0)]);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct IteratorRay<'a, T> {
+    grid: &'a Grid<T>,
+    coordinate: Option<Coordinate>,
+    step: Offset
+}
+
+impl<'a, T: Clone> IteratorRay<'a, T> {
+    pub fn new(grid: &'a Grid<T>, origin: Coordinate, step: Offset) -> IteratorRay<'a, T> {
+        assert!(grid.size().contains(origin), "index out of bounds");
+        assert!(step != Offset::zero(), "step is zero");
+
+        IteratorRay {
+            grid,
+            coordinate: Some(origin),
+            step
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for IteratorRay<'a, T> {
+    type Item = (Coordinate, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let coordinate = self.coordinate?.checked_apply(self.step, self.grid.size());
+        self.coordinate = coordinate;
+
+        coordinate.map(|coordinate| (coordinate, &self.grid[coordinate]))
+    }
+}
+
+impl<'a, T: Clone> FusedIterator for IteratorRay<'a, T> {}
+
+impl<T: Clone> Grid<T> {
+
+    /// Return an iterator over the elements in a direction.
+    ///
+    /// This method returns an iterator that starts from a coordinate and steps
+    /// repeatedly by an offset until it leaves the grid, yielding the
+    /// coordinates and the elements along the way. It covers the movement of
+    /// sliding pieces and simple ray casting.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - The coordinate to start from, which isn't yielded
+    /// * `step` - The offset between two successive elements
+    ///
+    /// # Panics
+    ///
+    /// It panics if the origin is out of bounds, or if the step is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Offset, Grid, coord, offset};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6],
+    ///                                 vec![7, 8, 9]]);
+    ///
+    /// let mut ray = grid.ray(coord!(0, 0), offset!(1, 1));
+    /// assert_eq!(ray.next(), Some((coord!(1, 1), &5)));
+    /// assert_eq!(ray.next(), Some((coord!(2, 2), &9)));
+    /// assert_eq!(ray.next(), None);
+    /// ```
+    ///
+    pub fn ray(&self, origin: Coordinate, step: Offset) -> IteratorRay<'_, T> {
+        IteratorRay::new(self, origin, step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use crate::size::Size;
+
+    #[test]
+    fn iterator_ray() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3, 4],
+                                        vec![5, 6, 7, 8],
+                                        vec![9, 10, 11, 12]]);
+
+        let values = grid.ray(coord!(3, 2), offset!(-1, 0)).map(|(_, &value)| value).collect::<Vec<_>>();
+        assert_eq!(values, vec![11, 10, 9]);
+
+        let values = grid.ray(coord!(1, 2), offset!(1, -1)).collect::<Vec<_>>();
+        assert_eq!(values, vec![(coord!(2, 1), &7), (coord!(3, 0), &4)]);
+
+        let values = grid.ray(coord!(0, 0), offset!(2, 0)).collect::<Vec<_>>();
+        assert_eq!(values, vec![(coord!(2, 0), &3)]);
+
+        let mut ray = grid.ray(coord!(0, 0), offset!(0, -1));
+        assert_eq!(ray.next(), None);
+        assert_eq!(ray.next(), None);
+    }
+
+    #[test]
+    fn iterator_ray_take_while() {
+        let grid = Grid::from_rows(vec![vec![0, 0, 0, 1, 0]]);
+
+        assert_eq!(grid.ray(coord!(0, 0), offset!(1, 0)).take_while(|&(_, &value)| value == 0).count(), 2);
+        assert_eq!(grid.ray(coord!(4, 0), offset!(-1, 0)).take_while(|&(_, &value)| value == 0).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "step is zero")]
+    fn iterator_ray_zero_step() {
+        let grid = Grid::with_size(size!(2, 2), 0);
+        grid.ray(coord!(0, 0), offset!(0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn iterator_ray_out_of_bounds() {
+        let grid = Grid::with_size(size!(2, 2), 0);
+        grid.ray(coord!(2, 2), offset!(1, 0));
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::iter::{Iterator, ExactSizeIterator, FusedIterator};
+use crate::coordinate::Coordinate;
+use crate::rect::Rect;
+
+/// An iterator over the coordinates of a rectangle
+///
+/// This structure is an iterator over the coordinates of a rectangle, from
+/// left to right and top to bottom. It's constructed from the rectangle
+/// directly.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Rect, coord, rect};
+/// #
+/// let mut iterator = rect!(0, 0, 2, 1).coordinates();
+/// assert_eq!(iterator.next(), Some(coord!(0, 0)));
+/// assert_eq!(iterator.next(), Some(coord!(1, 0)));
+/// assert_eq!(iterator.next(), None);
+/// ```
+///
+#[derive(Debug, Clone)]
+pub struct IteratorRect {
+    rect: Rect,
+    coordinate: Coordinate
+}
+
+impl IteratorRect {
+    pub fn new(rect: Rect) -> IteratorRect {
+        // An empty rectangle yields no coordinate; start right at the end.
+        let coordinate = if rect.size.is_empty() {
+            Coordinate::new(rect.coordinate.x, rect.coordinate.y + rect.size.height)
+        }
+        else {
+            rect.coordinate
+        };
+
+        IteratorRect { rect, coordinate }
+    }
+}
+
+impl Iterator for IteratorRect {
+    type Item = Coordinate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.coordinate.y == self.rect.coordinate.y + self.rect.size.height {
+            None
+        }
+        else {
+            let coordinate = self.coordinate;
+
+            self.coordinate.x += 1;
+            if self.coordinate.x == self.rect.coordinate.x + self.rect.size.width {
+                self.coordinate.x = self.rect.coordinate.x;
+                self.coordinate.y += 1;
+            }
+
+            Some(coordinate)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let x = self.coordinate.x - self.rect.coordinate.x;
+        let y = self.coordinate.y - self.rect.coordinate.y;
+        let remaining = self.rect.size.area() - (y * self.rect.size.width + x);
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for IteratorRect {}
+
+impl FusedIterator for IteratorRect {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn iterator_rect() {
+        let mut iterator = IteratorRect::new(rect!(1, 2, 3, 2));
+        assert_eq!(iterator.len(), 6);
+
+        assert_eq!(iterator.next(), Some(coord!(1, 2)));
+        assert_eq!(iterator.next(), Some(coord!(2, 2)));
+        assert_eq!(iterator.next(), Some(coord!(3, 2)));
+        assert_eq!(iterator.next(), Some(coord!(1, 3)));
+        assert_eq!(iterator.next(), Some(coord!(2, 3)));
+        assert_eq!(iterator.next(), Some(coord!(3, 3)));
+        assert_eq!(iterator.next(), None);
+
+        let mut iterator = IteratorRect::new(rect!(1, 2, 0, 2));
+        assert_eq!(iterator.len(), 0);
+        assert_eq!(iterator.next(), None);
+
+        let mut iterator = IteratorRect::new(rect!(1, 2, 2, 0));
+        assert_eq!(iterator.next(), None);
+    }
+}
@@ -6,11 +6,10 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
-use std::iter::Iterator;
+use core::iter::{Iterator, DoubleEndedIterator, ExactSizeIterator, FusedIterator};
 use crate::coordinate::Coordinate;
 use crate::row::Row;
 use crate::grid_iterator::GridIterator;
-use crate::coord;
 
 /// An iterator over a row
 ///
@@ -31,14 +30,23 @@ use crate::coord;
 /// assert_eq!(iterator.next(), None);
 /// ```
 ///
+#[derive(Debug, Clone)]
 pub struct IteratorRow<'a, T> {
     row: Row<'a, T>,
-    index: usize
+    index: usize,
+    end: usize
 }
 
-impl<'a, T> IteratorRow<'a, T> {
+impl<'a, T: Clone> IteratorRow<'a, T> {
     pub fn new(row: Row<'a, T>) -> IteratorRow<'a, T> {
-        IteratorRow { row, index: 0 }
+        let end = row.length();
+        IteratorRow { row, index: 0, end }
+    }
+
+    // Create an iterator over a part of the row only; segments rely on this.
+    pub(crate) fn with_range(row: Row<'a, T>, start: usize, end: usize) -> IteratorRow<'a, T> {
+        debug_assert!(start <= end && end <= row.length());
+        IteratorRow { row, index: start, end }
     }
 }
 
@@ -46,7 +54,7 @@ impl<'a, T: Clone> Iterator for IteratorRow<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index == self.row.length() {
+        if self.index == self.end {
             None
         }
         else {
@@ -55,8 +63,29 @@ impl<'a, T: Clone> Iterator for IteratorRow<'a, T> {
             Some(value)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: Clone> DoubleEndedIterator for IteratorRow<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        }
+        else {
+            self.end -= 1;
+            Some(self.row.value(self.end))
+        }
+    }
 }
 
+impl<'a, T: Clone> ExactSizeIterator for IteratorRow<'a, T> {}
+
+impl<'a, T: Clone> FusedIterator for IteratorRow<'a, T> {}
+
 impl<'a, T: Clone> GridIterator for IteratorRow<'a, T> {
     fn coordinate(&self) -> Coordinate {
         coord!(self.index, self.row.index)
@@ -66,6 +95,7 @@ impl<'a, T: Clone> GridIterator for IteratorRow<'a, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
     use crate::grid::Grid;
 
     #[test]
@@ -95,4 +125,52 @@ mod tests {
         assert_eq!(iterator.coordinate(), coord!(3, 1));
         assert_eq!(iterator.next(), None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn iterator_row_clone() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let mut iterator = IteratorRow::new(grid.row(1));
+        iterator.next();
+
+        let mut fork = iterator.clone();
+        assert_eq!(fork.next(), Some(&5));
+        assert_eq!(fork.next(), Some(&6));
+        assert_eq!(iterator.next(), Some(&5));
+
+        assert!(format!("{:?}", iterator).starts_with("IteratorRow"));
+    }
+
+    #[test]
+    fn iterator_row_rev() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let values: Vec<_> = IteratorRow::new(grid.row(1)).rev().collect();
+        assert_eq!(values, vec![&6, &5, &4]);
+
+        let mut iterator = IteratorRow::new(grid.row(0));
+        assert_eq!(iterator.next_back(), Some(&3));
+        assert_eq!(iterator.next(), Some(&1));
+        assert_eq!(iterator.next_back(), Some(&2));
+        assert_eq!(iterator.next(), None);
+        assert_eq!(iterator.next_back(), None);
+    }
+
+    #[test]
+    fn iterator_row_size_hint() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let mut iterator = IteratorRow::new(grid.row(1));
+        assert_eq!(iterator.len(), 3);
+
+        iterator.next();
+        assert_eq!(iterator.size_hint(), (2, Some(2)));
+
+        iterator.by_ref().for_each(drop);
+        assert_eq!(iterator.len(), 0);
+        assert_eq!(iterator.next(), None);
+    }
+}
@@ -0,0 +1,626 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::iter::FusedIterator;
+use core::slice;
+use alloc::string::String;
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::grid::Grid;
+
+/// A stack of named grid layers
+///
+/// This structure manages several grids of the same size, named layers, which
+/// are stacked from bottom to top. Each layer can be accessed by index or by
+/// name, and can be hidden. It matches how the tilemaps of 2D games are
+/// structured, with ground, objects and overlay layers for instance.
+///
+/// The elements of the visible layers at a coordinate form a stack, which can
+/// be iterated with `stack()` and flattened into a single grid with
+/// `composite()`.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Grid, LayeredGrid, coord, size};
+/// #
+/// let mut map = LayeredGrid::new(size!(3, 2));
+///
+/// map.push_layer("ground", Grid::with_size(size!(3, 2), Some('.')));
+/// map.push_layer("objects", Grid::with_size(size!(3, 2), None));
+/// map.layer_by_name_mut("objects").unwrap()[coord!(1, 0)] = Some('@');
+///
+/// // The topmost tile of each position.
+/// let tiles = map.composite(|stack| stack.rev().flatten().next().copied());
+/// assert_eq!(tiles.row(0).values(), vec![&Some('.'), &Some('@'), &Some('.')]);
+/// ```
+///
+#[derive(Debug, Eq, PartialEq)]
+pub struct LayeredGrid<T> {
+    size: Size,
+    layers: Vec<Layer<T>>
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Layer<T> {
+    name: String,
+    grid: Grid<T>,
+    visible: bool
+}
+
+impl<T: Clone> LayeredGrid<T> {
+
+    /// Create a layered grid without layers.
+    ///
+    /// This function creates a layered grid whose layers all have a given
+    /// size, without any layer yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the layers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, LayeredGrid, size};
+    /// #
+    /// let map = LayeredGrid::<u8>::new(size!(16, 16));
+    /// assert_eq!(map.size(), size!(16, 16));
+    /// assert!(map.is_empty());
+    /// ```
+    ///
+    pub fn new(size: Size) -> LayeredGrid<T> {
+        LayeredGrid {
+            size,
+            layers: Vec::new()
+        }
+    }
+
+    /// Return the size of the layers.
+    ///
+    /// This method returns the size shared by all layers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, LayeredGrid, size};
+    /// #
+    /// let map = LayeredGrid::<u8>::new(size!(4, 3));
+    /// assert_eq!(map.size(), size!(4, 3));
+    /// ```
+    ///
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Return the number of layers.
+    ///
+    /// This method returns the number of layers, visible or not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, LayeredGrid, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    /// map.push_layer("ground", Grid::with_size(size!(2, 2), 0));
+    ///
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    ///
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Check whether there are no layers.
+    ///
+    /// This method returns true if the layered grid has no layer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, LayeredGrid, size};
+    /// #
+    /// let map = LayeredGrid::<u8>::new(size!(2, 2));
+    /// assert!(map.is_empty());
+    /// ```
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Add a layer on top of the others.
+    ///
+    /// This method adds a visible layer on top of the stack and returns its
+    /// index. Names don't need to be unique, but only the lowest layer with a
+    /// given name can be accessed by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the layer
+    /// * `grid` - The elements of the layer
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grid doesn't have the size of the layers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, LayeredGrid, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    ///
+    /// assert_eq!(map.push_layer("ground", Grid::with_size(size!(2, 2), 0)), 0);
+    /// assert_eq!(map.push_layer("objects", Grid::with_size(size!(2, 2), 0)), 1);
+    /// ```
+    ///
+    pub fn push_layer(&mut self, name: &str, grid: Grid<T>) -> usize {
+        assert_eq!(grid.size(), self.size, "layer size doesn't match");
+
+        self.layers.push(Layer {
+            name: String::from(name),
+            grid,
+            visible: true
+        });
+
+        self.layers.len() - 1
+    }
+
+    /// Remove a layer.
+    ///
+    /// This method removes a layer from the stack and returns its elements;
+    /// the layers above it move down by one index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the layer
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, LayeredGrid, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    /// map.push_layer("ground", Grid::with_size(size!(2, 2), 0));
+    /// map.push_layer("objects", Grid::with_size(size!(2, 2), 1));
+    ///
+    /// assert_eq!(map.remove_layer(0), Grid::with_size(size!(2, 2), 0));
+    /// assert_eq!(map.index_of("objects"), Some(0));
+    /// ```
+    ///
+    pub fn remove_layer(&mut self, index: usize) -> Grid<T> {
+        assert!(index < self.layers.len(), "index out of bounds");
+        self.layers.remove(index).grid
+    }
+
+    /// Return a layer.
+    ///
+    /// This method returns the elements of the layer at a given index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the layer
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, LayeredGrid, coord, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    /// map.push_layer("ground", Grid::with_size(size!(2, 2), 7));
+    ///
+    /// assert_eq!(map.layer(0)[coord!(1, 1)], 7);
+    /// ```
+    ///
+    pub fn layer(&self, index: usize) -> &Grid<T> {
+        assert!(index < self.layers.len(), "index out of bounds");
+        &self.layers[index].grid
+    }
+
+    /// Return a layer mutably.
+    ///
+    /// This method returns the elements of the layer at a given index,
+    /// mutably.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the layer
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, LayeredGrid, coord, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    /// map.push_layer("ground", Grid::with_size(size!(2, 2), 0));
+    ///
+    /// map.layer_mut(0)[coord!(1, 1)] = 7;
+    /// assert_eq!(map.layer(0)[coord!(1, 1)], 7);
+    /// ```
+    ///
+    pub fn layer_mut(&mut self, index: usize) -> &mut Grid<T> {
+        assert!(index < self.layers.len(), "index out of bounds");
+        &mut self.layers[index].grid
+    }
+
+    /// Return the index of a layer.
+    ///
+    /// This method returns the index of the lowest layer with a given name,
+    /// or `None` if there's no such layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the layer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, LayeredGrid, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    /// map.push_layer("ground", Grid::with_size(size!(2, 2), 0));
+    /// map.push_layer("objects", Grid::with_size(size!(2, 2), 0));
+    ///
+    /// assert_eq!(map.index_of("objects"), Some(1));
+    /// assert_eq!(map.index_of("overlay"), None);
+    /// ```
+    ///
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.layers.iter().position(|layer| layer.name == name)
+    }
+
+    /// Return the name of a layer.
+    ///
+    /// This method returns the name of the layer at a given index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the layer
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, LayeredGrid, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    /// map.push_layer("ground", Grid::with_size(size!(2, 2), 0));
+    ///
+    /// assert_eq!(map.name(0), "ground");
+    /// ```
+    ///
+    pub fn name(&self, index: usize) -> &str {
+        assert!(index < self.layers.len(), "index out of bounds");
+        &self.layers[index].name
+    }
+
+    /// Return a layer by name.
+    ///
+    /// This method returns the elements of the lowest layer with a given
+    /// name, or `None` if there's no such layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the layer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, LayeredGrid, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    /// map.push_layer("ground", Grid::with_size(size!(2, 2), 0));
+    ///
+    /// assert!(map.layer_by_name("ground").is_some());
+    /// assert!(map.layer_by_name("overlay").is_none());
+    /// ```
+    ///
+    pub fn layer_by_name(&self, name: &str) -> Option<&Grid<T>> {
+        self.index_of(name).map(|index| &self.layers[index].grid)
+    }
+
+    /// Return a layer by name mutably.
+    ///
+    /// This method returns the elements of the lowest layer with a given
+    /// name mutably, or `None` if there's no such layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the layer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, LayeredGrid, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    /// map.push_layer("ground", Grid::with_size(size!(2, 2), 0));
+    ///
+    /// map.layer_by_name_mut("ground").unwrap().fill(1);
+    /// assert_eq!(map.layer(0), &Grid::with_size(size!(2, 2), 1));
+    /// ```
+    ///
+    pub fn layer_by_name_mut(&mut self, name: &str) -> Option<&mut Grid<T>> {
+        let index = self.index_of(name)?;
+        Some(&mut self.layers[index].grid)
+    }
+
+    /// Check whether a layer is visible.
+    ///
+    /// This method returns whether the layer at a given index is visible.
+    /// Layers are visible when they're added.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the layer
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, LayeredGrid, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    /// map.push_layer("ground", Grid::with_size(size!(2, 2), 0));
+    ///
+    /// assert!(map.is_visible(0));
+    /// ```
+    ///
+    pub fn is_visible(&self, index: usize) -> bool {
+        assert!(index < self.layers.len(), "index out of bounds");
+        self.layers[index].visible
+    }
+
+    /// Show or hide a layer.
+    ///
+    /// This method changes the visibility of the layer at a given index.
+    /// Hidden layers keep their elements, but they're left out of the stacks.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the layer
+    /// * `visible` - Whether the layer is visible
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, LayeredGrid, coord, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    /// map.push_layer("ground", Grid::with_size(size!(2, 2), 0));
+    /// map.push_layer("overlay", Grid::with_size(size!(2, 2), 1));
+    ///
+    /// map.set_visible(1, false);
+    /// assert_eq!(map.stack(coord!(0, 0)).collect::<Vec<_>>(), vec![&0]);
+    /// ```
+    ///
+    pub fn set_visible(&mut self, index: usize, visible: bool) {
+        assert!(index < self.layers.len(), "index out of bounds");
+        self.layers[index].visible = visible;
+    }
+
+    /// Return an iterator over the stack of elements at a coordinate.
+    ///
+    /// This method returns an iterator over the elements of the visible layers
+    /// at a given coordinate, from the bottom layer to the top layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - The coordinate of the stack
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, LayeredGrid, coord, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 2));
+    /// map.push_layer("ground", Grid::with_size(size!(2, 2), 'g'));
+    /// map.push_layer("objects", Grid::with_size(size!(2, 2), 'o'));
+    ///
+    /// let stack = map.stack(coord!(1, 0)).collect::<Vec<_>>();
+    /// assert_eq!(stack, vec![&'g', &'o']);
+    /// ```
+    ///
+    pub fn stack(&self, coordinate: Coordinate) -> IteratorStack<'_, T> {
+        assert!(self.size.contains(coordinate), "index out of bounds");
+
+        IteratorStack {
+            layers: self.layers.iter(),
+            coordinate
+        }
+    }
+
+    /// Flatten the visible layers into a grid.
+    ///
+    /// This method returns a grid whose elements are computed by a function
+    /// from the stack of elements of the visible layers at each coordinate,
+    /// from bottom to top; to pick the topmost non-empty tile for instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function computing an element from a stack
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, LayeredGrid, coord, size};
+    /// #
+    /// let mut map = LayeredGrid::new(size!(2, 1));
+    /// map.push_layer("height", Grid::from_rows(vec![vec![1, 2]]));
+    /// map.push_layer("buildings", Grid::from_rows(vec![vec![0, 5]]));
+    ///
+    /// let heights = map.composite(|stack| stack.sum::<i32>());
+    /// assert_eq!(heights.row(0).values(), vec![&1, &7]);
+    /// ```
+    ///
+    pub fn composite<U, F>(&self, mut function: F) -> Grid<U>
+        where U: Clone, F: FnMut(IteratorStack<'_, T>) -> U
+    {
+        let rows = (0..self.size.height).map(|y| {
+            (0..self.size.width).map(|x| function(self.stack(Coordinate::new(x, y)))).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(self.size.width, rows)
+    }
+}
+
+/// An iterator over the stack of elements of a layered grid
+///
+/// This structure is an iterator over the elements of the visible layers of a
+/// layered grid at a coordinate, from the bottom layer to the top layer. It's
+/// created by the `stack()` method of the layered grid.
+///
+#[derive(Debug, Clone)]
+pub struct IteratorStack<'a, T> {
+    layers: slice::Iter<'a, Layer<T>>,
+    coordinate: Coordinate
+}
+
+impl<'a, T> Iterator for IteratorStack<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let coordinate = self.coordinate;
+        self.layers.find(|layer| layer.visible).map(|layer| &layer.grid[coordinate])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.layers.len()))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IteratorStack<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let coordinate = self.coordinate;
+        self.layers.rfind(|layer| layer.visible).map(|layer| &layer.grid[coordinate])
+    }
+}
+
+impl<'a, T> FusedIterator for IteratorStack<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layered_grid_new() {
+        let map = LayeredGrid::<u8>::new(size!(3, 2));
+
+        assert_eq!(map.size(), size!(3, 2));
+        assert_eq!(map.len(), 0);
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn layered_grid_layers() {
+        let mut map = LayeredGrid::new(size!(2, 2));
+
+        assert_eq!(map.push_layer("ground", Grid::with_size(size!(2, 2), 1)), 0);
+        assert_eq!(map.push_layer("objects", Grid::with_size(size!(2, 2), 2)), 1);
+        assert_eq!(map.push_layer("overlay", Grid::with_size(size!(2, 2), 3)), 2);
+        assert_eq!(map.len(), 3);
+
+        assert_eq!(map.name(1), "objects");
+        assert_eq!(map.index_of("overlay"), Some(2));
+        assert_eq!(map.layer(2)[coord!(0, 0)], 3);
+        assert_eq!(map.layer_by_name("ground").unwrap()[coord!(0, 0)], 1);
+        assert!(map.layer_by_name("missing").is_none());
+
+        map.layer_mut(0)[coord!(1, 1)] = 10;
+        map.layer_by_name_mut("objects").unwrap()[coord!(1, 1)] = 20;
+        assert_eq!(map.layer(0)[coord!(1, 1)], 10);
+        assert_eq!(map.layer(1)[coord!(1, 1)], 20);
+
+        assert_eq!(map.remove_layer(1)[coord!(1, 1)], 20);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.name(1), "overlay");
+        assert_eq!(map.index_of("objects"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "layer size doesn't match")]
+    fn layered_grid_push_layer_invalid_size() {
+        let mut map = LayeredGrid::new(size!(2, 2));
+        map.push_layer("ground", Grid::with_size(size!(2, 3), 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn layered_grid_layer_out_of_bounds() {
+        let map = LayeredGrid::<u8>::new(size!(2, 2));
+        map.layer(0);
+    }
+
+    #[test]
+    fn layered_grid_stack() {
+        let mut map = LayeredGrid::new(size!(2, 1));
+        map.push_layer("a", Grid::from_rows(vec![vec![1, 2]]));
+        map.push_layer("b", Grid::from_rows(vec![vec![3, 4]]));
+        map.push_layer("c", Grid::from_rows(vec![vec![5, 6]]));
+
+        assert_eq!(map.stack(coord!(1, 0)).collect::<Vec<_>>(), vec![&2, &4, &6]);
+        assert_eq!(map.stack(coord!(0, 0)).rev().collect::<Vec<_>>(), vec![&5, &3, &1]);
+
+        map.set_visible(1, false);
+        assert!(!map.is_visible(1));
+        assert_eq!(map.stack(coord!(0, 0)).collect::<Vec<_>>(), vec![&1, &5]);
+        assert_eq!(map.stack(coord!(0, 0)).rev().collect::<Vec<_>>(), vec![&5, &1]);
+
+        map.set_visible(0, false);
+        map.set_visible(2, false);
+        assert_eq!(map.stack(coord!(0, 0)).next(), None);
+    }
+
+    #[test]
+    fn layered_grid_composite() {
+        let mut map = LayeredGrid::new(size!(3, 1));
+        map.push_layer("ground", Grid::from_rows(vec![vec![Some('.'), Some('.'), Some('.')]]));
+        map.push_layer("objects", Grid::from_rows(vec![vec![None, Some('@'), None]]));
+        map.push_layer("overlay", Grid::from_rows(vec![vec![None, None, Some('*')]]));
+
+        let topmost = |stack: IteratorStack<'_, Option<char>>| stack.rev().flatten().next().copied();
+
+        let tiles = map.composite(topmost);
+        assert_eq!(tiles.row(0).values(), vec![&Some('.'), &Some('@'), &Some('*')]);
+
+        map.set_visible(2, false);
+        let tiles = map.composite(topmost);
+        assert_eq!(tiles.row(0).values(), vec![&Some('.'), &Some('@'), &Some('.')]);
+
+        let map = LayeredGrid::<u8>::new(size!(2, 2));
+        assert!(map.composite(|mut stack| stack.next().is_none()).all(|_, &empty| empty));
+    }
+}
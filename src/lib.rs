@@ -110,6 +110,17 @@
 //! let offset = offset!(-1, 1);
 //! ```
 //!
+//! Finally, regions of grids are denoted with the `Rect` structure which is
+//! made of the coordinate of its top-left corner and its size.
+//!
+//! ```
+//! # use ingrid::{Coordinate, Size, Rect, coord, rect};
+//! #
+//! let rect = Rect::new(coord!(1, 1), Size::new(2, 2));
+//! // ... or ...
+//! let rect = rect!(1, 1, 2, 2);
+//! ```
+//!
 //! ## Grid and its elements
 //!
 //! Grids are made of **rows**, **columns** and **cells**, which are widespread
@@ -224,6 +235,21 @@
 //! As shown per the example, you need a mutable version of the row or column if
 //! you want to make changes to it.
 //!
+//! Similarly, rectangular regions of a grid can be looked at with views, which
+//! are created from a rectangle or from ranges of columns and rows.
+//!
+//! ```
+//! # use ingrid::{Coordinate, Grid, coord};
+//! #
+//! let grid = Grid::from_rows(vec![vec![1, 2, 3],
+//!                                 vec![4, 5, 6],
+//!                                 vec![7, 8, 9]]);
+//!
+//! let view = grid.slice(1..3, 0..2);
+//! assert_eq!(view[coord!(0, 0)], 2);
+//! assert_eq!(view.values(), vec![&2, &3, &5, &6]);
+//! ```
+//!
 //! ## Set of iterators
 //!
 //! You will legitimately be iterating grids in all sort of ways and iterators
@@ -284,18 +310,85 @@
 //!
 //! This part of the crate isn't implemented yet.
 //!
+//! ## Using without the standard library
+//!
+//! This crate only needs an allocator. It's `#![no_std]` and depends on the
+//! `alloc` crate; the standard library is pulled in by the `std` feature,
+//! which is enabled by default and gates the pieces that need it. Disable the
+//! default features to use it in embedded and wasm environments.
+//!
+//! ```toml
+//! [dependencies]
+//! ingrid = { version = "0.1", default-features = false }
+//! ```
+//!
+//! Optional features add functionalities that not everyone needs; the
+//! `heatmap` feature enables the `Heatmap` renderer to print numeric grids in
+//! the terminal, and the `npy` feature enables reading and writing grids in
+//! the `.npy` format of NumPy.
+//!
+#![no_std]
+
+#[cfg_attr(test, macro_use)]
+extern crate alloc;
+
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
 #[macro_use]
 mod coordinate;
 #[macro_use]
 mod size;
 #[macro_use]
 mod offset;
+#[macro_use]
+mod rect;
+mod projection;
 
 mod grid;
+mod grid_view;
+mod grid_view_mut;
+mod grid_cursor;
+mod path;
+mod grid_option;
+mod grid_builder;
+mod sampling;
+mod scaling;
+mod trimming;
+mod symmetry;
+mod word_search;
+mod nearest;
+mod influence;
+mod predicate;
+mod aggregation;
+mod pattern;
+mod matrix;
+mod drawing;
+mod blit;
+mod blending;
+mod elementwise;
+mod numeric;
+mod border;
+mod convolution;
+mod derivative;
+mod relaxation;
+mod histogram;
+mod stencil;
+mod morphology;
+mod snapshot_grid;
+mod shared_grid;
+mod atomic_grid;
+mod sparse_grid;
+mod layered_grid;
+mod indexed_grid;
 mod row;
+mod row_segment;
 mod row_mut;
+mod row_segment_mut;
 mod column;
+mod column_segment;
 mod column_mut;
+mod column_segment_mut;
 mod cell;
 
 mod grid_iterator;
@@ -303,20 +396,70 @@ mod iterator_grid;
 mod iterator_row;
 mod iterator_column;
 mod enumerate_coordinate;
+mod iterator_rect;
+mod iterator_grid_view;
+mod iterator_ray;
+mod iterator_edges;
+
+#[cfg(feature = "std")]
+mod binary;
+#[cfg(feature = "std")]
+mod streaming;
+#[cfg(feature = "heatmap")]
+mod heatmap;
+#[cfg(feature = "std")]
+mod sync_grid;
+#[cfg(feature = "std")]
+mod filter;
+#[cfg(feature = "npy")]
+mod npy;
 
 pub use coordinate::Coordinate;
 pub use size::Size;
 pub use offset::Offset;
+pub use rect::Rect;
 
 pub use grid::Grid;
+pub use grid_view::GridView;
+pub use grid_view_mut::GridViewMut;
+pub use grid_cursor::GridCursor;
+pub use path::Path;
+pub use grid_builder::{GridBuilder, RowWidthError};
+pub use scaling::Scaling;
+pub use sampling::Interpolation;
+pub use numeric::Numeric;
+pub use derivative::Neighborhood;
+pub use border::BorderPolicy;
+pub use nearest::Metric;
+pub use aggregation::MinMax;
+pub use snapshot_grid::SnapshotGrid;
+pub use shared_grid::SharedGrid;
+pub use atomic_grid::AtomicGrid;
+pub use sparse_grid::{SparseGrid, SparseEntry};
+pub use layered_grid::{LayeredGrid, IteratorStack};
+pub use indexed_grid::IndexedGrid;
 pub use row::Row;
+pub use row_segment::RowSegment;
 pub use row_mut::RowMut;
+pub use row_segment_mut::RowSegmentMut;
 pub use column::Column;
+pub use column_segment::ColumnSegment;
 pub use column_mut::ColumnMut;
+pub use column_segment_mut::ColumnSegmentMut;
 pub use cell::Cell;
 
 pub use grid_iterator::GridIterator;
 pub use iterator_grid::IteratorGrid;
 pub use iterator_row::IteratorRow;
 pub use iterator_column::IteratorColumn;
-pub use enumerate_coordinate::EnumerateCoordinate;
\ No newline at end of file
+pub use enumerate_coordinate::EnumerateCoordinate;
+pub use iterator_rect::IteratorRect;
+pub use iterator_grid_view::IteratorGridView;
+pub use iterator_ray::IteratorRay;
+pub use iterator_edges::IteratorEdges;
+#[cfg(feature = "std")]
+pub use binary::BinaryElement;
+#[cfg(feature = "heatmap")]
+pub use heatmap::Heatmap;
+#[cfg(feature = "std")]
+pub use sync_grid::SyncGrid;
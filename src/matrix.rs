@@ -0,0 +1,98 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+
+impl<T: Numeric> Grid<T> {
+
+    /// Construct an identity matrix.
+    ///
+    /// This function constructs a square grid of a given size whose elements
+    /// on the main diagonal are one, and whose other elements are zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The number of rows and columns
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::<u8>::identity(3);
+    /// assert_eq!(grid.row(0).values(), vec![&1, &0, &0]);
+    /// assert_eq!(grid.row(1).values(), vec![&0, &1, &0]);
+    /// assert_eq!(grid.row(2).values(), vec![&0, &0, &1]);
+    /// ```
+    ///
+    pub fn identity(size: usize) -> Grid<T> {
+        Grid::from_diagonal(&vec![T::from_f64(1.0); size])
+    }
+
+    /// Construct a diagonal matrix.
+    ///
+    /// This function constructs a square grid whose elements on the main
+    /// diagonal are given, and whose other elements are zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `diagonal` - The elements of the main diagonal, from top to bottom
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_diagonal(&[1.5, 2.0]);
+    /// assert_eq!(grid.row(0).values(), vec![&1.5, &0.0]);
+    /// assert_eq!(grid.row(1).values(), vec![&0.0, &2.0]);
+    /// ```
+    ///
+    pub fn from_diagonal(diagonal: &[T]) -> Grid<T> {
+        let zero = T::from_f64(0.0);
+
+        let rows = diagonal.iter().enumerate().map(|(index, &value)| {
+            let mut row = vec![zero; diagonal.len()];
+            row[index] = value;
+            row
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(diagonal.len(), rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_identity() {
+        let grid = Grid::<f64>::identity(2);
+        assert_eq!(grid.size(), size!(2, 2));
+        assert_eq!(grid.row(0).values(), vec![&1.0, &0.0]);
+        assert_eq!(grid.row(1).values(), vec![&0.0, &1.0]);
+
+        assert_eq!(Grid::<i32>::identity(1).values(), vec![&1]);
+        assert_eq!(Grid::<i32>::identity(0).size(), size!(0, 0));
+    }
+
+    #[test]
+    fn grid_from_diagonal() {
+        let grid = Grid::from_diagonal(&[1, -2, 3]);
+        assert_eq!(grid.size(), size!(3, 3));
+        assert_eq!(grid.row(0).values(), vec![&1, &0, &0]);
+        assert_eq!(grid.row(1).values(), vec![&0, &-2, &0]);
+        assert_eq!(grid.row(2).values(), vec![&0, &0, &3]);
+
+        assert_eq!(Grid::<u8>::from_diagonal(&[]).size(), size!(0, 0));
+    }
+}
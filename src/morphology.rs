@@ -0,0 +1,321 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use alloc::collections::VecDeque;
+use crate::grid::Grid;
+use crate::border::BorderPolicy;
+
+impl<T: Clone + PartialOrd> Grid<T> {
+
+    /// Return the grid with the minimum of the neighborhood of each element.
+    ///
+    /// This method returns a new grid whose elements are the minimum of the
+    /// square of elements within a given radius around each element of the
+    /// grid, clamped to the borders of the grid, like the windows of
+    /// `stencil()`. It's the erosion of grayscale morphology; it shrinks the
+    /// peaks and widens the valleys.
+    ///
+    /// The minimums are computed along the rows, then along the columns, with
+    /// sliding windows; the cost per element doesn't depend on the radius.
+    /// Use `min_filter_with_border()` to handle the borders differently.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - The radius of the neighborhood
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![5, 5, 5, 5],
+    ///                                 vec![5, 1, 5, 5],
+    ///                                 vec![5, 5, 5, 5]]);
+    ///
+    /// assert_eq!(grid.min_filter(1), Grid::from_rows(vec![vec![1, 1, 1, 5],
+    ///                                                     vec![1, 1, 1, 5],
+    ///                                                     vec![1, 1, 1, 5]]));
+    /// ```
+    ///
+    pub fn min_filter(&self, radius: usize) -> Grid<T> {
+        self.extremum_filter(radius, &BorderPolicy::Skip, |a, b| a <= b)
+    }
+
+    /// Return the grid with the minimum of the neighborhood of each element,
+    /// with a border policy.
+    ///
+    /// This method is similar to `min_filter()`, but the elements of the
+    /// neighborhood outside of the grid are given by a border policy. Note
+    /// that the clamp and skip policies are equivalent, as repeating the
+    /// edges doesn't change the minimums.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - The radius of the neighborhood
+    /// * `border` - The policy for the elements outside of the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, BorderPolicy};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![5, 5, 5, 5, 1]]);
+    ///
+    /// let eroded = grid.min_filter_with_border(1, BorderPolicy::Wrap);
+    /// assert_eq!(eroded.row(0), [1, 5, 5, 1, 1]);
+    ///
+    /// // A high constant doesn't take part in the minimums.
+    /// let eroded = grid.min_filter_with_border(1, BorderPolicy::Constant(9));
+    /// assert_eq!(eroded.row(0), [5, 5, 5, 1, 1]);
+    /// ```
+    ///
+    pub fn min_filter_with_border(&self, radius: usize, border: BorderPolicy<T>) -> Grid<T> {
+        self.extremum_filter(radius, &border, |a, b| a <= b)
+    }
+
+    /// Return the grid with the maximum of the neighborhood of each element.
+    ///
+    /// This method returns a new grid whose elements are the maximum of the
+    /// square of elements within a given radius around each element of the
+    /// grid, clamped to the borders of the grid, like the windows of
+    /// `stencil()`. It's the dilation of grayscale morphology; it widens the
+    /// peaks and shrinks the valleys.
+    ///
+    /// The maximums are computed along the rows, then along the columns, with
+    /// sliding windows; the cost per element doesn't depend on the radius.
+    /// Use `max_filter_with_border()` to handle the borders differently.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - The radius of the neighborhood
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0.0, 0.0, 0.0, 0.0],
+    ///                                 vec![0.0, 0.0, 0.0, 0.0],
+    ///                                 vec![0.0, 0.0, 0.0, 2.5]]);
+    ///
+    /// assert_eq!(grid.max_filter(1), Grid::from_rows(vec![vec![0.0, 0.0, 0.0, 0.0],
+    ///                                                     vec![0.0, 0.0, 2.5, 2.5],
+    ///                                                     vec![0.0, 0.0, 2.5, 2.5]]));
+    /// ```
+    ///
+    pub fn max_filter(&self, radius: usize) -> Grid<T> {
+        self.extremum_filter(radius, &BorderPolicy::Skip, |a, b| a >= b)
+    }
+
+    /// Return the grid with the maximum of the neighborhood of each element,
+    /// with a border policy.
+    ///
+    /// This method is similar to `max_filter()`, but the elements of the
+    /// neighborhood outside of the grid are given by a border policy. Note
+    /// that the clamp and skip policies are equivalent, as repeating the
+    /// edges doesn't change the maximums.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - The radius of the neighborhood
+    /// * `border` - The policy for the elements outside of the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, BorderPolicy};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![9, 1, 2, 3, 4]]);
+    ///
+    /// let dilated = grid.max_filter_with_border(1, BorderPolicy::Mirror);
+    /// assert_eq!(dilated.row(0), [9, 9, 3, 4, 4]);
+    ///
+    /// let dilated = grid.max_filter_with_border(1, BorderPolicy::Wrap);
+    /// assert_eq!(dilated.row(0), [9, 9, 3, 4, 9]);
+    /// ```
+    ///
+    pub fn max_filter_with_border(&self, radius: usize, border: BorderPolicy<T>) -> Grid<T> {
+        self.extremum_filter(radius, &border, |a, b| a >= b)
+    }
+
+    // Compute the extremum of the neighborhood of each element; the filter is
+    // separable, so the rows are filtered first, then the columns.
+    fn extremum_filter<F>(&self, radius: usize, border: &BorderPolicy<T>, precedes: F) -> Grid<T>
+        where F: Fn(&T, &T) -> bool
+    {
+        let (width, height) = (self.size().width, self.size().height);
+
+        let mut rows = Vec::with_capacity(height);
+        for row in 0..height {
+            let mut filtered = Vec::with_capacity(width);
+            extremum_line(self.row_slice(row), radius, border, &precedes, &mut filtered);
+            rows.push(filtered);
+        }
+
+        let mut column = Vec::with_capacity(height);
+        let mut filtered = Vec::with_capacity(height);
+        for x in 0..width {
+            column.clear();
+            column.extend(rows.iter().map(|row| row[x].clone()));
+
+            filtered.clear();
+            extremum_line(&column, radius, border, &precedes, &mut filtered);
+
+            for (row, value) in rows.iter_mut().zip(filtered.drain(..)) {
+                row[x] = value;
+            }
+        }
+
+        Grid::from_row_vectors(width, rows)
+    }
+}
+
+// Compute the extremum of the values within a radius around each value of a
+// line, extended by a border policy; repeating the edges doesn't change the
+// extremums, so the line is only extended with the other policies.
+fn extremum_line<T, F>(line: &[T], radius: usize, border: &BorderPolicy<T>, precedes: F, output: &mut Vec<T>)
+    where T: Clone, F: Fn(&T, &T) -> bool
+{
+    match border {
+        BorderPolicy::Clamp | BorderPolicy::Skip => sliding_extremum(line, radius, precedes, output),
+        _ if line.is_empty() => {},
+        _ => {
+            // Beyond the length of the line, the windows cover the whole
+            // line and the constant value, if any, so larger radii don't
+            // change the extremums.
+            let length = line.len();
+            let radius = radius.min(length);
+
+            let extended = (-(radius as isize)..(length + radius) as isize)
+                .map(|position| border.pick(line, border.locate(position, length)).clone())
+                .collect::<Vec<_>>();
+
+            // The windows of the values of the line are within the extended
+            // line; the values of the extensions are dropped.
+            sliding_extremum(&extended, radius, precedes, output);
+            output.drain(..radius);
+            output.truncate(length);
+        }
+    }
+}
+
+// Compute the extremum of the values within a radius around each value of a
+// line; the deque holds the indices of the candidates, the first one being
+// the extremum of the current window. A value evicts the candidates it
+// precedes since they leave the window before it does.
+fn sliding_extremum<T, F>(line: &[T], radius: usize, precedes: F, output: &mut Vec<T>)
+    where T: Clone, F: Fn(&T, &T) -> bool
+{
+    let mut candidates = VecDeque::new();
+    let mut next = 0;
+
+    for index in 0..line.len() {
+        let right = index.saturating_add(radius).min(line.len() - 1);
+        while next <= right {
+            while candidates.back().is_some_and(|&last| precedes(&line[next], &line[last])) {
+                candidates.pop_back();
+            }
+
+            candidates.push_back(next);
+            next += 1;
+        }
+
+        let left = index.saturating_sub(radius);
+        while candidates.front().is_some_and(|&first| first < left) {
+            candidates.pop_front();
+        }
+
+        output.push(line[candidates[0]].clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_min_filter() {
+        let grid = Grid::from_rows(vec![vec![7, 3, 9, 4, 8],
+                                        vec![2, 6, 5, 1, 9],
+                                        vec![8, 4, 7, 3, 6],
+                                        vec![5, 9, 2, 8, 4]]);
+
+        // It matches the straightforward computation for any radius.
+        for radius in 0..6 {
+            let expected = grid.stencil(radius, |window| {
+                **window.values().iter().min().unwrap()
+            });
+            assert_eq!(grid.min_filter(radius), expected);
+        }
+
+        assert_eq!(grid.min_filter(0), grid);
+        assert_eq!(grid.min_filter(usize::MAX), Grid::with_size(size!(5, 4), 1));
+        assert_eq!(Grid::<u8>::zero().min_filter(1), Grid::zero());
+    }
+
+    #[test]
+    fn grid_max_filter() {
+        let grid = Grid::from_rows(vec![vec![7, 3, 9, 4, 8],
+                                        vec![2, 6, 5, 1, 9],
+                                        vec![8, 4, 7, 3, 6],
+                                        vec![5, 9, 2, 8, 4]]);
+
+        for radius in 0..6 {
+            let expected = grid.stencil(radius, |window| {
+                **window.values().iter().max().unwrap()
+            });
+            assert_eq!(grid.max_filter(radius), expected);
+        }
+
+        // Equal values are handled as a plateau.
+        let grid = Grid::from_rows(vec![vec![1.0, 3.0, 3.0, 1.0, 1.0, 2.0]]);
+        assert_eq!(grid.max_filter(1).row(0), [3.0, 3.0, 3.0, 3.0, 2.0, 2.0]);
+        assert_eq!(grid.min_filter(1).row(0), [1.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
+
+        let grid = Grid::from_rows(vec![vec![4], vec![1], vec![6]]);
+        assert_eq!(grid.max_filter(1), Grid::from_rows(vec![vec![4], vec![6], vec![6]]));
+
+        assert_eq!(Grid::<u8>::zero().max_filter(1), Grid::zero());
+    }
+
+    #[test]
+    fn grid_min_max_filter_with_border() {
+        let grid = Grid::from_rows(vec![vec![7, 3, 9, 4, 8],
+                                        vec![2, 6, 5, 1, 9],
+                                        vec![8, 4, 7, 3, 6],
+                                        vec![5, 9, 2, 8, 4]]);
+
+        // It matches the straightforward computation for any policy and
+        // radius.
+        let borders = [BorderPolicy::Clamp, BorderPolicy::Wrap, BorderPolicy::Mirror,
+                       BorderPolicy::Constant(5), BorderPolicy::Skip];
+
+        for &border in &borders {
+            for radius in 0..6 {
+                let expected = grid.stencil_with_border(radius, border, |window| *window.iterator().min().unwrap());
+                assert_eq!(grid.min_filter_with_border(radius, border), expected);
+
+                let expected = grid.stencil_with_border(radius, border, |window| *window.iterator().max().unwrap());
+                assert_eq!(grid.max_filter_with_border(radius, border), expected);
+            }
+
+            assert_eq!(Grid::<u8>::zero().min_filter_with_border(1, BorderPolicy::Wrap), Grid::zero());
+            assert_eq!(Grid::<u8>::with_size(size!(0, 2), 0).max_filter_with_border(1, BorderPolicy::Mirror).size(), size!(0, 2));
+        }
+
+        // Large radii cover the whole grid without extending it that much.
+        for &radius in &[100_000_000, usize::MAX] {
+            assert_eq!(grid.min_filter_with_border(radius, BorderPolicy::Wrap), Grid::with_size(size!(5, 4), 1));
+            assert_eq!(grid.min_filter_with_border(radius, BorderPolicy::Mirror), Grid::with_size(size!(5, 4), 1));
+            assert_eq!(grid.max_filter_with_border(radius, BorderPolicy::Constant(0)), Grid::with_size(size!(5, 4), 9));
+            assert_eq!(grid.min_filter_with_border(radius, BorderPolicy::Constant(0)), Grid::with_size(size!(5, 4), 0));
+        }
+    }
+}
@@ -0,0 +1,201 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use crate::coordinate::Coordinate;
+use crate::grid::Grid;
+
+/// The distance metrics between the elements of a grid
+///
+/// This enumeration lists the ways to measure the distance between two
+/// elements of a grid.
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Metric {
+    /// The number of horizontal and vertical steps between the elements (also
+    /// known as the taxicab distance).
+    Manhattan,
+
+    /// The number of horizontal, vertical and diagonal steps between the
+    /// elements (also known as the chessboard distance).
+    Chebyshev,
+
+    /// The straight-line distance between the elements.
+    Euclidean
+}
+
+impl Metric {
+    // Measure a distance from its horizontal and vertical components; the
+    // Euclidean distance is squared to stay in integers, which preserves the
+    // order of the distances.
+    fn measure(self, dx: usize, dy: usize) -> usize {
+        match self {
+            Metric::Manhattan => dx + dy,
+            Metric::Chebyshev => dx.max(dy),
+            Metric::Euclidean => dx * dx + dy * dy
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+
+    /// Find the nearest element matching a predicate.
+    ///
+    /// This method returns the coordinate of the element closest to a given
+    /// coordinate for which a predicate returns true, or `None` if there's no
+    /// such element; the element at the coordinate itself is included. The
+    /// search expands ring by ring around the coordinate and stops as soon as
+    /// no farther ring can contain a closer element, so nearby matches are
+    /// found without scanning the whole grid.
+    ///
+    /// If several elements are at the same distance, the first one from left
+    /// to right and top to bottom is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - The coordinate to search from
+    /// * `predicate` - The function telling whether an element matches
+    /// * `metric` - The metric measuring the distances
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, Metric, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec!['.', '.', '.', 'g'],
+    ///                                 vec!['.', '@', '.', '.'],
+    ///                                 vec!['.', '.', '.', '.'],
+    ///                                 vec!['g', '.', '.', '.']]);
+    ///
+    /// let gold = |tile: &char| *tile == 'g';
+    ///
+    /// assert_eq!(grid.nearest(coord!(1, 1), gold, Metric::Manhattan), Some(coord!(3, 0)));
+    /// assert_eq!(grid.nearest(coord!(0, 2), gold, Metric::Chebyshev), Some(coord!(0, 3)));
+    /// assert_eq!(grid.nearest(coord!(1, 1), |tile| *tile == 'x', Metric::Euclidean), None);
+    /// ```
+    ///
+    pub fn nearest<F>(&self, coordinate: Coordinate, mut predicate: F, metric: Metric) -> Option<Coordinate>
+        where F: FnMut(&T) -> bool
+    {
+        let size = self.size();
+        assert!(size.contains(coordinate), "index out of bounds");
+
+        let (x, y) = (coordinate.x, coordinate.y);
+        let rings = x.max(size.width - 1 - x).max(y).max(size.height - 1 - y);
+
+        let mut nearest: Option<(usize, Coordinate)> = None;
+        for ring in 0..=rings {
+            // The elements of a ring are at least this far away.
+            if let Some((distance, _)) = nearest {
+                if metric.measure(ring, 0) > distance {
+                    break;
+                }
+            }
+
+            let top = y.saturating_sub(ring);
+            let bottom = (y + ring).min(size.height - 1);
+            let left = x.saturating_sub(ring);
+            let right = (x + ring).min(size.width - 1);
+
+            for row in top..=bottom {
+                let dy = y.abs_diff(row);
+
+                // The rows on the edges of the ring are walked entirely, the
+                // others only at both ends.
+                let (span, ends) = if dy == ring {
+                    (Some(left..=right), [None, None])
+                }
+                else {
+                    (None, [x.checked_sub(ring), x.checked_add(ring).filter(|&column| column < size.width)])
+                };
+
+                for column in span.into_iter().flatten().chain(ends.iter().flatten().copied()) {
+                    let dx = x.abs_diff(column);
+                    let candidate = Coordinate::new(column, row);
+                    let distance = metric.measure(dx, dy);
+                    let closer = match nearest {
+                        Some((nearest, coordinate)) => {
+                            (distance, row, column) < (nearest, coordinate.y, coordinate.x)
+                        },
+                        None => true
+                    };
+
+                    if closer && predicate(&self[candidate]) {
+                        nearest = Some((distance, candidate));
+                    }
+                }
+            }
+        }
+
+        nearest.map(|(_, coordinate)| coordinate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_nearest() {
+        let grid = Grid::from_rows(vec![vec![0, 0, 0, 0, 1],
+                                        vec![0, 0, 0, 0, 0],
+                                        vec![0, 0, 0, 0, 0],
+                                        vec![0, 0, 0, 0, 0],
+                                        vec![0, 1, 0, 0, 0]]);
+
+        let one = |value: &i32| *value == 1;
+
+        // Both are 3 steps away with diagonals, (4, 0) comes first.
+        assert_eq!(grid.nearest(coord!(1, 1), one, Metric::Chebyshev), Some(coord!(4, 0)));
+
+        // (4, 0) is 4 steps away without diagonals, (1, 4) is 3 steps away.
+        assert_eq!(grid.nearest(coord!(1, 1), one, Metric::Manhattan), Some(coord!(1, 4)));
+        assert_eq!(grid.nearest(coord!(1, 1), one, Metric::Euclidean), Some(coord!(1, 4)));
+
+        assert_eq!(grid.nearest(coord!(4, 0), one, Metric::Manhattan), Some(coord!(4, 0)));
+        assert_eq!(grid.nearest(coord!(0, 0), |value| *value == 2, Metric::Chebyshev), None);
+    }
+
+    #[test]
+    fn grid_nearest_beyond_ring() {
+        // (2, 0) in the second ring around (2, 2) is as close as (3, 1) in the
+        // first ring without diagonals, and it comes first.
+        let grid = Grid::from_rows(vec![vec![0, 0, 1, 0, 0],
+                                        vec![0, 0, 0, 1, 0],
+                                        vec![0, 0, 0, 0, 0],
+                                        vec![0, 0, 0, 0, 0],
+                                        vec![0, 0, 0, 0, 0]]);
+
+        let one = |value: &i32| *value == 1;
+
+        assert_eq!(grid.nearest(coord!(2, 2), one, Metric::Chebyshev), Some(coord!(3, 1)));
+        assert_eq!(grid.nearest(coord!(2, 2), one, Metric::Manhattan), Some(coord!(2, 0)));
+        assert_eq!(grid.nearest(coord!(2, 2), one, Metric::Euclidean), Some(coord!(3, 1)));
+
+        // (7, 1) in the third ring around (4, 4) is farther than (4, 8) in the
+        // fourth ring, except with diagonals.
+        let mut grid = Grid::with_size(size!(9, 9), 0);
+        grid[coord!(7, 1)] = 1;
+        grid[coord!(4, 8)] = 1;
+
+        assert_eq!(grid.nearest(coord!(4, 4), one, Metric::Chebyshev), Some(coord!(7, 1)));
+        assert_eq!(grid.nearest(coord!(4, 4), one, Metric::Manhattan), Some(coord!(4, 8)));
+        assert_eq!(grid.nearest(coord!(4, 4), one, Metric::Euclidean), Some(coord!(4, 8)));
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_nearest_out_of_bounds() {
+        let grid = Grid::with_size(size!(2, 2), 0);
+        grid.nearest(coord!(2, 0), |_| true, Metric::Manhattan);
+    }
+}
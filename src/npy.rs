@@ -0,0 +1,386 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use std::convert::TryFrom;
+use std::io::{self, Read, Write, ErrorKind};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use crate::grid::Grid;
+use crate::binary::{BinaryElement, read_rows, write_rows};
+
+// The first bytes of the NumPy format.
+const MAGIC: [u8; 6] = *b"\x93NUMPY";
+
+// The header and the magic bytes before it are padded to a multiple of this
+// alignment, as done by NumPy.
+const ALIGNMENT: usize = 64;
+
+// The largest header accepted when reading, which is the default limit of
+// NumPy; headers of two-dimensional arrays are much smaller.
+const MAX_HEADER_LENGTH: usize = 10000;
+
+impl<T: Clone + BinaryElement> Grid<T> {
+
+    /// Write the grid in the NumPy format.
+    ///
+    /// This method writes the grid as a two-dimensional array in the `.npy`
+    /// format of NumPy, so it can be loaded with `numpy.load()` for analysis
+    /// or plotting; the shape of the array is the height and the width of the
+    /// grid. Use `read_npy()` to load it back.
+    ///
+    /// This method is only available with the `npy` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The writer to write the grid to
+    ///
+    /// # Errors
+    ///
+    /// It returns an error of kind `InvalidInput` if the element type has no
+    /// NumPy type descriptor, and an error if writing to the writer fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1.0f32, 2.0],
+    ///                                 vec![3.0, 4.0]]);
+    ///
+    /// let mut bytes = Vec::new();
+    /// grid.write_npy(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(Grid::<f32>::read_npy(bytes.as_slice()).unwrap(), grid);
+    /// ```
+    ///
+    pub fn write_npy<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let descr = T::NPY_DESCR.ok_or_else(|| {
+            io::Error::new(ErrorKind::InvalidInput, "element type has no npy descriptor")
+        })?;
+
+        let size = self.size();
+        let mut header = format!("{{'descr': '{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+                                 descr, size.height, size.width);
+
+        // The header ends with a newline, after the padding.
+        let length = MAGIC.len() + 4 + header.len() + 1;
+        header.push_str(&" ".repeat((ALIGNMENT - length % ALIGNMENT) % ALIGNMENT));
+        header.push('\n');
+
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&[1, 0])?;
+
+        // The header only holds a few numbers; its length always fits in the
+        // first version of the format.
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+
+        write_rows(self, &mut writer)
+    }
+
+    /// Read a grid from the NumPy format.
+    ///
+    /// This function reads a two-dimensional array in the `.npy` format of
+    /// NumPy, as written by `numpy.save()` or `write_npy()`; the first axis of
+    /// the array is the height of the grid. Arrays in row-major order and in
+    /// column-major (Fortran) order are both supported, and the type of their
+    /// elements must match the element type of the grid.
+    ///
+    /// This function is only available with the `npy` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The reader to read the grid from
+    ///
+    /// # Errors
+    ///
+    /// It returns an error of kind `InvalidData` if the header is invalid or
+    /// too large, if the array isn't two-dimensional or if the type of its
+    /// elements doesn't match the element type (or if the element type has
+    /// no NumPy type descriptor), and an error of kind
+    /// `UnexpectedEof` if the data is truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::with_size(size!(3, 2), 7u8);
+    ///
+    /// let mut bytes = Vec::new();
+    /// grid.write_npy(&mut bytes).unwrap();
+    ///
+    /// // The type of the elements doesn't match.
+    /// assert!(Grid::<i8>::read_npy(bytes.as_slice()).is_err());
+    /// ```
+    ///
+    pub fn read_npy<R: Read>(mut reader: R) -> io::Result<Grid<T>> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if magic[..6] != MAGIC {
+            return Err(invalid_data("not a npy file"));
+        }
+
+        // The versions differ by the size of the header length only; the
+        // encoding of the header doesn't matter as it's plain ASCII.
+        let length = match magic[6] {
+            1 => {
+                let mut bytes = [0; 2];
+                reader.read_exact(&mut bytes)?;
+                u16::from_le_bytes(bytes) as usize
+            },
+            2 | 3 => {
+                let mut bytes = [0; 4];
+                reader.read_exact(&mut bytes)?;
+                usize::try_from(u32::from_le_bytes(bytes)).map_err(|_| invalid_data("header is too large"))?
+            },
+            _ => return Err(invalid_data("unsupported npy version"))
+        };
+
+        if length > MAX_HEADER_LENGTH {
+            return Err(invalid_data("header is too large"));
+        }
+
+        let mut header = vec![0; length];
+        reader.read_exact(&mut header)?;
+        let header = String::from_utf8(header).map_err(|_| invalid_data("header is invalid"))?;
+
+        let descr = header_value(&header, "descr")
+            .and_then(|value| value.get(1..)?.split(['\'', '"']).next())
+            .ok_or_else(|| invalid_data("header is invalid"))?;
+        // The byte order of the single-byte types is irrelevant.
+        let expected = T::NPY_DESCR.ok_or_else(|| invalid_data("element type has no npy descriptor"))?;
+        if descr != expected && !(T::SIZE == 1 && descr.get(1..) == expected.get(1..)) {
+            return Err(invalid_data("mismatching element type"));
+        }
+
+        let fortran_order = header_value(&header, "fortran_order")
+            .and_then(|value| {
+                if value.starts_with("True") { Some(true) }
+                else if value.starts_with("False") { Some(false) }
+                else { None }
+            })
+            .ok_or_else(|| invalid_data("header is invalid"))?;
+
+        let shape = header_value(&header, "shape")
+            .and_then(|value| value.strip_prefix('(')?.split(')').next())
+            .ok_or_else(|| invalid_data("header is invalid"))?
+            .split(',')
+            .map(str::trim)
+            .filter(|dimension| !dimension.is_empty())
+            .map(|dimension| dimension.parse::<usize>().map_err(|_| invalid_data("header is invalid")))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let (height, width) = match shape[..] {
+            [height, width] => (height, width),
+            _ => return Err(invalid_data("array isn't two-dimensional"))
+        };
+
+        if !fortran_order {
+            let rows = read_rows(&mut reader, width, height)?;
+            return Ok(Grid::from_row_vectors(width, rows));
+        }
+
+        // The elements are stored column by column.
+        let columns = read_rows::<T, R>(&mut reader, height, width)?;
+
+        let mut rows = (0..height).map(|_| Vec::with_capacity(width)).collect::<Vec<_>>();
+        for column in columns {
+            for (row, value) in rows.iter_mut().zip(column) {
+                row.push(value);
+            }
+        }
+
+        Ok(Grid::from_row_vectors(width, rows))
+    }
+}
+
+// Return the text following a key of the header, which is the representation
+// of a Python dictionary.
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let start = header.find(&format!("'{}'", key))? + key.len() + 2;
+    let value = header[start..].trim_start().strip_prefix(':')?;
+
+    Some(value.trim_start())
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_write_npy() {
+        let grid = Grid::from_rows(vec![vec![1u16, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let mut bytes = Vec::new();
+        grid.write_npy(&mut bytes).unwrap();
+
+        let header = b"{'descr': '<u2', 'fortran_order': False, 'shape': (2, 3), }";
+        assert_eq!(&bytes[..10], b"\x93NUMPY\x01\x00\x76\x00");
+        assert_eq!(&bytes[10..10 + header.len()], header);
+        assert_eq!(bytes[127], b'\n');
+        assert_eq!(&bytes[128..], &[1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0]);
+    }
+
+    #[test]
+    fn grid_read_npy() {
+        let grid = Grid::from_rows(vec![vec![-1.5f64, 2.0],
+                                        vec![3.25, -4.0],
+                                        vec![5.0, 6.125]]);
+
+        let mut bytes = Vec::new();
+        grid.write_npy(&mut bytes).unwrap();
+        assert_eq!(Grid::<f64>::read_npy(bytes.as_slice()).unwrap(), grid);
+
+        // Empty grids keep their width or height.
+        let grid = Grid::with_size(size!(3, 0), 0i32);
+
+        let mut bytes = Vec::new();
+        grid.write_npy(&mut bytes).unwrap();
+        assert_eq!(Grid::<i32>::read_npy(bytes.as_slice()).unwrap().size(), size!(3, 0));
+
+        let grid = Grid::with_size(size!(0, 2), 0i32);
+
+        let mut bytes = Vec::new();
+        grid.write_npy(&mut bytes).unwrap();
+        assert_eq!(Grid::<i32>::read_npy(bytes.as_slice()).unwrap().size(), size!(0, 2));
+    }
+
+    #[test]
+    fn grid_read_npy_numpy() {
+        // As written by NumPy, in the column-major order, with the version
+        // 2.0 of the format and a byte order marker for single bytes.
+        let mut bytes = b"\x93NUMPY\x02\x00".to_vec();
+        let header = b"{'descr': '<u1', 'fortran_order': True, 'shape': (2, 3), }          \n";
+        bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(header);
+        bytes.extend_from_slice(&[1, 4, 2, 5, 3, 6]);
+
+        let grid = Grid::<u8>::read_npy(bytes.as_slice()).unwrap();
+        assert_eq!(grid, Grid::from_rows(vec![vec![1, 2, 3],
+                                              vec![4, 5, 6]]));
+    }
+
+    #[test]
+    fn grid_read_npy_invalid() {
+        let grid = Grid::with_size(size!(2, 2), 42u8);
+
+        let mut bytes = Vec::new();
+        grid.write_npy(&mut bytes).unwrap();
+
+        let error = Grid::<i8>::read_npy(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        let error = Grid::<u8>::read_npy(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+        bytes[0] = b'X';
+        let error = Grid::<u8>::read_npy(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        // Arrays of a single dimension aren't grids.
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        let header = b"{'descr': '<f4', 'fortran_order': False, 'shape': (4,), }\n";
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header);
+        bytes.extend_from_slice(&[0; 16]);
+
+        let error = Grid::<f32>::read_npy(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        let header = b"{'descr': '<f4', 'shape': (1, 1), }\n";
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header);
+        bytes.extend_from_slice(&[0; 4]);
+
+        let error = Grid::<f32>::read_npy(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn grid_npy_custom_element() {
+        // Elements of the binary format without a NumPy type descriptor.
+        #[derive(Clone, Debug, PartialEq)]
+        struct Custom(u8);
+
+        impl BinaryElement for Custom {
+            const ENCODING: u8 = 42;
+            const SIZE: usize = 1;
+
+            fn write_bytes(&self, bytes: &mut [u8]) {
+                bytes[0] = self.0;
+            }
+
+            fn read_bytes(bytes: &[u8]) -> Self {
+                Custom(bytes[0])
+            }
+        }
+
+        let grid = Grid::with_size(size!(2, 2), Custom(1));
+        grid.write_binary(Vec::new()).unwrap();
+
+        let error = grid.write_npy(Vec::new()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+
+        let mut bytes = Vec::new();
+        Grid::with_size(size!(2, 2), 1u8).write_npy(&mut bytes).unwrap();
+
+        let error = Grid::<Custom>::read_npy(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn grid_read_npy_malformed() {
+        let npy = |version: u8, header: &str, data: &[u8]| {
+            let mut bytes = b"\x93NUMPY".to_vec();
+            bytes.extend_from_slice(&[version, 0]);
+            if version == 1 {
+                bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+            }
+            else {
+                bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+            }
+            bytes.extend_from_slice(header.as_bytes());
+            bytes.extend_from_slice(data);
+            bytes
+        };
+
+        // The header length isn't trusted.
+        let mut bytes = b"\x93NUMPY\x02\x00".to_vec();
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+
+        let error = Grid::<u8>::read_npy(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        // Neither is the shape, in both orders.
+        let bytes = npy(1, "{'descr': '|u1', 'fortran_order': False, 'shape': (1, 1099511627776), }\n", &[1, 2]);
+        let error = Grid::<u8>::read_npy(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+        let bytes = npy(3, "{'descr': '|u1', 'fortran_order': True, 'shape': (1099511627776, 1), }\n", &[1, 2]);
+        let error = Grid::<u8>::read_npy(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+        let bytes = npy(1, "{'descr': '|u1', 'fortran_order': False, 'shape': (17179869184, 0), }\n", &[]);
+        let error = Grid::<u8>::read_npy(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        let bytes = npy(1, "{'descr': '|u1', 'fortran_order': True, 'shape': (0, 1099511627776), }\n", &[]);
+        let error = Grid::<u8>::read_npy(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+}
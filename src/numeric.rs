@@ -0,0 +1,78 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+/// An interface to implement numeric elements
+///
+/// This trait allows the numeric algorithms of the grid (averaging,
+/// interpolation, filtering and so on) to work with any primitive numeric
+/// type. They compute with `f64` and convert the results back to the element
+/// type; integers are rounded to the nearest value and saturate at their
+/// bounds.
+///
+/// It's implemented for all primitive integer and floating-point types.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::Numeric;
+/// #
+/// assert_eq!(u8::from_f64(41.6), 42);
+/// assert_eq!(u8::from_f64(-3.0), 0);
+/// assert_eq!(i32::from_f64(-2.5), -3);
+/// assert_eq!(42u8.to_f64(), 42.0);
+/// ```
+///
+pub trait Numeric: Copy + PartialOrd {
+    /// Convert the value to `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Convert a `f64` value to the numeric type.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! numeric_integer {
+    ($($type:ty),*) => {
+        $(
+            impl Numeric for $type {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    // Round half away from zero; the cast truncates toward zero
+                    // and saturates at the bounds of the type.
+                    if value < 0.0 {
+                        (value - 0.5) as $type
+                    }
+                    else {
+                        (value + 0.5) as $type
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! numeric_float {
+    ($($type:ty),*) => {
+        $(
+            impl Numeric for $type {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $type
+                }
+            }
+        )*
+    };
+}
+
+numeric_integer!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+numeric_float!(f32, f64);
@@ -6,6 +6,8 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
+use core::ops::{Add, AddAssign, Sub, SubAssign, Neg, Mul};
+
 /// A two-dimensional offset
 ///
 /// This structure defines a basic two-dimensional offset to shift values
@@ -33,7 +35,19 @@
 /// let offset3 = Offset::zero();
 /// ```
 ///
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// Offsets support the usual arithmetic operators; they can be added,
+/// subtracted, negated and multiplied by a scalar.
+///
+/// ```
+/// # use ingrid::{Offset, offset};
+/// #
+/// assert_eq!(offset!(1, 2) + offset!(-1, 1), offset!(0, 3));
+/// assert_eq!(offset!(1, 2) - offset!(-1, 1), offset!(2, 1));
+/// assert_eq!(-offset!(1, -2), offset!(-1, 2));
+/// assert_eq!(offset!(1, -2) * 3, offset!(3, -6));
+/// ```
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Offset {
     /// The offset value on the X axis.
     pub x: isize,
@@ -83,6 +97,50 @@ impl Offset {
     }
 }
 
+impl Add for Offset {
+    type Output = Offset;
+
+    fn add(self, other: Offset) -> Self::Output {
+        Offset::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Offset {
+    type Output = Offset;
+
+    fn sub(self, other: Offset) -> Self::Output {
+        Offset::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl AddAssign for Offset {
+    fn add_assign(&mut self, other: Offset) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Offset {
+    fn sub_assign(&mut self, other: Offset) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Offset {
+    type Output = Offset;
+
+    fn neg(self) -> Self::Output {
+        Offset::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Offset {
+    type Output = Offset;
+
+    fn mul(self, scalar: isize) -> Self::Output {
+        Offset::new(self.x * scalar, self.y * scalar)
+    }
+}
+
 /// An offset instantiation helper.
 ///
 /// This macro helps instantiate offsets with a shorter syntax. Instead of
@@ -99,6 +157,6 @@ impl Offset {
 #[macro_export]
 macro_rules! offset {
     ($x:expr, $y:expr) => {
-        Offset::new($x, $y);
+        Offset::new($x, $y)
     };
 }
@@ -0,0 +1,306 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::iter::FromIterator;
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::grid::Grid;
+
+/// An ordered list of coordinates
+///
+/// This structure defines a path, which is an ordered list of coordinates
+/// such as a route computed by a pathfinding algorithm. The coordinates don't
+/// need to be adjacent, and a path may visit a coordinate more than once.
+///
+/// A path isn't tied to a grid; use `fits()` to check whether it's within the
+/// bounds of a grid before using it with `Grid::values_along()` or
+/// `Grid::set_along()`.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Grid, Path, coord, size};
+/// #
+/// let mut grid = Grid::with_size(size!(3, 3), '.');
+/// let path = Path::from(vec![coord!(0, 0), coord!(1, 1), coord!(2, 1)]);
+///
+/// grid.set_along(&path, '#');
+/// assert_eq!(grid.row(1).values(), vec![&'.', &'#', &'#']);
+/// ```
+///
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Path {
+    coordinates: Vec<Coordinate>
+}
+
+impl Path {
+
+    /// Create an empty path.
+    ///
+    /// This function creates a path without any coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Path;
+    /// #
+    /// let path = Path::new();
+    /// assert!(path.is_empty());
+    /// ```
+    ///
+    pub fn new() -> Path {
+        Path {
+            coordinates: Vec::new()
+        }
+    }
+
+    /// Return the coordinates of the path.
+    ///
+    /// This method returns the coordinates of the path, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Path, coord};
+    /// #
+    /// let path = Path::from(vec![coord!(0, 0), coord!(0, 1)]);
+    /// assert_eq!(path.coordinates(), &[coord!(0, 0), coord!(0, 1)]);
+    /// ```
+    ///
+    pub fn coordinates(&self) -> &[Coordinate] {
+        &self.coordinates
+    }
+
+    /// Return the number of coordinates of the path.
+    ///
+    /// This method returns the number of coordinates of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Path, coord};
+    /// #
+    /// let path = Path::from(vec![coord!(0, 0), coord!(0, 1)]);
+    /// assert_eq!(path.len(), 2);
+    /// ```
+    ///
+    pub fn len(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    /// Check whether the path is empty.
+    ///
+    /// This method returns true if the path has no coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Path, coord};
+    /// #
+    /// let mut path = Path::new();
+    /// assert!(path.is_empty());
+    ///
+    /// path.push(coord!(0, 0));
+    /// assert!(!path.is_empty());
+    /// ```
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.coordinates.is_empty()
+    }
+
+    /// Add a coordinate to the end of the path.
+    ///
+    /// This method appends a coordinate to the path.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - The coordinate to append
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Path, coord};
+    /// #
+    /// let mut path = Path::new();
+    /// path.push(coord!(1, 2));
+    ///
+    /// assert_eq!(path.coordinates(), &[coord!(1, 2)]);
+    /// ```
+    ///
+    pub fn push(&mut self, coordinate: Coordinate) {
+        self.coordinates.push(coordinate);
+    }
+
+    /// Check whether the path is within a size.
+    ///
+    /// This method returns true if all the coordinates of the path are within
+    /// a given size, which means the path can be used with a grid of that
+    /// size.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Path, coord, size};
+    /// #
+    /// let path = Path::from(vec![coord!(0, 0), coord!(2, 1)]);
+    ///
+    /// assert!(path.fits(size!(3, 2)));
+    /// assert!(!path.fits(size!(2, 2)));
+    /// ```
+    ///
+    pub fn fits(&self, size: Size) -> bool {
+        self.coordinates.iter().all(|&coordinate| size.contains(coordinate))
+    }
+}
+
+impl From<Vec<Coordinate>> for Path {
+    fn from(coordinates: Vec<Coordinate>) -> Path {
+        Path { coordinates }
+    }
+}
+
+impl FromIterator<Coordinate> for Path {
+    fn from_iter<I: IntoIterator<Item = Coordinate>>(iterator: I) -> Path {
+        Path {
+            coordinates: iterator.into_iter().collect()
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+
+    /// Return the elements along a path.
+    ///
+    /// This method returns the elements of the grid at the coordinates of a
+    /// path, in the order of the path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to follow
+    ///
+    /// # Panics
+    ///
+    /// It panics if a coordinate of the path is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, Path, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                 vec![3, 4]]);
+    ///
+    /// let path = Path::from(vec![coord!(0, 0), coord!(0, 1), coord!(1, 1)]);
+    /// assert_eq!(grid.values_along(&path), vec![&1, &3, &4]);
+    /// ```
+    ///
+    pub fn values_along(&self, path: &Path) -> Vec<&T> {
+        path.coordinates.iter().map(|&coordinate| self.value(coordinate)).collect()
+    }
+
+    /// Set the elements along a path.
+    ///
+    /// This method sets the elements of the grid at the coordinates of a path
+    /// to a given value, drawing the path onto the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to draw
+    /// * `value` - The value of the elements
+    ///
+    /// # Panics
+    ///
+    /// It panics if a coordinate of the path is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, Path, coord};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                     vec![3, 4]]);
+    ///
+    /// let path = Path::from(vec![coord!(1, 0), coord!(1, 1)]);
+    /// grid.set_along(&path, 0);
+    ///
+    /// assert_eq!(grid.column(1).values(), vec![&0, &0]);
+    /// ```
+    ///
+    pub fn set_along(&mut self, path: &Path, value: T) {
+        for &coordinate in &path.coordinates {
+            self.set_value(coordinate, value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_new() {
+        let path = Path::new();
+        assert!(path.is_empty());
+        assert_eq!(path.len(), 0);
+        assert_eq!(path, Path::default());
+    }
+
+    #[test]
+    fn path_from() {
+        let path = Path::from(vec![coord!(0, 0), coord!(1, 0)]);
+        assert_eq!(path.len(), 2);
+        assert_eq!(path.coordinates(), &[coord!(0, 0), coord!(1, 0)]);
+
+        let path = (0..3).map(|x| coord!(x, x)).collect::<Path>();
+        assert_eq!(path.coordinates(), &[coord!(0, 0), coord!(1, 1), coord!(2, 2)]);
+    }
+
+    #[test]
+    fn path_fits() {
+        let mut path = Path::new();
+        assert!(path.fits(size!(0, 0)));
+
+        path.push(coord!(1, 2));
+        assert!(path.fits(size!(2, 3)));
+        assert!(!path.fits(size!(1, 3)));
+        assert!(!path.fits(size!(2, 2)));
+    }
+
+    #[test]
+    fn grid_values_along() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let path = Path::from(vec![coord!(2, 1), coord!(1, 0), coord!(2, 1)]);
+        assert_eq!(grid.values_along(&path), vec![&6, &2, &6]);
+        assert!(grid.values_along(&Path::new()).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn grid_values_along_out_of_bounds() {
+        let grid = Grid::with_size(size!(2, 2), 0);
+        grid.values_along(&Path::from(vec![coord!(0, 2)]));
+    }
+
+    #[test]
+    fn grid_set_along() {
+        let mut grid = Grid::with_size(size!(3, 2), 0);
+
+        let path = Path::from(vec![coord!(0, 0), coord!(1, 0), coord!(1, 1)]);
+        grid.set_along(&path, 1);
+
+        assert_eq!(grid.row(0).values(), vec![&1, &1, &0]);
+        assert_eq!(grid.row(1).values(), vec![&0, &1, &0]);
+    }
+}
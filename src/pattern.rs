@@ -0,0 +1,260 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use crate::size::Size;
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+
+impl<T: Clone> Grid<T> {
+
+    /// Construct a grid by tiling another grid.
+    ///
+    /// This function constructs a grid by repeating a tile a given number of
+    /// times horizontally and vertically. It's useful to create backgrounds
+    /// or fixtures with periodic boundaries.
+    ///
+    /// # Arguments
+    ///
+    /// * `tile` - The grid to repeat
+    /// * `count` - The number of times the tile is repeated horizontally and vertically
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let tile = Grid::from_rows(vec![vec![1, 2],
+    ///                                 vec![3, 4]]);
+    ///
+    /// let grid = Grid::repeat(&tile, size!(3, 2));
+    /// assert_eq!(grid.size(), size!(6, 4));
+    /// assert_eq!(grid.row(0).values(), vec![&1, &2, &1, &2, &1, &2]);
+    /// assert_eq!(grid.row(1).values(), vec![&3, &4, &3, &4, &3, &4]);
+    /// assert_eq!(grid.row(2).values(), vec![&1, &2, &1, &2, &1, &2]);
+    /// ```
+    ///
+    pub fn repeat(tile: &Grid<T>, count: Size) -> Grid<T> {
+        let width = tile.size().width * count.width;
+
+        let rows = (0..count.height).flat_map(|_| {
+            tile.row_vectors().iter().map(|row| {
+                row.iter().cycle().take(width).cloned().collect()
+            })
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(width, rows)
+    }
+
+    /// Construct a grid with a checkerboard pattern.
+    ///
+    /// This function constructs a grid whose elements alternate between two
+    /// values, horizontally and vertically, starting with the first value at
+    /// the top-left corner.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the grid
+    /// * `a` - The value of the elements on the top-left corner diagonals
+    /// * `b` - The value of the other elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::checkerboard(size!(3, 2), 'x', 'o');
+    /// assert_eq!(grid.row(0).values(), vec![&'x', &'o', &'x']);
+    /// assert_eq!(grid.row(1).values(), vec![&'o', &'x', &'o']);
+    /// ```
+    ///
+    pub fn checkerboard(size: Size, a: T, b: T) -> Grid<T> {
+        Grid::generated(size, |x, y| {
+            if (x + y) % 2 == 0 { a.clone() } else { b.clone() }
+        })
+    }
+
+    /// Construct a grid with a frame.
+    ///
+    /// This function constructs a grid whose elements on the edges take one
+    /// value, and whose other elements take another value.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the grid
+    /// * `border` - The value of the elements on the edges
+    /// * `interior` - The value of the other elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::framed(size!(4, 3), '#', '.');
+    /// assert_eq!(grid.row(0).values(), vec![&'#', &'#', &'#', &'#']);
+    /// assert_eq!(grid.row(1).values(), vec![&'#', &'.', &'.', &'#']);
+    /// assert_eq!(grid.row(2).values(), vec![&'#', &'#', &'#', &'#']);
+    /// ```
+    ///
+    pub fn framed(size: Size, border: T, interior: T) -> Grid<T> {
+        Grid::generated(size, |x, y| {
+            if x == 0 || y == 0 || x + 1 == size.width || y + 1 == size.height {
+                border.clone()
+            }
+            else {
+                interior.clone()
+            }
+        })
+    }
+
+    // Construct a grid whose elements are computed from their position.
+    fn generated<F>(size: Size, mut function: F) -> Grid<T>
+        where F: FnMut(usize, usize) -> T
+    {
+        let rows = (0..size.height).map(|y| {
+            (0..size.width).map(|x| function(x, y)).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(size.width, rows)
+    }
+}
+
+impl<T: Numeric> Grid<T> {
+
+    /// Construct a grid with a horizontal gradient.
+    ///
+    /// This function constructs a grid whose elements go linearly from a value
+    /// on the left column to another value on the right column. The elements
+    /// of a column are all equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the grid
+    /// * `from` - The value of the elements of the left column
+    /// * `to` - The value of the elements of the right column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::gradient_x(size!(5, 2), 0.0, 1.0);
+    /// assert_eq!(grid.row(0).values(), vec![&0.0, &0.25, &0.5, &0.75, &1.0]);
+    /// assert_eq!(grid.row(1).values(), vec![&0.0, &0.25, &0.5, &0.75, &1.0]);
+    /// ```
+    ///
+    pub fn gradient_x(size: Size, from: T, to: T) -> Grid<T> {
+        Grid::generated(size, |x, _| gradient(from, to, x, size.width))
+    }
+
+    /// Construct a grid with a vertical gradient.
+    ///
+    /// This function constructs a grid whose elements go linearly from a value
+    /// on the top row to another value on the bottom row. The elements of a
+    /// row are all equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the grid
+    /// * `from` - The value of the elements of the top row
+    /// * `to` - The value of the elements of the bottom row
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::gradient_y(size!(2, 3), 0u8, 255);
+    /// assert_eq!(grid.row(0).values(), vec![&0, &0]);
+    /// assert_eq!(grid.row(1).values(), vec![&128, &128]);
+    /// assert_eq!(grid.row(2).values(), vec![&255, &255]);
+    /// ```
+    ///
+    pub fn gradient_y(size: Size, from: T, to: T) -> Grid<T> {
+        Grid::generated(size, |_, y| gradient(from, to, y, size.height))
+    }
+}
+
+// Compute the value at a given position of a linear gradient spanning a given
+// length; a gradient of a single element takes the first value.
+fn gradient<T: Numeric>(from: T, to: T, position: usize, length: usize) -> T {
+    if length < 2 {
+        return from;
+    }
+
+    let ratio = position as f64 / (length - 1) as f64;
+    T::from_f64(from.to_f64() + (to.to_f64() - from.to_f64()) * ratio)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_repeat() {
+        let tile = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let grid = Grid::repeat(&tile, size!(2, 3));
+        assert_eq!(grid.size(), size!(6, 6));
+        assert_eq!(grid.row(0).values(), vec![&1, &2, &3, &1, &2, &3]);
+        assert_eq!(grid.row(1).values(), vec![&4, &5, &6, &4, &5, &6]);
+        assert_eq!(grid.row(4).values(), vec![&1, &2, &3, &1, &2, &3]);
+        assert_eq!(grid.row(5).values(), vec![&4, &5, &6, &4, &5, &6]);
+
+        assert_eq!(Grid::repeat(&tile, size!(1, 1)), tile);
+        assert_eq!(Grid::repeat(&tile, size!(0, 0)).size(), size!(0, 0));
+        assert_eq!(Grid::repeat(&Grid::<u8>::zero(), size!(2, 2)).size(), size!(0, 0));
+    }
+
+    #[test]
+    fn grid_checkerboard() {
+        let grid = Grid::checkerboard(size!(4, 3), 1, 0);
+        assert_eq!(grid.size(), size!(4, 3));
+        assert_eq!(grid.row(0).values(), vec![&1, &0, &1, &0]);
+        assert_eq!(grid.row(1).values(), vec![&0, &1, &0, &1]);
+        assert_eq!(grid.row(2).values(), vec![&1, &0, &1, &0]);
+
+        assert_eq!(Grid::checkerboard(size!(0, 0), 1, 0).size(), size!(0, 0));
+    }
+
+    #[test]
+    fn grid_framed() {
+        let grid = Grid::framed(size!(3, 4), 1, 0);
+        assert_eq!(grid.row(0).values(), vec![&1, &1, &1]);
+        assert_eq!(grid.row(1).values(), vec![&1, &0, &1]);
+        assert_eq!(grid.row(2).values(), vec![&1, &0, &1]);
+        assert_eq!(grid.row(3).values(), vec![&1, &1, &1]);
+
+        let grid = Grid::framed(size!(2, 2), 1, 0);
+        assert_eq!(grid.values(), vec![&1, &1, &1, &1]);
+
+        let grid = Grid::framed(size!(1, 3), 1, 0);
+        assert_eq!(grid.values(), vec![&1, &1, &1]);
+    }
+
+    #[test]
+    fn grid_gradient_x() {
+        let grid = Grid::gradient_x(size!(3, 2), 10i32, -10);
+        assert_eq!(grid.row(0).values(), vec![&10, &0, &-10]);
+        assert_eq!(grid.row(1).values(), vec![&10, &0, &-10]);
+
+        let grid = Grid::gradient_x(size!(1, 2), 1.0, 2.0);
+        assert_eq!(grid.values(), vec![&1.0, &1.0]);
+    }
+
+    #[test]
+    fn grid_gradient_y() {
+        let grid = Grid::gradient_y(size!(2, 3), 0.0f32, 1.0);
+        assert_eq!(grid.row(0).values(), vec![&0.0, &0.0]);
+        assert_eq!(grid.row(1).values(), vec![&0.5, &0.5]);
+        assert_eq!(grid.row(2).values(), vec![&1.0, &1.0]);
+
+        assert_eq!(Grid::gradient_y(size!(0, 0), 0u8, 1).size(), size!(0, 0));
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use crate::coordinate::Coordinate;
+use crate::grid::Grid;
+
+impl<T: Clone> Grid<T> {
+
+    /// Count the elements matching a predicate.
+    ///
+    /// This method returns the number of elements of the grid for which a
+    /// predicate, given the coordinate and the element, returns true.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Predicate taking the coordinate and the element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.count_if(|_, &value| value % 2 == 0), 3);
+    /// assert_eq!(grid.count_if(|coordinate, &value| coordinate.y == 1 && value > 4), 2);
+    /// ```
+    ///
+    pub fn count_if<F>(&self, mut predicate: F) -> usize
+        where F: FnMut(Coordinate, &T) -> bool
+    {
+        let mut count = 0;
+        self.for_each_until(|coordinate, value| {
+            if predicate(coordinate, value) {
+                count += 1;
+            }
+            false
+        });
+
+        count
+    }
+
+    /// Check whether any element matches a predicate.
+    ///
+    /// This method returns true if a predicate, given the coordinate and the
+    /// element, returns true for at least one element of the grid. It stops at
+    /// the first matching element; it returns false if the grid is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Predicate taking the coordinate and the element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert!(grid.any(|coordinate, &value| coordinate.x == 0 && value == 4));
+    /// assert!(!grid.any(|_, &value| value > 6));
+    /// ```
+    ///
+    pub fn any<F>(&self, mut predicate: F) -> bool
+        where F: FnMut(Coordinate, &T) -> bool
+    {
+        self.for_each_until(|coordinate, value| predicate(coordinate, value))
+    }
+
+    /// Check whether all elements match a predicate.
+    ///
+    /// This method returns true if a predicate, given the coordinate and the
+    /// element, returns true for all elements of the grid. It stops at the
+    /// first element that doesn't match; it returns true if the grid is
+    /// empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Predicate taking the coordinate and the element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert!(grid.all(|coordinate, &value| value == coordinate.x + coordinate.y * 3 + 1));
+    /// assert!(!grid.all(|_, &value| value < 6));
+    /// ```
+    ///
+    pub fn all<F>(&self, mut predicate: F) -> bool
+        where F: FnMut(Coordinate, &T) -> bool
+    {
+        !self.for_each_until(|coordinate, value| !predicate(coordinate, value))
+    }
+
+    // Visit the elements in row-major order until the function returns true;
+    // return whether it did.
+    fn for_each_until<F>(&self, mut function: F) -> bool
+        where F: FnMut(Coordinate, &T) -> bool
+    {
+        self.row_vectors().iter().enumerate().any(|(y, row)| {
+            row.iter().enumerate().any(|(x, value)| function(Coordinate::new(x, y), value))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_count_if() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        assert_eq!(grid.count_if(|_, _| true), 6);
+        assert_eq!(grid.count_if(|_, &value| value > 3), 3);
+        assert_eq!(grid.count_if(|coordinate, _| coordinate.x == 2), 2);
+        assert_eq!(grid.count_if(|coordinate, &value| coordinate == coord!(1, 1) && value == 5), 1);
+        assert_eq!(Grid::<u8>::zero().count_if(|_, _| true), 0);
+    }
+
+    #[test]
+    fn grid_any() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        assert!(grid.any(|_, &value| value == 6));
+        assert!(grid.any(|coordinate, _| coordinate == coord!(2, 1)));
+        assert!(!grid.any(|coordinate, _| coordinate.y > 1));
+        assert!(!Grid::<u8>::zero().any(|_, _| true));
+
+        let mut visited = 0;
+        grid.any(|_, &value| {
+            visited += 1;
+            value == 2
+        });
+        assert_eq!(visited, 2);
+    }
+
+    #[test]
+    fn grid_all() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        assert!(grid.all(|_, &value| value > 0));
+        assert!(grid.all(|coordinate, _| coordinate.x < 3 && coordinate.y < 2));
+        assert!(!grid.all(|_, &value| value != 4));
+        assert!(Grid::<u8>::zero().all(|_, _| false));
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+
+impl Coordinate {
+
+    /// Return the isometric screen position of the coordinate.
+    ///
+    /// This method returns the screen position of the element at the
+    /// coordinate when the grid is rendered with an isometric projection, in
+    /// the diamond layout; the X axis of the grid goes down to the right and
+    /// its Y axis goes down to the left. The position is the top corner of the
+    /// tile, and the top corner of the first tile is at the origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `tile_size` - The size of the tiles on the screen
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, coord, size};
+    /// #
+    /// assert_eq!(coord!(0, 0).to_iso(size!(64, 32)), (0.0, 0.0));
+    /// assert_eq!(coord!(1, 0).to_iso(size!(64, 32)), (32.0, 16.0));
+    /// assert_eq!(coord!(0, 1).to_iso(size!(64, 32)), (-32.0, 16.0));
+    /// assert_eq!(coord!(2, 1).to_iso(size!(64, 32)), (32.0, 48.0));
+    /// ```
+    ///
+    pub fn to_iso(self, tile_size: Size) -> (f32, f32) {
+        let (half_width, half_height) = half(tile_size);
+        let (x, y) = (self.x as f32, self.y as f32);
+
+        ((x - y) * half_width, (x + y) * half_height)
+    }
+
+    /// Return the coordinate at an isometric screen position.
+    ///
+    /// This function returns the coordinate of the tile containing a screen
+    /// position when the grid is rendered with an isometric projection, in
+    /// the diamond layout; it's the inverse of `to_iso()`. It returns `None`
+    /// if the position is outside the tiles of positive coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The horizontal screen position
+    /// * `y` - The vertical screen position
+    /// * `tile_size` - The size of the tiles on the screen
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, coord, size};
+    /// #
+    /// assert_eq!(Coordinate::from_iso(0.0, 16.0, size!(64, 32)), Some(coord!(0, 0)));
+    /// assert_eq!(Coordinate::from_iso(40.0, 30.0, size!(64, 32)), Some(coord!(1, 0)));
+    /// assert_eq!(Coordinate::from_iso(-40.0, 30.0, size!(64, 32)), Some(coord!(0, 1)));
+    /// assert_eq!(Coordinate::from_iso(40.0, 10.0, size!(64, 32)), None);
+    /// ```
+    ///
+    pub fn from_iso(x: f32, y: f32, tile_size: Size) -> Option<Coordinate> {
+        let (half_width, half_height) = half(tile_size);
+        let (u, v) = (x / half_width, y / half_height);
+
+        let (x, y) = ((v + u) / 2.0, (v - u) / 2.0);
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+
+        // The casts truncate the positions, which are positive.
+        Some(Coordinate::new(x as usize, y as usize))
+    }
+
+    /// Return the staggered screen position of the coordinate.
+    ///
+    /// This method returns the screen position of the element at the
+    /// coordinate when the grid is rendered with an isometric projection, in
+    /// the staggered layout; the odd rows are shifted to the right by half a
+    /// tile and the rows overlap by half a tile. The position is the top-left
+    /// corner of the bounding box of the tile, and the bounding box of the
+    /// first tile is at the origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `tile_size` - The size of the tiles on the screen
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, coord, size};
+    /// #
+    /// assert_eq!(coord!(0, 0).to_staggered(size!(64, 32)), (0.0, 0.0));
+    /// assert_eq!(coord!(1, 0).to_staggered(size!(64, 32)), (64.0, 0.0));
+    /// assert_eq!(coord!(0, 1).to_staggered(size!(64, 32)), (32.0, 16.0));
+    /// assert_eq!(coord!(1, 2).to_staggered(size!(64, 32)), (64.0, 32.0));
+    /// ```
+    ///
+    pub fn to_staggered(self, tile_size: Size) -> (f32, f32) {
+        let (half_width, half_height) = half(tile_size);
+        let shift = if self.y % 2 == 1 { half_width } else { 0.0 };
+
+        (self.x as f32 * tile_size.width as f32 + shift, self.y as f32 * half_height)
+    }
+
+    /// Return the coordinate at a staggered screen position.
+    ///
+    /// This function returns the coordinate of the tile containing a screen
+    /// position when the grid is rendered with an isometric projection, in
+    /// the staggered layout; it's the inverse of `to_staggered()`. It returns
+    /// `None` if the position is outside the tiles of positive coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The horizontal screen position
+    /// * `y` - The vertical screen position
+    /// * `tile_size` - The size of the tiles on the screen
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, coord, size};
+    /// #
+    /// assert_eq!(Coordinate::from_staggered(32.0, 16.0, size!(64, 32)), Some(coord!(0, 0)));
+    /// assert_eq!(Coordinate::from_staggered(60.0, 28.0, size!(64, 32)), Some(coord!(0, 1)));
+    /// assert_eq!(Coordinate::from_staggered(100.0, 16.0, size!(64, 32)), Some(coord!(1, 0)));
+    /// assert_eq!(Coordinate::from_staggered(4.0, 4.0, size!(64, 32)), None);
+    /// ```
+    ///
+    pub fn from_staggered(x: f32, y: f32, tile_size: Size) -> Option<Coordinate> {
+        let (half_width, half_height) = half(tile_size);
+        if x < 0.0 || y < 0.0 {
+            return None;
+        }
+
+        // The bounding boxes of the tiles of the even rows tile the screen;
+        // the corners of each box belong to the tiles of the odd rows.
+        let column = (x / tile_size.width as f32) as usize;
+        let row = (y / tile_size.height as f32) as usize;
+
+        let dx = x - column as f32 * tile_size.width as f32 - half_width;
+        let dy = y - row as f32 * tile_size.height as f32 - half_height;
+
+        if dx.abs() / half_width + dy.abs() / half_height <= 1.0 {
+            return Some(Coordinate::new(column, row * 2));
+        }
+
+        let column = if dx < 0.0 { column.checked_sub(1)? } else { column };
+        let row = if dy < 0.0 { (row * 2).checked_sub(1)? } else { row * 2 + 1 };
+
+        Some(Coordinate::new(column, row))
+    }
+}
+
+// Return the half of the width and the height of the tiles.
+fn half(tile_size: Size) -> (f32, f32) {
+    (tile_size.width as f32 / 2.0, tile_size.height as f32 / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coordinate_iso() {
+        let tile_size = size!(4, 2);
+
+        for y in 0..4 {
+            for x in 0..4 {
+                let (screen_x, screen_y) = coord!(x, y).to_iso(tile_size);
+
+                // Points inside the tile, near each of its corners.
+                assert_eq!(Coordinate::from_iso(screen_x, screen_y + 0.1, tile_size), Some(coord!(x, y)));
+                assert_eq!(Coordinate::from_iso(screen_x + 1.8, screen_y + 1.0, tile_size), Some(coord!(x, y)));
+                assert_eq!(Coordinate::from_iso(screen_x, screen_y + 1.9, tile_size), Some(coord!(x, y)));
+                assert_eq!(Coordinate::from_iso(screen_x - 1.8, screen_y + 1.0, tile_size), Some(coord!(x, y)));
+            }
+        }
+
+        assert_eq!(Coordinate::from_iso(0.0, -0.1, tile_size), None);
+        assert_eq!(Coordinate::from_iso(3.0, 0.1, tile_size), None);
+        assert_eq!(Coordinate::from_iso(-3.0, 0.1, tile_size), None);
+    }
+
+    #[test]
+    fn coordinate_staggered() {
+        let tile_size = size!(4, 2);
+
+        for y in 0..4 {
+            for x in 0..4 {
+                let (screen_x, screen_y) = coord!(x, y).to_staggered(tile_size);
+
+                // Points inside the tile, near each of its corners.
+                assert_eq!(Coordinate::from_staggered(screen_x + 2.0, screen_y + 0.1, tile_size), Some(coord!(x, y)));
+                assert_eq!(Coordinate::from_staggered(screen_x + 3.8, screen_y + 1.0, tile_size), Some(coord!(x, y)));
+                assert_eq!(Coordinate::from_staggered(screen_x + 2.0, screen_y + 1.9, tile_size), Some(coord!(x, y)));
+                assert_eq!(Coordinate::from_staggered(screen_x + 0.2, screen_y + 1.0, tile_size), Some(coord!(x, y)));
+            }
+        }
+
+        // The corners of the first bounding box at the top and on the left.
+        assert_eq!(Coordinate::from_staggered(0.1, 0.1, tile_size), None);
+        assert_eq!(Coordinate::from_staggered(3.9, 0.1, tile_size), None);
+        assert_eq!(Coordinate::from_staggered(0.1, 1.9, tile_size), None);
+        assert_eq!(Coordinate::from_staggered(3.9, 1.9, tile_size), Some(coord!(0, 1)));
+
+        assert_eq!(Coordinate::from_staggered(-0.1, 1.0, tile_size), None);
+        assert_eq!(Coordinate::from_staggered(2.0, -0.1, tile_size), None);
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::offset::Offset;
+use crate::iterator_rect::IteratorRect;
+
+/// A two-dimensional rectangle
+///
+/// This structure defines a basic two-dimensional rectangle to denote regions
+/// of grids. It's made of the coordinate of its top-left corner and its size,
+/// and enables copy semantics just like coordinates and sizes. Also, in
+/// practice, you use the `rect!` macro helper to instantiate rectangles.
+///
+/// Note that a rectangle whose width or height is zero is said to be empty
+/// and contains no coordinate.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Rect, coord, size, rect};
+/// #
+/// // Create a rectangle starting at (1, 1) and spanning over 2x3 elements.
+/// let rect1 = rect!(1, 1, 2, 3);
+///
+/// // It's equivalent to the following.
+/// let rect2 = Rect::new(coord!(1, 1), size!(2, 3));
+///
+/// assert_eq!(rect1, rect2);
+/// assert!(rect1.contains(coord!(2, 3)));
+/// ```
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rect {
+    /// The coordinate of the top-left corner.
+    pub coordinate: Coordinate,
+
+    /// The size of the rectangle.
+    pub size: Size
+}
+
+impl Rect {
+    /// Construct a new rectangle.
+    ///
+    /// This function constructs a new rectangle from the coordinate of its
+    /// top-left corner and its size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Rect, coord, size};
+    /// #
+    /// let rect = Rect::new(coord!(1, 2), size!(3, 4));
+    ///
+    /// assert_eq!(rect.coordinate, coord!(1, 2));
+    /// assert_eq!(rect.size, size!(3, 4));
+    /// ```
+    ///
+    pub fn new(coordinate: Coordinate, size: Size) -> Rect {
+        Rect { coordinate, size }
+    }
+
+    /// Construct a zero rectangle.
+    ///
+    /// This function constructs a 'zero' rectangle which is a rectangle at
+    /// coordinate (0, 0) with a size of (0, 0).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Rect, coord, size};
+    /// #
+    /// let rect = Rect::zero();
+    ///
+    /// assert_eq!(rect.coordinate, coord!(0, 0));
+    /// assert_eq!(rect.size, size!(0, 0));
+    /// ```
+    ///
+    pub fn zero() -> Rect {
+        Rect { coordinate: Coordinate::zero(), size: Size::zero() }
+    }
+
+    /// Check whether a coordinate is within the rectangle.
+    ///
+    /// This method returns whether a coordinate is within the bounds of the
+    /// rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - The coordinate to check
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Rect, coord, rect};
+    /// #
+    /// let rect = rect!(1, 1, 2, 2);
+    ///
+    /// assert_eq!(rect.contains(coord!(1, 1)), true);
+    /// assert_eq!(rect.contains(coord!(2, 2)), true);
+    /// assert_eq!(rect.contains(coord!(0, 1)), false);
+    /// assert_eq!(rect.contains(coord!(3, 1)), false);
+    /// ```
+    ///
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        coordinate.x >= self.coordinate.x && coordinate.x < self.right() &&
+        coordinate.y >= self.coordinate.y && coordinate.y < self.bottom()
+    }
+
+    /// Compute the intersection with another rectangle.
+    ///
+    /// This method returns the rectangle that is covered by both rectangles,
+    /// or `None` if they don't overlap.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other rectangle
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Rect, rect};
+    /// #
+    /// let a = rect!(0, 0, 3, 3);
+    /// let b = rect!(2, 1, 3, 3);
+    /// let c = rect!(3, 3, 1, 1);
+    ///
+    /// assert_eq!(a.intersection(b), Some(rect!(2, 1, 1, 2)));
+    /// assert_eq!(a.intersection(c), None);
+    /// ```
+    ///
+    pub fn intersection(&self, other: Rect) -> Option<Rect> {
+        let left = self.coordinate.x.max(other.coordinate.x);
+        let top = self.coordinate.y.max(other.coordinate.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+
+        if left < right && top < bottom {
+            Some(Rect::new(Coordinate::new(left, top), Size::new(right - left, bottom - top)))
+        }
+        else {
+            None
+        }
+    }
+
+    /// Compute the union with another rectangle.
+    ///
+    /// This method returns the smallest rectangle that contains both
+    /// rectangles. Empty rectangles contain no coordinate and are therefore
+    /// ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other rectangle
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Rect, rect};
+    /// #
+    /// let a = rect!(0, 0, 2, 2);
+    /// let b = rect!(3, 1, 1, 2);
+    ///
+    /// assert_eq!(a.union(b), rect!(0, 0, 4, 3));
+    /// ```
+    ///
+    pub fn union(&self, other: Rect) -> Rect {
+        if other.size.is_empty() {
+            return *self;
+        }
+
+        if self.size.is_empty() {
+            return other;
+        }
+
+        let left = self.coordinate.x.min(other.coordinate.x);
+        let top = self.coordinate.y.min(other.coordinate.y);
+        let right = self.right().max(other.right());
+        let bottom = self.bottom().max(other.bottom());
+
+        Rect::new(Coordinate::new(left, top), Size::new(right - left, bottom - top))
+    }
+
+    /// Translate the rectangle.
+    ///
+    /// This method returns the rectangle shifted by a given offset; its size
+    /// is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The offset to shift the rectangle with
+    ///
+    /// # Panics
+    ///
+    /// It panics if the top-left corner goes below zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Offset, Rect, offset, rect};
+    /// #
+    /// let rect = rect!(1, 1, 2, 2);
+    /// assert_eq!(rect.translate(offset!(2, -1)), rect!(3, 0, 2, 2));
+    /// ```
+    ///
+    pub fn translate(&self, offset: Offset) -> Rect {
+        Rect::new(self.coordinate + offset, self.size)
+    }
+
+    /// Returns an iterator over the coordinates of the rectangle.
+    ///
+    /// This method returns an iterator over the coordinates contained in the
+    /// rectangle, from left to right and top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Rect, coord, rect};
+    /// #
+    /// let rect = rect!(1, 1, 2, 2);
+    ///
+    /// let mut iterator = rect.coordinates();
+    /// assert_eq!(iterator.next(), Some(coord!(1, 1)));
+    /// assert_eq!(iterator.next(), Some(coord!(2, 1)));
+    /// assert_eq!(iterator.next(), Some(coord!(1, 2)));
+    /// assert_eq!(iterator.next(), Some(coord!(2, 2)));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    ///
+    pub fn coordinates(&self) -> IteratorRect {
+        IteratorRect::new(*self)
+    }
+
+    fn right(&self) -> usize {
+        self.coordinate.x + self.size.width
+    }
+
+    fn bottom(&self) -> usize {
+        self.coordinate.y + self.size.height
+    }
+}
+
+/// A rectangle instantiation helper.
+///
+/// This macro helps instantiate rectangles with a shorter syntax. Instead of
+/// typing a full `Rect::new(Coordinate::new(x, y), Size::new(width, height))`,
+/// one simply has to write `rect!(x, y, width, height)` leading to more
+/// readable code.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Rect, coord, size, rect};
+/// #
+/// assert_eq!(rect!(1, 2, 3, 4), Rect::new(coord!(1, 2), size!(3, 4)));
+/// ```
+///
+#[macro_export]
+macro_rules! rect {
+    ($x:expr, $y:expr, $width:expr, $height:expr) => {
+        Rect::new(Coordinate::new($x, $y), Size::new($width, $height))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rect_contains() {
+        let rect = rect!(1, 2, 2, 1);
+
+        assert!(rect.contains(coord!(1, 2)));
+        assert!(rect.contains(coord!(2, 2)));
+        assert!(!rect.contains(coord!(0, 2)));
+        assert!(!rect.contains(coord!(3, 2)));
+        assert!(!rect.contains(coord!(1, 1)));
+        assert!(!rect.contains(coord!(1, 3)));
+
+        assert!(!Rect::zero().contains(coord!(0, 0)));
+    }
+
+    #[test]
+    fn rect_intersection() {
+        let rect = rect!(1, 1, 3, 3);
+
+        assert_eq!(rect.intersection(rect!(0, 0, 2, 2)), Some(rect!(1, 1, 1, 1)));
+        assert_eq!(rect.intersection(rect!(2, 2, 5, 5)), Some(rect!(2, 2, 2, 2)));
+        assert_eq!(rect.intersection(rect!(0, 0, 5, 5)), Some(rect));
+        assert_eq!(rect.intersection(rect!(4, 1, 1, 1)), None);
+        assert_eq!(rect.intersection(rect!(2, 2, 0, 1)), None);
+    }
+
+    #[test]
+    fn rect_union() {
+        let rect = rect!(1, 1, 2, 2);
+
+        assert_eq!(rect.union(rect!(0, 0, 1, 1)), rect!(0, 0, 3, 3));
+        assert_eq!(rect.union(rect!(2, 2, 3, 1)), rect!(1, 1, 4, 2));
+        assert_eq!(rect.union(rect!(5, 5, 0, 0)), rect);
+        assert_eq!(Rect::zero().union(rect), rect);
+    }
+
+    #[test]
+    fn rect_translate() {
+        let rect = rect!(1, 1, 2, 2);
+
+        assert_eq!(rect.translate(offset!(1, 0)), rect!(2, 1, 2, 2));
+        assert_eq!(rect.translate(offset!(-1, -1)), rect!(0, 0, 2, 2));
+    }
+}
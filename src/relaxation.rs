@@ -0,0 +1,295 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+use crate::derivative::Neighborhood;
+use crate::border::BorderPolicy;
+
+impl<T: Numeric> Grid<T> {
+
+    /// Compute one step of a relaxation.
+    ///
+    /// This method returns a new grid whose elements are computed by a
+    /// function from the elements of the grid and their neighbors, all read
+    /// from the grid before the step (which is known as a Jacobi iteration).
+    /// It's the building block of heat diffusion, smoothing, or iterative
+    /// solvers of the Laplace equation.
+    ///
+    /// The function is given the element and its neighbors; the neighbors
+    /// outside the grid are left out, so elements on the edges have fewer
+    /// neighbors. They're ordered from left to right and top to bottom. Use
+    /// `relax_step_with_border()` to handle the borders differently.
+    ///
+    /// # Arguments
+    ///
+    /// * `neighborhood` - The neighbors of each element
+    /// * `function` - The function computing an element from its neighbors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, Neighborhood};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0.0, 0.0, 0.0],
+    ///                                 vec![0.0, 8.0, 0.0],
+    ///                                 vec![0.0, 0.0, 0.0]]);
+    ///
+    /// // Average each element with its neighbors.
+    /// let grid = grid.relax_step(Neighborhood::Four, |center, neighbors| {
+    ///     (center + neighbors.iter().sum::<f64>()) / (neighbors.len() + 1) as f64
+    /// });
+    ///
+    /// assert_eq!(grid, Grid::from_rows(vec![vec![0.0, 8.0 / 4.0, 0.0],
+    ///                                       vec![8.0 / 4.0, 8.0 / 5.0, 8.0 / 4.0],
+    ///                                       vec![0.0, 8.0 / 4.0, 0.0]]));
+    /// ```
+    ///
+    pub fn relax_step<F>(&self, neighborhood: Neighborhood, function: F) -> Grid<T>
+        where F: FnMut(T, &[T]) -> T
+    {
+        self.relax_step_with_border(neighborhood, BorderPolicy::Skip, function)
+    }
+
+    /// Compute one step of a relaxation, with a border policy.
+    ///
+    /// This method is similar to `relax_step()`, but the neighbors outside of
+    /// the grid are given by a border policy; unless it's the skip policy,
+    /// all elements have the same number of neighbors.
+    ///
+    /// # Arguments
+    ///
+    /// * `neighborhood` - The neighbors of each element
+    /// * `border` - The policy for the elements outside of the grid
+    /// * `function` - The function computing an element from its neighbors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, Neighborhood, BorderPolicy};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+    ///
+    /// // Sum the neighbors on a ring.
+    /// let grid = grid.relax_step_with_border(Neighborhood::Four, BorderPolicy::Wrap, |_, neighbors| {
+    ///     neighbors.iter().sum()
+    /// });
+    ///
+    /// assert_eq!(grid, Grid::from_rows(vec![vec![7, 8, 9]]));
+    /// ```
+    ///
+    pub fn relax_step_with_border<F>(&self, neighborhood: Neighborhood, border: BorderPolicy<T>, function: F) -> Grid<T>
+        where F: FnMut(T, &[T]) -> T
+    {
+        let mut rows = self.row_vectors().to_vec();
+        self.relax_into(neighborhood, &border, function, &mut rows);
+
+        Grid::from_row_vectors(self.size().width, rows)
+    }
+
+    /// Relax the grid in place.
+    ///
+    /// This method runs a number of relaxation steps on the grid, in place.
+    /// Each step computes the elements from the elements of the previous step,
+    /// just like `relax_step()`, but the intermediate grids share a single
+    /// buffer instead of being allocated at each step. Use
+    /// `relax_with_border()` to handle the borders differently.
+    ///
+    /// # Arguments
+    ///
+    /// * `neighborhood` - The neighbors of each element
+    /// * `iterations` - The number of steps
+    /// * `function` - The function computing an element from its neighbors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, Neighborhood};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![9, 0, 0, 0, 0]]);
+    ///
+    /// // Spread the highest value by one element at each step.
+    /// grid.relax(Neighborhood::Four, 2, |center, neighbors| {
+    ///     neighbors.iter().fold(center, |a, &b| a.max(b))
+    /// });
+    ///
+    /// assert_eq!(grid.row(0), [9, 9, 9, 0, 0]);
+    /// ```
+    ///
+    pub fn relax<F>(&mut self, neighborhood: Neighborhood, iterations: usize, function: F)
+        where F: FnMut(T, &[T]) -> T
+    {
+        self.relax_with_border(neighborhood, BorderPolicy::Skip, iterations, function)
+    }
+
+    /// Relax the grid in place, with a border policy.
+    ///
+    /// This method is similar to `relax()`, but the neighbors outside of the
+    /// grid are given by a border policy, just like `relax_step_with_border()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `neighborhood` - The neighbors of each element
+    /// * `border` - The policy for the elements outside of the grid
+    /// * `iterations` - The number of steps
+    /// * `function` - The function computing an element from its neighbors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, Neighborhood, BorderPolicy};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![0.0f64, 0.0, 0.0, 0.0]]);
+    ///
+    /// // Spread the highest value, the outside of the grid being a constant.
+    /// grid.relax_with_border(Neighborhood::Four, BorderPolicy::Constant(8.0), 1, |center, neighbors| {
+    ///     neighbors.iter().fold(center, |a, &b| a.max(b))
+    /// });
+    ///
+    /// assert_eq!(grid.row(0), [8.0, 8.0, 8.0, 8.0]);
+    /// ```
+    ///
+    pub fn relax_with_border<F>(&mut self, neighborhood: Neighborhood, border: BorderPolicy<T>, iterations: usize, mut function: F)
+        where F: FnMut(T, &[T]) -> T
+    {
+        let mut buffer = self.row_vectors().to_vec();
+
+        for _ in 0..iterations {
+            self.relax_into(neighborhood, &border, &mut function, &mut buffer);
+
+            for (row, relaxed) in self.row_vectors_mut().iter_mut().zip(buffer.iter()) {
+                row.copy_from_slice(relaxed);
+            }
+        }
+    }
+
+    // Compute a relaxation step into rows that have the size of the grid.
+    fn relax_into<F>(&self, neighborhood: Neighborhood, border: &BorderPolicy<T>, mut function: F, output: &mut [Vec<T>])
+        where F: FnMut(T, &[T]) -> T
+    {
+        let (width, height) = (self.size().width, self.size().height);
+        let mut neighbors = Vec::with_capacity(8);
+
+        for (y, row) in output.iter_mut().enumerate() {
+            for (x, element) in row.iter_mut().enumerate() {
+                neighbors.clear();
+
+                for dy in -1..=1 {
+                    for dx in -1..=1 {
+                        let diagonal = dx != 0 && dy != 0;
+                        let center = dx == 0 && dy == 0;
+
+                        if center || (diagonal && neighborhood == Neighborhood::Four) {
+                            continue;
+                        }
+
+                        let ny = border.locate(y as isize + dy, height);
+                        let nx = border.locate(x as isize + dx, width);
+
+                        if (nx.is_some() && ny.is_some()) || border.constant().is_some() {
+                            neighbors.push(*border.element(self, nx, ny));
+                        }
+                    }
+                }
+
+                *element = function(self.row_slice(y)[x], &neighbors);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_relax_step() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let sums = grid.relax_step(Neighborhood::Four, |_, neighbors| neighbors.iter().sum());
+        assert_eq!(sums, Grid::from_rows(vec![vec![ 6,  9,  8],
+                                              vec![ 6, 12,  8]]));
+
+        let sums = grid.relax_step(Neighborhood::Eight, |_, neighbors| neighbors.iter().sum());
+        assert_eq!(sums, Grid::from_rows(vec![vec![11, 19, 13],
+                                              vec![ 8, 16, 10]]));
+
+        let mut order = Vec::new();
+        grid.relax_step(Neighborhood::Eight, |center, neighbors| {
+            if center == 5 {
+                order.extend_from_slice(neighbors);
+            }
+            center
+        });
+        assert_eq!(order, vec![1, 2, 3, 4, 6]);
+
+        assert_eq!(Grid::<f64>::zero().relax_step(Neighborhood::Four, |center, _| center),
+                   Grid::zero());
+    }
+
+    #[test]
+    fn grid_relax() {
+        let mut grid = Grid::from_rows(vec![vec![0, 0, 0, 0]]);
+
+        // Running steps in place matches running them one after the other.
+        let mut steps = grid.relax_step(Neighborhood::Four, |center, _| center + 1);
+        grid.relax(Neighborhood::Four, 1, |center, _| center + 1);
+        assert_eq!(grid, steps);
+
+        grid.relax(Neighborhood::Four, 2, |center, neighbors| center.max(*neighbors.iter().max().unwrap()) + 1);
+        for _ in 0..2 {
+            steps = steps.relax_step(Neighborhood::Four, |center, neighbors| center.max(*neighbors.iter().max().unwrap()) + 1);
+        }
+        assert_eq!(grid, steps);
+
+        let mut grid = Grid::from_rows(vec![vec![0.0, 4.0, 0.0]]);
+        grid.relax(Neighborhood::Four, 0, |_, _| 1.0);
+        assert_eq!(grid, Grid::from_rows(vec![vec![0.0, 4.0, 0.0]]));
+
+        grid.relax(Neighborhood::Four, 1, |_, neighbors| neighbors.iter().sum::<f64>() / neighbors.len() as f64);
+        assert_eq!(grid, Grid::from_rows(vec![vec![4.0, 0.0, 4.0]]));
+    }
+
+    #[test]
+    fn grid_relax_step_with_border() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let mut order = Vec::new();
+        grid.relax_step_with_border(Neighborhood::Eight, BorderPolicy::Clamp, |center, neighbors| {
+            if center == 3 {
+                order.extend_from_slice(neighbors);
+            }
+            center
+        });
+        assert_eq!(order, vec![2, 3, 3, 2, 3, 5, 6, 6]);
+
+        let sums = grid.relax_step_with_border(Neighborhood::Four, BorderPolicy::Mirror, |_, neighbors| neighbors.iter().sum());
+        assert_eq!(sums, Grid::from_rows(vec![vec![12, 14, 16],
+                                              vec![12, 14, 16]]));
+
+        let counts = grid.relax_step_with_border(Neighborhood::Eight, BorderPolicy::Constant(0), |_, neighbors| neighbors.len() as i32);
+        assert_eq!(counts, Grid::with_size(grid.size(), 8));
+
+        let sums = grid.relax_step_with_border(Neighborhood::Eight, BorderPolicy::Skip, |_, neighbors| neighbors.iter().sum());
+        assert_eq!(sums, grid.relax_step(Neighborhood::Eight, |_, neighbors| neighbors.iter().sum()));
+
+        // Relaxing in place matches running the steps one after the other.
+        let mut relaxed = Grid::from_rows(vec![vec![1, 2, 3],
+                                               vec![4, 5, 6]]);
+        relaxed.relax_with_border(Neighborhood::Four, BorderPolicy::Wrap, 2, |center, neighbors| center + neighbors[0]);
+
+        let mut steps = grid;
+        for _ in 0..2 {
+            steps = steps.relax_step_with_border(Neighborhood::Four, BorderPolicy::Wrap, |center, neighbors| center + neighbors[0]);
+        }
+        assert_eq!(relaxed, steps);
+    }
+}
@@ -6,11 +6,12 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
-use std::ops::Index;
+use core::ops::{Index, RangeBounds};
+use alloc::vec::Vec;
 use crate::coordinate::Coordinate;
-use crate::grid::Grid;
+use crate::grid::{Grid, range_limits};
+use crate::row_segment::RowSegment;
 use crate::iterator_row::IteratorRow;
-use crate::coord;
 
 /// A view onto a row of a grid
 ///
@@ -61,6 +62,18 @@ use crate::coord;
 /// println!("Last element of last row is {}", grid.row(1)[2]);
 /// ```
 ///
+/// Comparing the elements with an array, a slice or a vector.
+///
+/// ```
+/// # use ingrid::Grid;
+/// #
+/// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+///                                 vec![4, 5, 6]]);
+///
+/// assert_eq!(grid.row(0), [1, 2, 3]);
+/// assert_eq!(grid.row(0), vec![1, 2, 3]);
+/// ```
+///
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Row<'a, T> {
     /// A reference to its grid.
@@ -148,6 +161,48 @@ impl<'a, T: Clone> Row<'a, T> {
         self.iterator().collect()
     }
 
+    /// Return the elements of the row as an owned vector.
+    ///
+    /// This method returns the elements of the row as a vector of cloned
+    /// values, unlike `values()` which returns a vector of references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                 vec![3, 4]]);
+    ///
+    /// assert_eq!(grid.row(0).to_vec(), vec![1, 2]);
+    /// assert_eq!(grid.row(1).to_vec(), vec![3, 4]);
+    /// ```
+    ///
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iterator().cloned().collect()
+    }
+
+    /// Return the elements of the row as a slice.
+    ///
+    /// This method returns the elements of the row as a slice. Unlike columns,
+    /// the elements of a row are contiguous in memory, which allows to use the
+    /// slice primitive type and all of its operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.row(1).as_slice(), &[4, 5, 6]);
+    /// ```
+    ///
+    pub fn as_slice(&self) -> &'a [T] {
+        self.grid.row_slice(self.index)
+    }
+
     /// Returns a reference to the first element of the row.
     ///
     /// This method returns a reference to the first element of the row. It's
@@ -271,6 +326,36 @@ impl<'a, T: Clone> Row<'a, T> {
             Some(self.grid.row(self.index + 1))
         }
     }
+
+    /// Returns a view onto a part of the row.
+    ///
+    /// This method returns a segment of the row, which is a view onto a range
+    /// of its elements with the same API as the row.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range of the elements
+    ///
+    /// # Panics
+    ///
+    /// It panics if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3, 4],
+    ///                                 vec![5, 6, 7, 8]]);
+    ///
+    /// assert_eq!(grid.row(1).segment(1..3), [6, 7]);
+    /// assert_eq!(grid.row(0).segment(2..), [3, 4]);
+    /// ```
+    ///
+    pub fn segment<R: RangeBounds<usize>>(&self, range: R) -> RowSegment<'a, T> {
+        let (start, end) = range_limits(range, self.length());
+        RowSegment::new(self.clone(), start, end)
+    }
 }
 
 impl<'a, T: Clone> Index<usize> for Row<'a, T> {
@@ -281,6 +366,25 @@ impl<'a, T: Clone> Index<usize> for Row<'a, T> {
     }
 }
 
+impl<'a, T: Clone + PartialEq> PartialEq<[T]> for Row<'a, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.length() == other.len() &&
+        other.iter().enumerate().all(|(index, value)| self.value(index) == value)
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<Vec<T>> for Row<'a, T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<'a, T: Clone + PartialEq, const N: usize> PartialEq<[T; N]> for Row<'a, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -328,7 +432,25 @@ mod tests {
         assert_eq!(row[0], 3);
         assert_eq!(row[1], 4);
 
-        row[2];
+        let _ = row[2];
+    }
+
+    #[test]
+    fn row_to_vec() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        assert_eq!(grid.row(0).to_vec(), vec![1, 2, 3]);
+        assert_eq!(grid.row(1).to_vec(), vec![4, 5, 6]);
+    }
+
+    #[test]
+    fn row_as_slice() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        assert_eq!(grid.row(0).as_slice(), &[1, 2, 3]);
+        assert_eq!(grid.row(1).as_slice(), &[4, 5, 6]);
     }
 
     #[test]
@@ -408,4 +530,18 @@ mod tests {
 
         assert!(last_row.bottom().is_none());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn row_eq() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.row(1), vec![4, 5, 6]);
+        assert_eq!(grid.row(1), [4, 5, 6][..]);
+
+        assert_ne!(grid.row(1), [4, 5]);
+        assert_ne!(grid.row(1), vec![4, 5, 6, 7]);
+        assert_ne!(grid.row(1), [4, 0, 6][..]);
+    }
+}
@@ -6,12 +6,14 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
-use std::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut};
+use core::cmp::Ordering;
+use alloc::vec::Vec;
 use crate::coordinate::Coordinate;
 use crate::grid::Grid;
 use crate::row::Row;
 use crate::iterator_row::IteratorRow;
-use crate::coord;
+use crate::row_segment_mut::RowSegmentMut;
 
 /// A mutable view onto a row of a grid
 ///
@@ -487,6 +489,116 @@ impl<'a, T: Clone> RowMut<'a, T> {
         }
     }
 
+    /// Return the elements of the row as a slice.
+    ///
+    /// This method returns the elements of the row as a slice. Unlike columns,
+    /// the elements of a row are contiguous in memory, which allows to use the
+    /// slice primitive type and all of its operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![4, 5, 6]]);
+    ///
+    /// let row = grid.row_mut(1);
+    /// assert_eq!(row.as_slice(), &[4, 5, 6]);
+    /// ```
+    ///
+    pub fn as_slice(&self) -> &[T] {
+        self.grid.row_slice(self.index)
+    }
+
+    /// Return the elements of the row as a mutable slice.
+    ///
+    /// This method returns the elements of the row as a mutable slice. Unlike
+    /// columns, the elements of a row are contiguous in memory, which allows to
+    /// use the slice primitive type and all of its operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![6, 4, 5]]);
+    ///
+    /// let mut row = grid.row_mut(1);
+    /// row.as_mut_slice().sort();
+    ///
+    /// assert_eq!(row.as_slice(), &[4, 5, 6]);
+    /// ```
+    ///
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.grid.row_slice_mut(self.index)
+    }
+
+    /// Fill the row with a given value.
+    ///
+    /// This method fills the row with a given value that is cloned for all the
+    /// elements.
+    ///
+    /// Note that it's similar to the `fill()` method of the slice primitive
+    /// type.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to fill the row with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![4, 5, 6]]);
+    ///
+    /// grid.row_mut(1).fill(42);
+    ///
+    /// assert_eq!(grid.row(0), [1, 2, 3]);
+    /// assert_eq!(grid.row(1), [42, 42, 42]);
+    /// ```
+    ///
+    pub fn fill(&mut self, value: T) {
+        self.as_mut_slice().fill(value);
+    }
+
+    /// Copy the elements of a slice into the row.
+    ///
+    /// This method copies all the elements of a slice into the row, replacing
+    /// its elements. The length of the slice must be the same as the length of
+    /// the row.
+    ///
+    /// Note that it's similar to the `copy_from_slice()` method of the slice
+    /// primitive type.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The slice to copy the elements from
+    ///
+    /// # Panics
+    ///
+    /// It panics if the length of the slice doesn't equal the length of the
+    /// row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![0, 0, 0]]);
+    ///
+    /// grid.row_mut(1).copy_from_slice(&[4, 5, 6]);
+    /// assert_eq!(grid.row(1), [4, 5, 6]);
+    /// ```
+    ///
+    pub fn copy_from_slice(&mut self, source: &[T]) where T: Copy {
+        self.as_mut_slice().copy_from_slice(source);
+    }
+
     /// Reverse the order of the elements.
     ///
     /// This method reverses the order of the elements in the row, in place.
@@ -511,7 +623,7 @@ impl<'a, T: Clone> RowMut<'a, T> {
     /// ```
     ///
     pub fn reverse(&mut self) {
-        self.grid.row_slice(self.index).reverse();
+        self.as_mut_slice().reverse();
     }
 
     /// Rotate elements to the left.
@@ -549,7 +661,7 @@ impl<'a, T: Clone> RowMut<'a, T> {
     /// ```
     ///
     pub fn rotate_left(&mut self, number: usize) {
-        self.grid.row_slice(self.index).rotate_left(number);
+        self.as_mut_slice().rotate_left(number);
     }
 
     /// Rotate elements to the right.
@@ -587,7 +699,7 @@ impl<'a, T: Clone> RowMut<'a, T> {
     /// ```
     ///
     pub fn rotate_right(&mut self, number: usize) {
-        self.grid.row_slice(self.index).rotate_right(number);
+        self.as_mut_slice().rotate_right(number);
     }
 
     /// Swap two elements in the row.
@@ -623,7 +735,135 @@ impl<'a, T: Clone> RowMut<'a, T> {
     /// ```
     ///
     pub fn swap(&mut self, a: usize, b: usize) {
-        self.grid.row_slice(self.index).swap(a, b);
+        self.as_mut_slice().swap(a, b);
+    }
+
+    /// Swap the elements of the row with the elements of a slice.
+    ///
+    /// This method swaps all the elements of the row with the elements of a
+    /// given slice. The length of the slice must be the same as the length of
+    /// the row.
+    ///
+    /// Note that it's similar to the `swap_with_slice()` method of the slice
+    /// primitive type.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The slice to swap the elements with
+    ///
+    /// # Panics
+    ///
+    /// It panics if the length of the slice doesn't equal the length of the
+    /// row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![0, 0, 0]]);
+    ///
+    /// let mut buffer = [4, 5, 6];
+    /// grid.row_mut(1).swap_with_slice(&mut buffer);
+    ///
+    /// assert_eq!(grid.row(1), [4, 5, 6]);
+    /// assert_eq!(buffer, [0, 0, 0]);
+    /// ```
+    ///
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        self.as_mut_slice().swap_with_slice(other);
+    }
+
+    /// Sort the elements.
+    ///
+    /// This method sorts the elements of the row in ascending order, in place.
+    /// The sort is stable.
+    ///
+    /// Note that it's similar to the `sort()` method of the slice primitive
+    /// type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![6, 4, 5]]);
+    ///
+    /// grid.row_mut(1).sort();
+    /// assert_eq!(grid.row(1), [4, 5, 6]);
+    /// ```
+    ///
+    pub fn sort(&mut self) where T: Ord {
+        self.as_mut_slice().sort();
+    }
+
+    /// Sort the elements with a comparator function.
+    ///
+    /// This method sorts the elements of the row in the order defined by a
+    /// comparator function, in place. The sort is stable.
+    ///
+    /// Note that it's similar to the `sort_by()` method of the slice primitive
+    /// type.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - The function comparing two elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                     vec![6, 4, 5]]);
+    ///
+    /// grid.row_mut(0).sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(grid.row(0), [3, 2, 1]);
+    /// ```
+    ///
+    pub fn sort_by<F>(&mut self, compare: F) where F: FnMut(&T, &T) -> Ordering {
+        self.as_mut_slice().sort_by(compare);
+    }
+
+    /// Divide the row into two mutable segments at an index.
+    ///
+    /// This method divides the row into two disjoint segments that can be
+    /// modified at the same time; the first one contains the elements before
+    /// the index, the second one contains the others.
+    ///
+    /// Note that it's similar to the `split_at_mut()` method of the slice
+    /// primitive type.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index to divide the row at
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is greater than the length of the row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3, 4]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (mut left, mut right) = row.split_at_mut(2);
+    /// left.fill(0);
+    /// right.reverse();
+    ///
+    /// assert_eq!(grid.row(0), [0, 0, 4, 3]);
+    /// ```
+    ///
+    pub fn split_at_mut(&mut self, index: usize) -> (RowSegmentMut<'_, T>, RowSegmentMut<'_, T>) {
+        assert!(index <= self.length(), "index out of bounds");
+
+        let (left, right) = self.as_mut_slice().split_at_mut(index);
+        (RowSegmentMut::new(left, 0), RowSegmentMut::new(right, index))
     }
 }
 
@@ -641,6 +881,25 @@ impl<'a, T: Clone> IndexMut<usize> for RowMut<'a, T> {
     }
 }
 
+impl<'a, T: Clone + PartialEq> PartialEq<[T]> for RowMut<'a, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.length() == other.len() &&
+        other.iter().enumerate().all(|(index, value)| self.value(index) == value)
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<Vec<T>> for RowMut<'a, T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<'a, T: Clone + PartialEq, const N: usize> PartialEq<[T; N]> for RowMut<'a, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -742,7 +1001,7 @@ mod tests {
         assert_eq!(row[0], 3);
         assert_eq!(row[1], 4);
 
-        row[2];
+        let _ = row[2];
     }
 
     #[test]
@@ -754,15 +1013,15 @@ mod tests {
         let mut row = grid.row_mut(1);
         row[1] = 4;
 
-        let mut row = grid.row_mut(0);
+        let row = grid.row_mut(0);
         assert_eq!(row[0], 1);
         assert_eq!(row[1], 2);
 
-        let mut row = grid.row_mut(1);
+        let row = grid.row_mut(1);
         assert_eq!(row[0], 3);
         assert_eq!(row[1], 4);
 
-        row[2];
+        let _ = row[2];
     }
 
     #[test]
@@ -907,6 +1166,54 @@ mod tests {
         assert!(last_row.bottom_mut().is_none());
     }
 
+    #[test]
+    fn row_as_slice() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6]]);
+
+        assert_eq!(grid.row_mut(0).as_slice(), &[1, 2, 3]);
+        assert_eq!(grid.row_mut(1).as_slice(), &[4, 5, 6]);
+    }
+
+    #[test]
+    fn row_as_mut_slice() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6]]);
+
+        grid.row_mut(0).as_mut_slice()[1] = 42;
+        grid.row_mut(1).as_mut_slice().reverse();
+
+        assert_eq!(grid.row(0), [1, 42, 3]);
+        assert_eq!(grid.row(1), [6, 5, 4]);
+    }
+
+    #[test]
+    fn row_fill() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6],
+                                            vec![7, 8, 9]]);
+
+        grid.row_mut(1).fill(0);
+
+        assert_eq!(grid.row(0), [1, 2, 3]);
+        assert_eq!(grid.row(1), [0, 0, 0]);
+        assert_eq!(grid.row(2), [7, 8, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match destination slice length")]
+    fn row_copy_from_slice() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![0, 0, 0]]);
+
+        grid.row_mut(1).copy_from_slice(&[4, 5, 6]);
+
+        assert_eq!(grid.row(0), [1, 2, 3]);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+
+        grid.row_mut(1).copy_from_slice(&[7, 8]);
+    }
+
     #[test]
     fn row_reverse() {
         let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
@@ -1014,4 +1321,85 @@ mod tests {
         assert_eq!(grid.row(1).values(), vec!(&5, &4, &6));
         assert_eq!(grid.row(2).values(), vec!(&7, &9, &8));
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[should_panic(expected = "destination and source slices have different lengths")]
+    fn row_swap_with_slice() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6]]);
+
+        let mut buffer = vec![7, 8, 9];
+        grid.row_mut(0).swap_with_slice(&mut buffer);
+
+        assert_eq!(grid.row(0), [7, 8, 9]);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(buffer, vec![1, 2, 3]);
+
+        grid.row_mut(1).swap_with_slice(&mut buffer[..2]);
+    }
+
+    #[test]
+    fn row_eq() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                            vec![4, 5, 6]]);
+
+        assert_eq!(grid.row_mut(1), [4, 5, 6]);
+        assert_eq!(grid.row_mut(1), vec![4, 5, 6]);
+        assert_eq!(grid.row_mut(1), [4, 5, 6][..]);
+
+        assert_ne!(grid.row_mut(1), [4, 5]);
+        assert_ne!(grid.row_mut(1), vec![4, 5, 6, 7]);
+        assert_ne!(grid.row_mut(1), [4, 0, 6][..]);
+    }
+
+    #[test]
+    fn row_sort() {
+        let mut grid = Grid::from_rows(vec![vec![3, 1, 2],
+                                            vec![9, 7, 8]]);
+
+        grid.row_mut(0).sort();
+        assert_eq!(grid.row(0), [1, 2, 3]);
+        assert_eq!(grid.row(1), [9, 7, 8]);
+
+        grid.row_mut(1).sort_by(|a, b| b.cmp(a));
+        assert_eq!(grid.row(1), [9, 8, 7]);
+
+        // The sort is stable.
+        let mut grid = Grid::from_rows(vec![vec![(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')]]);
+
+        grid.row_mut(0).sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(grid.row(0), [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+    }
+
+    #[test]
+    fn row_split_at_mut() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3, 4],
+                                            vec![5, 6, 7, 8]]);
+
+        let mut row = grid.row_mut(1);
+        let (mut left, mut right) = row.split_at_mut(1);
+        assert_eq!(left.range(), 0..1);
+        assert_eq!(right.range(), 1..4);
+        assert_eq!(left, [5]);
+        assert_eq!(right, vec![6, 7, 8]);
+
+        // Two-pointer swap across both segments.
+        core::mem::swap(&mut left[0], &mut right[2]);
+        right.set_value(0, 0);
+        right.swap(1, 2);
+        assert_eq!(grid.row(0), [1, 2, 3, 4]);
+        assert_eq!(grid.row(1), [8, 0, 5, 7]);
+
+        let mut row = grid.row_mut(0);
+        let (left, right) = row.split_at_mut(4);
+        assert_eq!(left.length(), 4);
+        assert_eq!(right.length(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn row_split_at_mut_out_of_bounds() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+        grid.row_mut(0).split_at_mut(4);
+    }
+}
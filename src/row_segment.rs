@@ -0,0 +1,307 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::ops::{Index, Range};
+use alloc::vec::Vec;
+use crate::row::Row;
+use crate::iterator_row::IteratorRow;
+
+/// A view onto a part of a row of a grid
+///
+/// This structure is an **immutable** view into a range of elements of a row,
+/// for instance "columns 3 to 7 of row 2". It's created by the `segment()`
+/// method of the rows and it has the same API as the rows, except that the
+/// index zero corresponds to the first element of the range.
+///
+/// Note that iterating over a segment yields the coordinates of the elements
+/// in the grid, not in the segment.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::Grid;
+/// #
+/// let grid = Grid::from_rows(vec![vec![1, 2, 3, 4],
+///                                 vec![5, 6, 7, 8]]);
+///
+/// let segment = grid.row(1).segment(1..3);
+/// assert_eq!(segment.length(), 2);
+/// assert_eq!(segment[0], 6);
+/// assert_eq!(segment, [6, 7]);
+/// ```
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RowSegment<'a, T> {
+    row: Row<'a, T>,
+    start: usize,
+    end: usize
+}
+
+impl<'a, T: Clone> RowSegment<'a, T> {
+    pub(crate) fn new(row: Row<'a, T>, start: usize, end: usize) -> RowSegment<'a, T> {
+        RowSegment { row, start, end }
+    }
+
+    /// Return the row of the segment.
+    ///
+    /// This method returns the whole row the segment is part of.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.row(1).segment(1..).row().index, 1);
+    /// ```
+    ///
+    pub fn row(&self) -> Row<'a, T> {
+        self.row.clone()
+    }
+
+    /// Return the range of the segment.
+    ///
+    /// This method returns the range of the segment within its row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.row(0).segment(1..).range(), 1..3);
+    /// ```
+    ///
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the length of the segment.
+    ///
+    /// This method returns the number of elements of the segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.row(0).segment(..2).length(), 2);
+    /// assert_eq!(grid.row(0).segment(1..1).length(), 0);
+    /// ```
+    ///
+    pub fn length(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns a reference to an element of the segment.
+    ///
+    /// This method returns a reference to an element of the segment from its
+    /// index; index zero corresponds to the first element of the segment.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// let segment = grid.row(1).segment(1..);
+    /// assert_eq!(segment.value(0), &5);
+    /// assert_eq!(segment.value(1), &6);
+    /// segment.value(2); // It panics here !
+    /// ```
+    ///
+    pub fn value(&self, index: usize) -> &'a T {
+        assert!(index < self.length(), "index out of bounds");
+        self.row.value(self.start + index)
+    }
+
+    /// Return the elements of the segment.
+    ///
+    /// This method returns the elements of the segment as a vector of
+    /// reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.row(1).segment(..2).values(), vec![&4, &5]);
+    /// ```
+    ///
+    pub fn values(&self) -> Vec<&T> {
+        self.iterator().collect()
+    }
+
+    /// Return the elements of the segment as an owned vector.
+    ///
+    /// This method returns the elements of the segment as a vector of cloned
+    /// values, unlike `values()` which returns a vector of references.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.row(1).segment(..2).to_vec(), vec![4, 5]);
+    /// ```
+    ///
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iterator().cloned().collect()
+    }
+
+    /// Return the elements of the segment as a slice.
+    ///
+    /// This method returns the elements of the segment as a slice, as the
+    /// elements of a row are contiguous in memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// assert_eq!(grid.row(1).segment(1..).as_slice(), &[5, 6]);
+    /// ```
+    ///
+    pub fn as_slice(&self) -> &'a [T] {
+        &self.row.as_slice()[self.start..self.end]
+    }
+
+    /// Returns an iterator over the segment.
+    ///
+    /// This method returns an iterator over the elements of the segment. The
+    /// coordinates it reports are the coordinates of the elements in the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, GridIterator, coord};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// let mut iterator = grid.row(1).segment(1..).iterator().enumerate_coordinate();
+    /// assert_eq!(iterator.next(), Some((coord!(1, 1), &5)));
+    /// assert_eq!(iterator.next(), Some((coord!(2, 1), &6)));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    ///
+    pub fn iterator(&self) -> IteratorRow<'a, T> {
+        IteratorRow::with_range(self.row.clone(), self.start, self.end)
+    }
+}
+
+impl<'a, T: Clone> Index<usize> for RowSegment<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.value(index)
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<[T]> for RowSegment<'a, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<Vec<T>> for RowSegment<'a, T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<'a, T: Clone + PartialEq, const N: usize> PartialEq<[T; N]> for RowSegment<'a, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Grid;
+    use crate::coordinate::Coordinate;
+    use crate::grid_iterator::GridIterator;
+
+    #[test]
+    fn row_segment() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3, 4],
+                                        vec![5, 6, 7, 8]]);
+
+        let segment = grid.row(1).segment(1..3);
+        assert_eq!(segment.range(), 1..3);
+        assert_eq!(segment.length(), 2);
+        assert_eq!(segment.value(0), &6);
+        assert_eq!(segment[1], 7);
+        assert_eq!(segment.values(), vec![&6, &7]);
+        assert_eq!(segment.to_vec(), vec![6, 7]);
+        assert_eq!(segment.as_slice(), &[6, 7]);
+        assert_eq!(segment, [6, 7]);
+        assert_eq!(segment, vec![6, 7]);
+
+        assert_eq!(grid.row(0).segment(..).to_vec(), vec![1, 2, 3, 4]);
+        assert_eq!(grid.row(0).segment(..=1).to_vec(), vec![1, 2]);
+        assert_eq!(grid.row(0).segment(4..).length(), 0);
+    }
+
+    #[test]
+    fn row_segment_iterator() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3, 4],
+                                        vec![5, 6, 7, 8]]);
+
+        let mut iterator = grid.row(0).segment(1..3).iterator();
+        assert_eq!(iterator.len(), 2);
+        assert_eq!(iterator.coordinate(), coord!(1, 0));
+        assert_eq!(iterator.next(), Some(&2));
+        assert_eq!(iterator.next_back(), Some(&3));
+        assert_eq!(iterator.next(), None);
+
+        let values = grid.row(1).segment(2..).iterator().rev().collect::<Vec<_>>();
+        assert_eq!(values, vec![&8, &7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn row_segment_value_out_of_bounds() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+        grid.row(0).segment(0..2).value(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn row_segment_out_of_bounds() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+        grid.row(0).segment(1..4);
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::ops::{Index, IndexMut, Range};
+use alloc::vec::Vec;
+
+/// A mutable view onto a part of a row of a grid
+///
+/// This structure is a **mutable** view into a range of elements of a row.
+/// It's created by the `split_at_mut()` method of the mutable rows, which
+/// splits a row into two disjoint segments that can be modified at the same
+/// time, just like the slice primitive type does. Index zero corresponds to
+/// the first element of the segment.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::Grid;
+/// #
+/// let mut grid = Grid::from_rows(vec![vec![1, 2, 3, 4]]);
+///
+/// let mut row = grid.row_mut(0);
+/// let (mut left, mut right) = row.split_at_mut(2);
+/// core::mem::swap(&mut left[0], &mut right[1]);
+///
+/// assert_eq!(grid.row(0), [4, 2, 3, 1]);
+/// ```
+///
+#[derive(Debug, Eq, PartialEq)]
+pub struct RowSegmentMut<'a, T> {
+    elements: &'a mut [T],
+    start: usize
+}
+
+impl<'a, T: Clone> RowSegmentMut<'a, T> {
+    pub(crate) fn new(elements: &'a mut [T], start: usize) -> RowSegmentMut<'a, T> {
+        RowSegmentMut { elements, start }
+    }
+
+    /// Return the range of the segment.
+    ///
+    /// This method returns the range of the segment within its row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (left, right) = row.split_at_mut(1);
+    /// assert_eq!(left.range(), 0..1);
+    /// assert_eq!(right.range(), 1..3);
+    /// ```
+    ///
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.start + self.elements.len()
+    }
+
+    /// Returns the length of the segment.
+    ///
+    /// This method returns the number of elements of the segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (left, right) = row.split_at_mut(1);
+    /// assert_eq!(left.length(), 1);
+    /// assert_eq!(right.length(), 2);
+    /// ```
+    ///
+    pub fn length(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns a reference to an element of the segment.
+    ///
+    /// This method returns a reference to an element of the segment from its
+    /// index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (_, right) = row.split_at_mut(1);
+    /// assert_eq!(right.value(0), &2);
+    /// ```
+    ///
+    pub fn value(&self, index: usize) -> &T {
+        assert!(index < self.length(), "index out of bounds");
+        &self.elements[index]
+    }
+
+    /// Returns a mutable reference to an element of the segment.
+    ///
+    /// This method returns a mutable reference to an element of the segment
+    /// from its index.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (_, mut right) = row.split_at_mut(1);
+    /// *right.value_mut(0) = 42;
+    ///
+    /// assert_eq!(grid.row(0), [1, 42, 3]);
+    /// ```
+    ///
+    pub fn value_mut(&mut self, index: usize) -> &mut T {
+        assert!(index < self.length(), "index out of bounds");
+        &mut self.elements[index]
+    }
+
+    /// Replace an element of the segment.
+    ///
+    /// This method replaces the value of an element of the segment from its
+    /// index and a new value.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the element
+    /// * `value` - New value of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (_, mut right) = row.split_at_mut(1);
+    /// right.set_value(1, 42);
+    ///
+    /// assert_eq!(grid.row(0), [1, 2, 42]);
+    /// ```
+    ///
+    pub fn set_value(&mut self, index: usize, value: T) {
+        *self.value_mut(index) = value;
+    }
+
+    /// Return the elements of the segment.
+    ///
+    /// This method returns the elements of the segment as a vector of
+    /// reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (left, right) = row.split_at_mut(1);
+    /// assert_eq!(left.values(), vec![&1]);
+    /// assert_eq!(right.values(), vec![&2, &3]);
+    /// ```
+    ///
+    pub fn values(&self) -> Vec<&T> {
+        self.elements.iter().collect()
+    }
+
+    /// Return the elements of the segment as a slice.
+    ///
+    /// This method returns the elements of the segment as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (_, right) = row.split_at_mut(1);
+    /// assert_eq!(right.as_slice(), &[2, 3]);
+    /// ```
+    ///
+    pub fn as_slice(&self) -> &[T] {
+        self.elements
+    }
+
+    /// Return the elements of the segment as a mutable slice.
+    ///
+    /// This method returns the elements of the segment as a mutable slice,
+    /// which allows to use the slice primitive type and all of its operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 3, 2]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (_, mut right) = row.split_at_mut(1);
+    /// right.as_mut_slice().sort();
+    ///
+    /// assert_eq!(grid.row(0), [1, 2, 3]);
+    /// ```
+    ///
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.elements
+    }
+
+    /// Fill the segment with a given value.
+    ///
+    /// This method fills the segment with a given value that is cloned for
+    /// all the elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to fill the segment with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (mut left, mut right) = row.split_at_mut(1);
+    /// left.fill(0);
+    /// right.fill(42);
+    ///
+    /// assert_eq!(grid.row(0), [0, 42, 42]);
+    /// ```
+    ///
+    pub fn fill(&mut self, value: T) {
+        self.elements.fill(value);
+    }
+
+    /// Reverse the order of elements in the segment.
+    ///
+    /// This method reverses the order of the elements of the segment,
+    /// in-place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (_, mut right) = row.split_at_mut(1);
+    /// right.reverse();
+    ///
+    /// assert_eq!(grid.row(0), [1, 3, 2]);
+    /// ```
+    ///
+    pub fn reverse(&mut self) {
+        self.elements.reverse();
+    }
+
+    /// Swap two elements in the segment.
+    ///
+    /// This method swaps two elements of the segment from their index.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The index of the first element
+    /// * `b` - The index of the second element
+    ///
+    /// # Panics
+    ///
+    /// It panics if `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec![1, 2, 3]]);
+    ///
+    /// let mut row = grid.row_mut(0);
+    /// let (_, mut right) = row.split_at_mut(1);
+    /// right.swap(0, 1);
+    ///
+    /// assert_eq!(grid.row(0), [1, 3, 2]);
+    /// ```
+    ///
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.length() && b < self.length(), "index out of bounds");
+        self.elements.swap(a, b);
+    }
+}
+
+impl<'a, T: Clone> Index<usize> for RowSegmentMut<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.value(index)
+    }
+}
+
+impl<'a, T: Clone> IndexMut<usize> for RowSegmentMut<'a, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.value_mut(index)
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<[T]> for RowSegmentMut<'a, T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<'a, T: Clone + PartialEq> PartialEq<Vec<T>> for RowSegmentMut<'a, T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<'a, T: Clone + PartialEq, const N: usize> PartialEq<[T; N]> for RowSegmentMut<'a, T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        *self == other[..]
+    }
+}
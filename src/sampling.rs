@@ -0,0 +1,386 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+
+/// The methods to interpolate grids
+///
+/// This enumeration lists the methods to compute the value of a grid between
+/// its elements, when resampling it for instance.
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Interpolation {
+    /// The value of the nearest element.
+    Nearest,
+
+    /// The value interpolated linearly from the four surrounding elements.
+    Bilinear
+}
+
+impl Grid<f32> {
+
+    /// Sample the grid at a sub-element position with bilinear interpolation.
+    ///
+    /// This method returns the value of the grid at an arbitrary position,
+    /// interpolated from the four surrounding elements. Elements are located
+    /// at integer positions, therefore, sampling at (1.0, 2.0) returns the
+    /// element at coordinate (1, 2), and sampling at (1.5, 2.0) returns the
+    /// average of the elements at coordinates (1, 2) and (2, 2).
+    ///
+    /// Positions outside of the grid are clamped to its edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The horizontal position
+    /// * `y` - The vertical position
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0.0, 1.0],
+    ///                                 vec![2.0, 3.0]]);
+    ///
+    /// assert_eq!(grid.sample(0.0, 0.0), 0.0);
+    /// assert_eq!(grid.sample(0.5, 0.0), 0.5);
+    /// assert_eq!(grid.sample(0.5, 0.5), 1.5);
+    /// assert_eq!(grid.sample(5.0, 5.0), 3.0);
+    /// ```
+    ///
+    pub fn sample(&self, x: f32, y: f32) -> f32 {
+        sample(self, x as f64, y as f64, Interpolation::Bilinear) as f32
+    }
+
+    /// Sample the grid at a sub-element position with the nearest element.
+    ///
+    /// This method returns the value of the element that is the nearest to an
+    /// arbitrary position. Elements are located at integer positions, and
+    /// positions outside of the grid are clamped to its edges.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The horizontal position
+    /// * `y` - The vertical position
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grid is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0.0, 1.0],
+    ///                                 vec![2.0, 3.0]]);
+    ///
+    /// assert_eq!(grid.sample_nearest(0.4, 0.0), 0.0);
+    /// assert_eq!(grid.sample_nearest(0.6, 0.0), 1.0);
+    /// assert_eq!(grid.sample_nearest(0.6, 0.6), 3.0);
+    /// assert_eq!(grid.sample_nearest(-5.0, 0.9), 2.0);
+    /// ```
+    ///
+    pub fn sample_nearest(&self, x: f32, y: f32) -> f32 {
+        sample(self, x as f64, y as f64, Interpolation::Nearest) as f32
+    }
+}
+
+impl<T: Numeric> Grid<T> {
+
+    /// Return the grid resampled to a given size.
+    ///
+    /// This method returns a new grid of any size whose elements are
+    /// interpolated from the elements of the grid. Unlike `scaled()`, the
+    /// transition between the elements is smooth when using bilinear
+    /// interpolation.
+    ///
+    /// The elements of both grids are aligned on their centers, therefore,
+    /// the corner elements of the new grid don't necessarily match the corner
+    /// elements of the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the new grid
+    /// * `interpolation` - The method to interpolate the elements
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grid is empty while the new size is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, Interpolation, size};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0.0, 4.0]]);
+    ///
+    /// let grid = grid.resampled(size!(4, 1), Interpolation::Bilinear);
+    /// assert_eq!(grid.row(0).values(), vec![&0.0, &1.0, &3.0, &4.0]);
+    /// ```
+    ///
+    pub fn resampled(&self, size: Size, interpolation: Interpolation) -> Grid<T> {
+        assert!(size.is_empty() || !self.is_empty(), "grid is empty");
+
+        let x_ratio = self.size().width as f64 / size.width as f64;
+        let y_ratio = self.size().height as f64 / size.height as f64;
+
+        let rows = (0..size.height).map(|y| {
+            let y = (y as f64 + 0.5) * y_ratio - 0.5;
+
+            (0..size.width).map(|x| {
+                let x = (x as f64 + 0.5) * x_ratio - 0.5;
+                T::from_f64(sample(self, x, y, interpolation))
+            }).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(size.width, rows)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Numeric> Grid<T> {
+
+    /// Return the grid rotated by an arbitrary angle.
+    ///
+    /// This method returns a new grid of a given size with the content of the
+    /// grid rotated around its center by an arbitrary angle; the centers of
+    /// both grids are aligned. The elements are interpolated from the
+    /// elements of the grid, and those that fall outside of the grid are set
+    /// with a fill value.
+    ///
+    /// The angle is in radians, and positive angles rotate the content
+    /// clockwise as the vertical axis points downward. Request a larger size
+    /// to see the corners of the rotated content, or the same size to clip
+    /// them.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle of the rotation, in radians
+    /// * `size` - Size of the new grid
+    /// * `fill` - The value of the elements outside of the rotated grid
+    /// * `interpolation` - The method to interpolate the elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, Interpolation, size};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                 vec![3, 4]]);
+    ///
+    /// let angle = std::f64::consts::FRAC_PI_2;
+    /// let rotated = grid.rotated_by(angle, size!(2, 2), 0, Interpolation::Nearest);
+    ///
+    /// assert_eq!(rotated.row(0).values(), vec![&3, &1]);
+    /// assert_eq!(rotated.row(1).values(), vec![&4, &2]);
+    /// ```
+    ///
+    pub fn rotated_by(&self, angle: f64, size: Size, fill: T, interpolation: Interpolation) -> Grid<T> {
+        let (sin, cos) = angle.sin_cos();
+
+        // The centers of the grids, in the coordinate space of the elements.
+        let center_x = (self.size().width as f64 - 1.0) / 2.0;
+        let center_y = (self.size().height as f64 - 1.0) / 2.0;
+        let rotated_center_x = (size.width as f64 - 1.0) / 2.0;
+        let rotated_center_y = (size.height as f64 - 1.0) / 2.0;
+
+        let width = self.size().width as f64;
+        let height = self.size().height as f64;
+
+        let rows = (0..size.height).map(|y| {
+            let dy = y as f64 - rotated_center_y;
+
+            (0..size.width).map(|x| {
+                let dx = x as f64 - rotated_center_x;
+
+                // Find where the element comes from with the inverse rotation.
+                let x = cos * dx + sin * dy + center_x;
+                let y = -sin * dx + cos * dy + center_y;
+
+                if x < -0.5 || x >= width - 0.5 || y < -0.5 || y >= height - 0.5 {
+                    fill
+                }
+                else {
+                    T::from_f64(sample(self, x, y, interpolation))
+                }
+            }).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(size.width, rows)
+    }
+}
+
+// Sample a grid at an arbitrary position; positions outside of the grid are
+// clamped to its edges.
+pub(crate) fn sample<T: Numeric>(grid: &Grid<T>, x: f64, y: f64, interpolation: Interpolation) -> f64 {
+    assert!(!grid.is_empty(), "grid is empty");
+
+    let (left, right, dx) = interpolation_limits(x, grid.size().width);
+    let (top, bottom, dy) = interpolation_limits(y, grid.size().height);
+
+    match interpolation {
+        Interpolation::Nearest => {
+            let x = if dx < 0.5 { left } else { right };
+            let y = if dy < 0.5 { top } else { bottom };
+
+            grid[Coordinate::new(x, y)].to_f64()
+        },
+        Interpolation::Bilinear => {
+            let value = |x, y| grid[Coordinate::new(x, y)].to_f64();
+
+            let top_value = lerp(value(left, top), value(right, top), dx);
+            let bottom_value = lerp(value(left, bottom), value(right, bottom), dx);
+
+            lerp(top_value, bottom_value, dy)
+        }
+    }
+}
+
+// Compute the two elements surrounding a position along an axis, and the
+// fraction of the distance between them; the position is clamped to the axis.
+fn interpolation_limits(position: f64, length: usize) -> (usize, usize, f64) {
+    let last = length - 1;
+    let position = position.max(0.0).min(last as f64);
+
+    // The position is positive, therefore, truncating it is flooring it.
+    let lower = (position as usize).min(last);
+    let upper = (lower + 1).min(last);
+
+    (lower, upper, position - lower as f64)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_sample() {
+        let grid = Grid::from_rows(vec![vec![0.0, 2.0, 4.0],
+                                        vec![4.0, 6.0, 8.0]]);
+
+        assert_eq!(grid.sample(0.0, 0.0), 0.0);
+        assert_eq!(grid.sample(2.0, 1.0), 8.0);
+        assert_eq!(grid.sample(1.0, 0.0), 2.0);
+        assert_eq!(grid.sample(1.5, 0.0), 3.0);
+        assert_eq!(grid.sample(0.0, 0.25), 1.0);
+        assert_eq!(grid.sample(0.5, 0.5), 3.0);
+        assert_eq!(grid.sample(1.75, 1.0), 7.5);
+
+        // Positions outside of the grid are clamped.
+        assert_eq!(grid.sample(-1.0, -1.0), 0.0);
+        assert_eq!(grid.sample(10.0, 0.5), 6.0);
+        assert_eq!(grid.sample(f32::NAN, 0.0), 0.0);
+
+        let grid = Grid::with_size(size!(1, 1), 42.0);
+        assert_eq!(grid.sample(0.5, 0.5), 42.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid is empty")]
+    fn grid_sample_empty() {
+        let grid = Grid::<f32>::zero();
+        grid.sample(0.0, 0.0);
+    }
+
+    #[test]
+    fn grid_sample_nearest() {
+        let grid = Grid::from_rows(vec![vec![0.0, 2.0, 4.0],
+                                        vec![4.0, 6.0, 8.0]]);
+
+        assert_eq!(grid.sample_nearest(0.0, 0.0), 0.0);
+        assert_eq!(grid.sample_nearest(0.49, 0.0), 0.0);
+        assert_eq!(grid.sample_nearest(0.5, 0.0), 2.0);
+        assert_eq!(grid.sample_nearest(1.6, 0.7), 8.0);
+        assert_eq!(grid.sample_nearest(10.0, -3.0), 4.0);
+
+        let grid = Grid::with_size(size!(1, 1), 42.0);
+        assert_eq!(grid.sample_nearest(0.9, 0.9), 42.0);
+    }
+
+    #[test]
+    fn grid_resampled() {
+        let grid = Grid::from_rows(vec![vec![0.0, 4.0],
+                                        vec![8.0, 12.0]]);
+
+        let resampled = grid.resampled(size!(4, 4), Interpolation::Bilinear);
+        assert_eq!(resampled.row(0).values(), vec![&0.0, &1.0, &3.0, &4.0]);
+        assert_eq!(resampled.row(1).values(), vec![&2.0, &3.0, &5.0, &6.0]);
+        assert_eq!(resampled.row(2).values(), vec![&6.0, &7.0, &9.0, &10.0]);
+        assert_eq!(resampled.row(3).values(), vec![&8.0, &9.0, &11.0, &12.0]);
+
+        let resampled = grid.resampled(size!(3, 1), Interpolation::Nearest);
+        assert_eq!(resampled.row(0).values(), vec![&8.0, &12.0, &12.0]);
+
+        let resampled = grid.resampled(size!(1, 1), Interpolation::Bilinear);
+        assert_eq!(resampled.row(0).values(), vec![&6.0]);
+
+        assert_eq!(grid.resampled(size!(2, 2), Interpolation::Bilinear), grid);
+        assert_eq!(grid.resampled(size!(0, 2), Interpolation::Bilinear).size(), size!(0, 2));
+
+        // Integers are rounded.
+        let grid = Grid::from_rows(vec![vec![0u8, 3]]);
+        let resampled = grid.resampled(size!(4, 1), Interpolation::Bilinear);
+        assert_eq!(resampled.row(0).values(), vec![&0, &1, &2, &3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid is empty")]
+    fn grid_resampled_empty() {
+        Grid::<f32>::zero().resampled(size!(1, 1), Interpolation::Nearest);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn grid_rotated_by() {
+        use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let mut rotated = Grid::from_rows(vec![vec![1, 2, 3],
+                                               vec![4, 5, 6]]);
+        rotated.rotate_right();
+
+        assert_eq!(grid.rotated_by(FRAC_PI_2, size!(2, 3), 0, Interpolation::Nearest), rotated);
+        assert_eq!(grid.rotated_by(FRAC_PI_2, size!(2, 3), 0, Interpolation::Bilinear), rotated);
+
+        let rotated = grid.rotated_by(PI, size!(3, 2), 0, Interpolation::Bilinear);
+        assert_eq!(rotated.row(0).values(), vec![&6, &5, &4]);
+        assert_eq!(rotated.row(1).values(), vec![&3, &2, &1]);
+
+        assert_eq!(grid.rotated_by(0.0, size!(3, 2), 0, Interpolation::Bilinear), grid);
+
+        // Expanding reveals the fill value around the content.
+        let rotated = grid.rotated_by(0.0, size!(5, 4), 0, Interpolation::Nearest);
+        assert_eq!(rotated.row(0).values(), vec![&0, &0, &0, &0, &0]);
+        assert_eq!(rotated.row(1).values(), vec![&0, &1, &2, &3, &0]);
+        assert_eq!(rotated.row(2).values(), vec![&0, &4, &5, &6, &0]);
+        assert_eq!(rotated.row(3).values(), vec![&0, &0, &0, &0, &0]);
+
+        // The corners fall outside of the grid when rotating by 45 degrees.
+        let grid = Grid::with_size(size!(5, 5), 1.0);
+        let rotated = grid.rotated_by(FRAC_PI_4, size!(5, 5), -1.0, Interpolation::Bilinear);
+        assert_eq!(rotated.row(0).values(), vec![&-1.0, &1.0, &1.0, &1.0, &-1.0]);
+        assert_eq!(rotated.row(2).values(), vec![&1.0, &1.0, &1.0, &1.0, &1.0]);
+        assert_eq!(rotated.row(4).values(), vec![&-1.0, &1.0, &1.0, &1.0, &-1.0]);
+    }
+}
@@ -0,0 +1,343 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::rect::Rect;
+use crate::grid::Grid;
+use crate::grid_view::GridView;
+use crate::numeric::Numeric;
+
+/// The strategies to scale grids
+///
+/// This enumeration lists the strategies to compute the elements of a grid
+/// scaled with `Grid::scaled_with()`.
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Scaling {
+    /// Each element takes the value of the nearest element of the original
+    /// grid.
+    Nearest,
+
+    /// Each element takes the average value of the elements of the original
+    /// grid it covers; it's the nearest element when enlarging.
+    Average
+}
+
+impl<T: Clone> Grid<T> {
+
+    /// Return the grid scaled to a given size.
+    ///
+    /// This method returns a new grid with a different resolution, whose
+    /// elements take the value of the nearest element of the grid. It works
+    /// with any type of element; see `scaled_with()` for the other strategies
+    /// of numeric grids.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the new grid
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grid is empty while the new size is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec!['a', 'b'],
+    ///                                 vec!['c', 'd']]);
+    ///
+    /// let grid = grid.scaled(size!(4, 2));
+    /// assert_eq!(grid.row(0).values(), vec![&'a', &'a', &'b', &'b']);
+    /// assert_eq!(grid.row(1).values(), vec![&'c', &'c', &'d', &'d']);
+    /// ```
+    ///
+    pub fn scaled(&self, size: Size) -> Grid<T> {
+        self.scaled_by(size, |grid, region| {
+            let x = nearest(region.0, region.1);
+            let y = nearest(region.2, region.3);
+
+            grid[Coordinate::new(x, y)].clone()
+        })
+    }
+
+    /// Return the grid reduced block by block.
+    ///
+    /// This method divides the grid into non-overlapping blocks of a given
+    /// size and returns a new grid whose elements are the blocks reduced with
+    /// a function (the maximum, the average or the most frequent element for
+    /// instance). It's useful to coarse-grain simulations or to generate levels
+    /// of detail.
+    ///
+    /// The blocks on the right and bottom edges are smaller if the size of the
+    /// grid isn't a multiple of the size of the blocks.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the blocks
+    /// * `function` - Function reducing a block to an element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the size of the blocks is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3, 4, 5],
+    ///                                 vec![6, 7, 8, 9, 0]]);
+    ///
+    /// let grid = grid.pool(size!(2, 2), |block| **block.values().iter().max().unwrap());
+    /// assert_eq!(grid.row(0).values(), vec![&7, &9, &5]);
+    /// ```
+    ///
+    pub fn pool<U, F>(&self, size: Size, mut function: F) -> Grid<U>
+        where U: Clone, F: FnMut(GridView<'_, T>) -> U
+    {
+        assert!(!size.is_empty(), "block size is empty");
+
+        let width = self.size().width.div_ceil(size.width);
+        let height = self.size().height.div_ceil(size.height);
+
+        let rows = (0..height).map(|y| {
+            let top = y * size.height;
+            let bottom = (top + size.height).min(self.size().height);
+
+            (0..width).map(|x| {
+                let left = x * size.width;
+                let right = (left + size.width).min(self.size().width);
+
+                let rect = Rect::new(Coordinate::new(left, top), Size::new(right - left, bottom - top));
+                function(self.view(rect))
+            }).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(width, rows)
+    }
+
+    /// Return the grid with each element expanded into a block.
+    ///
+    /// This method returns a new grid where each element of the grid is
+    /// repeated as a block of a given size, like a Kronecker product. It's
+    /// useful to blow up a low-resolution grid into a high-resolution one,
+    /// turning a map of tile indices into a map of pixels for instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the blocks
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, size};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                 vec![3, 4]]);
+    ///
+    /// let grid = grid.kron_expand(size!(3, 2));
+    /// assert_eq!(grid.size(), size!(6, 4));
+    /// assert_eq!(grid.row(0).values(), vec![&1, &1, &1, &2, &2, &2]);
+    /// assert_eq!(grid.row(1).values(), vec![&1, &1, &1, &2, &2, &2]);
+    /// assert_eq!(grid.row(2).values(), vec![&3, &3, &3, &4, &4, &4]);
+    /// ```
+    ///
+    pub fn kron_expand(&self, size: Size) -> Grid<T> {
+        let width = self.size().width * size.width;
+
+        let rows = self.row_vectors().iter().flat_map(|row| {
+            let expanded = row.iter()
+                .flat_map(|value| core::iter::repeat_n(value, size.width))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            core::iter::repeat_n(expanded, size.height)
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(width, rows)
+    }
+
+    // Create the scaled grid; each element is computed from the region of the
+    // grid it covers, given as left, right, top and bottom limits.
+    fn scaled_by<U, F>(&self, size: Size, function: F) -> Grid<U>
+        where U: Clone, F: Fn(&Grid<T>, (usize, usize, usize, usize)) -> U
+    {
+        assert!(size.is_empty() || !self.is_empty(), "grid is empty");
+
+        let rows = (0..size.height).map(|y| {
+            let (top, bottom) = covered(y, size.height, self.size().height);
+
+            (0..size.width).map(|x| {
+                let (left, right) = covered(x, size.width, self.size().width);
+                function(self, (left, right, top, bottom))
+            }).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(size.width, rows)
+    }
+}
+
+impl<T: Numeric> Grid<T> {
+
+    /// Return the grid scaled to a given size with a given strategy.
+    ///
+    /// This method returns a new grid with a different resolution, whose
+    /// elements are computed with a given strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the new grid
+    /// * `scaling` - The strategy to compute the elements
+    ///
+    /// # Panics
+    ///
+    /// It panics if the grid is empty while the new size is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, Scaling, size};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0u8, 2, 10, 10],
+    ///                                 vec![4, 6, 10, 10]]);
+    ///
+    /// let grid = grid.scaled_with(size!(2, 1), Scaling::Average);
+    /// assert_eq!(grid.row(0).values(), vec![&3, &10]);
+    /// ```
+    ///
+    pub fn scaled_with(&self, size: Size, scaling: Scaling) -> Grid<T> {
+        match scaling {
+            Scaling::Nearest => self.scaled(size),
+            Scaling::Average => self.scaled_by(size, |grid, (left, right, top, bottom)| {
+                let mut sum = 0.0;
+                for y in top..bottom {
+                    for value in &grid.row_slice(y)[left..right] {
+                        sum += value.to_f64();
+                    }
+                }
+
+                T::from_f64(sum / ((right - left) * (bottom - top)) as f64)
+            })
+        }
+    }
+}
+
+// Compute the range of elements of the original axis that an element of the
+// scaled axis covers; it's never empty.
+fn covered(index: usize, length: usize, original: usize) -> (usize, usize) {
+    let start = index * original / length;
+    let end = ((index + 1) * original).div_ceil(length);
+
+    (start, end.max(start + 1))
+}
+
+// Return the element in the middle of a range, rounding down.
+fn nearest(start: usize, end: usize) -> usize {
+    start + (end - start - 1) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_scaled() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let scaled = grid.scaled(size!(6, 4));
+        assert_eq!(scaled.size(), size!(6, 4));
+        assert_eq!(scaled.row(0).values(), vec![&1, &1, &2, &2, &3, &3]);
+        assert_eq!(scaled.row(1).values(), vec![&1, &1, &2, &2, &3, &3]);
+        assert_eq!(scaled.row(2).values(), vec![&4, &4, &5, &5, &6, &6]);
+        assert_eq!(scaled.row(3).values(), vec![&4, &4, &5, &5, &6, &6]);
+
+        let scaled = grid.scaled(size!(1, 1));
+        assert_eq!(scaled.row(0).values(), vec![&2]);
+
+        assert_eq!(grid.scaled(size!(3, 2)), grid);
+        assert_eq!(grid.scaled(size!(0, 3)).size(), size!(0, 3));
+        assert_eq!(Grid::<i32>::zero().scaled(size!(0, 0)).size(), size!(0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "grid is empty")]
+    fn grid_scaled_empty() {
+        Grid::<i32>::zero().scaled(size!(2, 2));
+    }
+
+    #[test]
+    fn grid_scaled_with() {
+        let grid = Grid::from_rows(vec![vec![1.0, 2.0, 3.0, 4.0],
+                                        vec![5.0, 6.0, 7.0, 8.0]]);
+
+        let scaled = grid.scaled_with(size!(2, 1), Scaling::Average);
+        assert_eq!(scaled.row(0).values(), vec![&3.5, &5.5]);
+
+        let scaled = grid.scaled_with(size!(3, 2), Scaling::Average);
+        assert_eq!(scaled.row(0).values(), vec![&1.5, &2.5, &3.5]);
+        assert_eq!(scaled.row(1).values(), vec![&5.5, &6.5, &7.5]);
+
+        let scaled = grid.scaled_with(size!(2, 1), Scaling::Nearest);
+        assert_eq!(scaled.row(0).values(), vec![&1.0, &3.0]);
+
+        // Averages are rounded for integers.
+        let grid = Grid::from_rows(vec![vec![1u8, 2],
+                                        vec![2, 2]]);
+
+        let scaled = grid.scaled_with(size!(1, 1), Scaling::Average);
+        assert_eq!(scaled.row(0).values(), vec![&2]);
+    }
+
+    #[test]
+    fn grid_pool() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3, 4, 5],
+                                        vec![6, 7, 8, 9, 0],
+                                        vec![1, 1, 2, 2, 3]]);
+
+        let sums = grid.pool(size!(2, 2), |block| block.values().into_iter().sum::<i32>());
+        assert_eq!(sums.size(), size!(3, 2));
+        assert_eq!(sums.row(0).values(), vec![&16, &24, &5]);
+        assert_eq!(sums.row(1).values(), vec![&2, &4, &3]);
+
+        let sizes = grid.pool(size!(3, 3), |block| block.size());
+        assert_eq!(sizes.row(0).values(), vec![&size!(3, 3), &size!(2, 3)]);
+
+        let grid = Grid::<u8>::zero();
+        assert_eq!(grid.pool(size!(2, 2), |_| 0).size(), size!(0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "block size is empty")]
+    fn grid_pool_empty_block() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4]]);
+        grid.pool(size!(0, 2), |_| 0);
+    }
+
+    #[test]
+    fn grid_kron_expand() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let expanded = grid.kron_expand(size!(2, 3));
+        assert_eq!(expanded.size(), size!(6, 6));
+        assert_eq!(expanded.row(0).values(), vec![&1, &1, &2, &2, &3, &3]);
+        assert_eq!(expanded.row(2).values(), vec![&1, &1, &2, &2, &3, &3]);
+        assert_eq!(expanded.row(3).values(), vec![&4, &4, &5, &5, &6, &6]);
+        assert_eq!(expanded.row(5).values(), vec![&4, &4, &5, &5, &6, &6]);
+
+        assert_eq!(grid.kron_expand(size!(1, 1)), grid);
+        assert_eq!(grid.kron_expand(size!(0, 2)).size(), size!(0, 4));
+        assert_eq!(Grid::<u8>::zero().kron_expand(size!(2, 2)).size(), size!(0, 0));
+    }
+}
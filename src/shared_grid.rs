@@ -0,0 +1,175 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::ops::Deref;
+use alloc::sync::Arc;
+use crate::grid::Grid;
+
+/// A grid that is cheaply shared between its clones
+///
+/// This structure is a reference-counted grid; cloning it doesn't copy any
+/// element, the clones share the same grid. It lets several readers, such as
+/// a render thread and an AI thread, hold the same large grid without deep
+/// copies. It dereferences to a regular grid for reading.
+///
+/// Modifying a shared grid goes through `make_mut()` which copies the grid
+/// first if it's shared with other clones; the other clones are unaffected.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Grid, SharedGrid, coord, size};
+/// #
+/// let mut grid = SharedGrid::from(Grid::with_size(size!(3, 3), 0));
+///
+/// // Hand a copy to another reader; no element is copied.
+/// let reader = grid.clone();
+/// grid.make_mut()[coord!(1, 1)] = 42;
+///
+/// assert_eq!(grid[coord!(1, 1)], 42);
+/// assert_eq!(reader[coord!(1, 1)], 0);
+/// ```
+///
+#[derive(Debug, Eq, PartialEq)]
+pub struct SharedGrid<T> {
+    grid: Arc<Grid<T>>
+}
+
+impl<T: Clone> SharedGrid<T> {
+
+    /// Return a mutable reference to the grid.
+    ///
+    /// This method returns a mutable reference to the grid. If the grid is
+    /// shared with other clones, it's copied first so the other clones keep
+    /// their elements; otherwise, it's modified in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, SharedGrid, coord, size};
+    /// #
+    /// let mut grid = SharedGrid::from(Grid::with_size(size!(2, 2), 0));
+    ///
+    /// grid.make_mut().set_value(coord!(1, 0), 42);
+    /// assert_eq!(grid.value(coord!(1, 0)), &42);
+    /// ```
+    ///
+    pub fn make_mut(&mut self) -> &mut Grid<T> {
+        if Arc::get_mut(&mut self.grid).is_none() {
+            self.grid = Arc::new(copy(&self.grid));
+        }
+
+        Arc::get_mut(&mut self.grid).unwrap()
+    }
+
+    /// Check whether the grid is shared.
+    ///
+    /// This method returns true if the grid is shared with other clones, in
+    /// which case modifying it will copy it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, SharedGrid, size};
+    /// #
+    /// let grid = SharedGrid::from(Grid::with_size(size!(2, 2), 0));
+    /// assert!(!grid.is_shared());
+    ///
+    /// let reader = grid.clone();
+    /// assert!(grid.is_shared());
+    /// ```
+    ///
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.grid) > 1
+    }
+
+    /// Convert the shared grid into a regular grid.
+    ///
+    /// This method returns the grid as a regular grid. If it's shared with
+    /// other clones, it's copied; otherwise, it's moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, SharedGrid, size};
+    /// #
+    /// let grid = SharedGrid::from(Grid::with_size(size!(2, 2), 42));
+    /// assert_eq!(grid.into_grid(), Grid::with_size(size!(2, 2), 42));
+    /// ```
+    ///
+    pub fn into_grid(self) -> Grid<T> {
+        Arc::try_unwrap(self.grid).unwrap_or_else(|grid| copy(&grid))
+    }
+}
+
+// Copy the elements of a grid into a new grid.
+fn copy<T: Clone>(grid: &Grid<T>) -> Grid<T> {
+    let rows = grid.row_vectors().iter().map(|row| row.to_vec()).collect();
+    Grid::from_row_vectors(grid.size().width, rows)
+}
+
+impl<T> Clone for SharedGrid<T> {
+    fn clone(&self) -> Self {
+        SharedGrid { grid: Arc::clone(&self.grid) }
+    }
+}
+
+impl<T> From<Grid<T>> for SharedGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        SharedGrid { grid: Arc::new(grid) }
+    }
+}
+
+impl<T> Deref for SharedGrid<T> {
+    type Target = Grid<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinate::Coordinate;
+
+    #[test]
+    fn shared_grid_make_mut() {
+        let mut grid = SharedGrid::from(Grid::from_rows(vec![vec![1, 2],
+                                                             vec![3, 4]]));
+
+        grid.make_mut()[coord!(0, 0)] = 10;
+        assert_eq!(grid[coord!(0, 0)], 10);
+
+        let reader = grid.clone();
+        assert!(grid.is_shared());
+        assert!(reader.is_shared());
+
+        grid.make_mut()[coord!(1, 1)] = 40;
+        assert!(!grid.is_shared());
+        assert!(!reader.is_shared());
+
+        assert_eq!(reader.into_grid(), Grid::from_rows(vec![vec![10, 2],
+                                                            vec![3, 4]]));
+        assert_eq!(grid.into_grid(), Grid::from_rows(vec![vec![10, 2],
+                                                          vec![3, 40]]));
+    }
+
+    #[test]
+    fn shared_grid_into_grid() {
+        let grid = SharedGrid::from(Grid::from_rows(vec![vec![1, 2],
+                                                         vec![3, 4]]));
+        let reader = grid.clone();
+
+        assert_eq!(reader.into_grid(), Grid::from_rows(vec![vec![1, 2],
+                                                            vec![3, 4]]));
+        assert!(!grid.is_shared());
+        assert_eq!(grid.into_grid(), Grid::from_rows(vec![vec![1, 2],
+                                                          vec![3, 4]]));
+    }
+}
@@ -6,6 +6,8 @@
 //
 // Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
 
+use crate::coordinate::Coordinate;
+
 /// A two-dimensional size
 ///
 /// This structure defines a basic two-dimensional size to specify the dimension
@@ -33,7 +35,7 @@
 /// let size3 = Size::zero();
 /// ```
 ///
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Size {
     /// The width of the size.
     pub width: usize,
@@ -82,6 +84,112 @@ impl Size {
     pub fn zero() -> Size {
         Size { width: 0, height: 0 }
     }
+
+    /// Return the area of the size.
+    ///
+    /// This method returns the area of the size, which is the number of
+    /// elements a grid of this size holds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, size};
+    /// #
+    /// assert_eq!(size!(3, 2).area(), 6);
+    /// assert_eq!(size!(3, 0).area(), 0);
+    /// ```
+    ///
+    pub fn area(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// Check whether the size is empty.
+    ///
+    /// This method returns whether the size is empty, which is when either its
+    /// width or its height is zero. A grid with an empty size contains no
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, size};
+    /// #
+    /// assert_eq!(size!(3, 2).is_empty(), false);
+    /// assert_eq!(size!(3, 0).is_empty(), true);
+    /// assert_eq!(size!(0, 2).is_empty(), true);
+    /// ```
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Check whether a coordinate is within the size.
+    ///
+    /// This method returns whether a coordinate is within the bounds of the
+    /// size, in other words, if it's a valid coordinate for a grid of this
+    /// size.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - The coordinate to check
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, coord, size};
+    /// #
+    /// let size = size!(3, 2);
+    ///
+    /// assert_eq!(size.contains(coord!(2, 1)), true);
+    /// assert_eq!(size.contains(coord!(3, 1)), false);
+    /// assert_eq!(size.contains(coord!(2, 2)), false);
+    /// ```
+    ///
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        coordinate.x < self.width && coordinate.y < self.height
+    }
+
+    /// Return the component-wise minimum of two sizes.
+    ///
+    /// This method returns a size whose width and height are the smallest
+    /// width and height of the two sizes.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other size to compare with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, size};
+    /// #
+    /// assert_eq!(size!(3, 1).min(size!(2, 4)), size!(2, 1));
+    /// ```
+    ///
+    pub fn min(self, other: Size) -> Size {
+        Size::new(self.width.min(other.width), self.height.min(other.height))
+    }
+
+    /// Return the component-wise maximum of two sizes.
+    ///
+    /// This method returns a size whose width and height are the biggest
+    /// width and height of the two sizes.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other size to compare with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, size};
+    /// #
+    /// assert_eq!(size!(3, 1).max(size!(2, 4)), size!(3, 4));
+    /// ```
+    ///
+    pub fn max(self, other: Size) -> Size {
+        Size::new(self.width.max(other.width), self.height.max(other.height))
+    }
 }
 
 /// A size instantiation helper.
@@ -101,6 +209,6 @@ impl Size {
 #[macro_export]
 macro_rules! size {
     ($width:expr, $height:expr) => {
-        Size::new($width, $height);
+        Size::new($width, $height)
     };
 }
\ No newline at end of file
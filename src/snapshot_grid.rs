@@ -0,0 +1,299 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::ops::{Index, IndexMut};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::grid::Grid;
+
+/// A grid with cheap copy-on-write snapshots
+///
+/// This structure is a grid whose rows are shared between its snapshots.
+/// Taking a snapshot doesn't copy any element; instead, the rows are copied
+/// lazily, the first time they're modified, and only the rows that are
+/// modified are copied. It allows to keep many historical versions of a large
+/// grid at the cost of the rows that actually changed between them.
+///
+/// Snapshots are independent grids; modifying one never affects the others.
+/// Note that cloning a snapshot grid is equivalent to taking a snapshot.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Grid, SnapshotGrid, coord, size};
+/// #
+/// let mut grid = SnapshotGrid::from(Grid::with_size(size!(3, 3), 0));
+///
+/// // Keep the current version before making changes.
+/// let snapshot = grid.snapshot();
+/// grid[coord!(1, 1)] = 42;
+///
+/// assert_eq!(grid[coord!(1, 1)], 42);
+/// assert_eq!(snapshot[coord!(1, 1)], 0);
+/// ```
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SnapshotGrid<T> {
+    size: Size,
+    rows: Vec<Arc<Vec<T>>>
+}
+
+impl<T: Clone> SnapshotGrid<T> {
+
+    /// Take a snapshot of the grid.
+    ///
+    /// This method returns a snapshot of the grid in its current state. It's
+    /// a cheap operation as the rows are shared with the grid until either of
+    /// them modifies them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, SnapshotGrid, coord};
+    /// #
+    /// let mut grid = SnapshotGrid::from(Grid::from_rows(vec![vec![1, 2],
+    ///                                                        vec![3, 4]]));
+    ///
+    /// let snapshot = grid.snapshot();
+    /// grid.set_value(coord!(0, 0), 5);
+    ///
+    /// assert_eq!(snapshot.value(coord!(0, 0)), &1);
+    /// ```
+    ///
+    pub fn snapshot(&self) -> SnapshotGrid<T> {
+        self.clone()
+    }
+
+    /// Return the size of the grid.
+    ///
+    /// This method returns the size of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, SnapshotGrid, size};
+    /// #
+    /// let grid = SnapshotGrid::from(Grid::with_size(size!(2, 3), 0));
+    /// assert_eq!(grid.size(), size!(2, 3));
+    /// ```
+    ///
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Return a reference to an element of the grid.
+    ///
+    /// This method returns a reference to an element of the grid from its
+    /// coordinate.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,should_panic
+    /// # use ingrid::{Coordinate, Grid, SnapshotGrid, coord};
+    /// #
+    /// let grid = SnapshotGrid::from(Grid::from_rows(vec![vec![1, 2],
+    ///                                                    vec![3, 4]]));
+    ///
+    /// assert_eq!(grid.value(coord!(0, 0)), &1);
+    /// assert_eq!(grid.value(coord!(1, 1)), &4);
+    ///
+    /// grid.value(coord!(2, 0)); // It panics here !
+    /// ```
+    ///
+    pub fn value(&self, coordinate: Coordinate) -> &T {
+        assert!(self.size.contains(coordinate), "index out of bounds");
+
+        &self.rows[coordinate.y][coordinate.x]
+    }
+
+    /// Return a mutable reference to an element of the grid.
+    ///
+    /// This method returns a mutable reference to an element of the grid from
+    /// its coordinate. If the row of the element is shared with a snapshot,
+    /// the row is copied first.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, SnapshotGrid, coord};
+    /// #
+    /// let mut grid = SnapshotGrid::from(Grid::from_rows(vec![vec![1, 2],
+    ///                                                        vec![3, 0]]));
+    ///
+    /// *grid.value_mut(coord!(1, 1)) = 4;
+    /// assert_eq!(grid.value(coord!(1, 1)), &4);
+    /// ```
+    ///
+    pub fn value_mut(&mut self, coordinate: Coordinate) -> &mut T {
+        assert!(self.size.contains(coordinate), "index out of bounds");
+
+        &mut Arc::make_mut(&mut self.rows[coordinate.y])[coordinate.x]
+    }
+
+    /// Replace an element of the grid.
+    ///
+    /// This method replaces the value of an element of the grid from its
+    /// coordinate and a new value. If the row of the element is shared with a
+    /// snapshot, the row is copied first.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    /// * `value` - New value of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, SnapshotGrid, coord, size};
+    /// #
+    /// let mut grid = SnapshotGrid::from(Grid::with_size(size!(2, 2), 0));
+    ///
+    /// grid.set_value(coord!(1, 0), 42);
+    /// assert_eq!(grid.value(coord!(1, 0)), &42);
+    /// ```
+    ///
+    pub fn set_value(&mut self, coordinate: Coordinate, value: T) {
+        *self.value_mut(coordinate) = value;
+    }
+
+    /// Convert the snapshot grid into a regular grid.
+    ///
+    /// This method returns a regular grid with the elements of the grid. The
+    /// rows that are shared with snapshots are copied, the others are moved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, SnapshotGrid, size};
+    /// #
+    /// let grid = SnapshotGrid::from(Grid::with_size(size!(2, 2), 42));
+    /// assert_eq!(grid.into_grid(), Grid::with_size(size!(2, 2), 42));
+    /// ```
+    ///
+    pub fn into_grid(self) -> Grid<T> {
+        let rows = self.rows.into_iter()
+            .map(|row| Arc::try_unwrap(row).unwrap_or_else(|row| (*row).clone()))
+            .collect();
+
+        Grid::from_row_vectors(self.size.width, rows)
+    }
+}
+
+impl<T: Clone> From<Grid<T>> for SnapshotGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        let size = grid.size();
+        let rows = grid.into_row_vectors().into_iter().map(Arc::new).collect();
+
+        SnapshotGrid { size, rows }
+    }
+}
+
+impl<T: Clone> Index<Coordinate> for SnapshotGrid<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &Self::Output {
+        self.value(coordinate)
+    }
+}
+
+impl<T: Clone> IndexMut<Coordinate> for SnapshotGrid<T> {
+    fn index_mut(&mut self, coordinate: Coordinate) -> &mut Self::Output {
+        self.value_mut(coordinate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_grid_snapshot() {
+        let mut grid = SnapshotGrid::from(Grid::from_rows(vec![vec![1, 2],
+                                                               vec![3, 4],
+                                                               vec![5, 6]]));
+
+        let first = grid.snapshot();
+        grid[coord!(0, 1)] = 0;
+
+        let second = grid.snapshot();
+        grid[coord!(1, 1)] = 0;
+        grid[coord!(1, 2)] = 0;
+
+        assert_eq!(first.into_grid(), Grid::from_rows(vec![vec![1, 2],
+                                                           vec![3, 4],
+                                                           vec![5, 6]]));
+        assert_eq!(second.into_grid(), Grid::from_rows(vec![vec![1, 2],
+                                                            vec![0, 4],
+                                                            vec![5, 6]]));
+        assert_eq!(grid.into_grid(), Grid::from_rows(vec![vec![1, 2],
+                                                          vec![0, 0],
+                                                          vec![5, 0]]));
+    }
+
+    #[test]
+    fn snapshot_grid_copy_on_write() {
+        let mut grid = SnapshotGrid::from(Grid::with_size(size!(2, 3), 0));
+        let snapshot = grid.snapshot();
+
+        // Only the modified row stops being shared.
+        grid.set_value(coord!(1, 1), 42);
+
+        assert!(Arc::ptr_eq(&grid.rows[0], &snapshot.rows[0]));
+        assert!(!Arc::ptr_eq(&grid.rows[1], &snapshot.rows[1]));
+        assert!(Arc::ptr_eq(&grid.rows[2], &snapshot.rows[2]));
+
+        // The row is now owned by the grid; it's not copied again.
+        let row = Arc::as_ptr(&grid.rows[1]);
+        grid.set_value(coord!(0, 1), 42);
+        assert_eq!(Arc::as_ptr(&grid.rows[1]), row);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn snapshot_grid_value() {
+        let grid = SnapshotGrid::from(Grid::from_rows(vec![vec![1, 2],
+                                                           vec![3, 4]]));
+
+        assert_eq!(grid.value(coord!(0, 0)), &1);
+        assert_eq!(grid.value(coord!(1, 0)), &2);
+        assert_eq!(grid.value(coord!(0, 1)), &3);
+        assert_eq!(grid.value(coord!(1, 1)), &4);
+
+        grid.value(coord!(0, 2));
+    }
+
+    #[test]
+    fn snapshot_grid_into_grid() {
+        let mut grid = Grid::<i32>::zero();
+        grid.resize(size!(3, 0), 0);
+
+        assert_eq!(SnapshotGrid::from(grid).into_grid().size(), size!(3, 0));
+    }
+}
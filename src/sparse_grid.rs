@@ -0,0 +1,488 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::collections::btree_map::{self, BTreeMap};
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+
+/// A grid that only stores the elements that are set
+///
+/// This structure is a grid whose elements are optional and which only
+/// allocates memory for the elements that are set. It's meant for large grids
+/// that are mostly empty, such as maps of a few scattered entities. The
+/// elements are kept in row-major order.
+///
+/// Just like maps, it comes with an entry API to conditionally initialize the
+/// elements.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+/// #
+/// let mut grid = SparseGrid::new(size!(1000, 1000));
+///
+/// grid.insert(coord!(10, 20), 'a');
+/// *grid.entry(coord!(999, 999)).or_insert('b') = 'c';
+///
+/// assert_eq!(grid.len(), 2);
+/// assert_eq!(grid.get(coord!(10, 20)), Some(&'a'));
+/// assert_eq!(grid.get(coord!(999, 999)), Some(&'c'));
+/// assert_eq!(grid.get(coord!(0, 0)), None);
+/// ```
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    size: Size,
+    elements: BTreeMap<Coordinate, T>
+}
+
+impl<T> SparseGrid<T> {
+
+    /// Construct an empty sparse grid.
+    ///
+    /// This function constructs a sparse grid of a given size with no element
+    /// set. It doesn't allocate memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Size of the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, SparseGrid, size};
+    /// #
+    /// let grid = SparseGrid::<i32>::new(size!(10, 10));
+    ///
+    /// assert_eq!(grid.size(), size!(10, 10));
+    /// assert!(grid.is_empty());
+    /// ```
+    ///
+    pub fn new(size: Size) -> SparseGrid<T> {
+        SparseGrid { size, elements: BTreeMap::new() }
+    }
+
+    /// Return the size of the grid.
+    ///
+    /// This method returns the size of the grid, regardless of the number of
+    /// elements that are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, SparseGrid, size};
+    /// #
+    /// let grid = SparseGrid::<i32>::new(size!(3, 2));
+    /// assert_eq!(grid.size(), size!(3, 2));
+    /// ```
+    ///
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Return the number of elements that are set.
+    ///
+    /// This method returns the number of elements that are set in the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::new(size!(3, 3));
+    /// grid.insert(coord!(1, 1), 42);
+    ///
+    /// assert_eq!(grid.len(), 1);
+    /// ```
+    ///
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Check whether no element is set.
+    ///
+    /// This method returns whether no element is set in the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, SparseGrid, size};
+    /// #
+    /// let grid = SparseGrid::<i32>::new(size!(3, 3));
+    /// assert!(grid.is_empty());
+    /// ```
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Return a reference to an element of the grid.
+    ///
+    /// This method returns a reference to an element of the grid from its
+    /// coordinate, or `None` if the element isn't set.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::new(size!(3, 3));
+    /// grid.insert(coord!(1, 1), 42);
+    ///
+    /// assert_eq!(grid.get(coord!(1, 1)), Some(&42));
+    /// assert_eq!(grid.get(coord!(0, 0)), None);
+    /// ```
+    ///
+    pub fn get(&self, coordinate: Coordinate) -> Option<&T> {
+        assert!(self.size.contains(coordinate), "index out of bounds");
+        self.elements.get(&coordinate)
+    }
+
+    /// Return a mutable reference to an element of the grid.
+    ///
+    /// This method returns a mutable reference to an element of the grid from
+    /// its coordinate, or `None` if the element isn't set.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::new(size!(3, 3));
+    /// grid.insert(coord!(1, 1), 42);
+    ///
+    /// *grid.get_mut(coord!(1, 1)).unwrap() += 1;
+    /// assert_eq!(grid.get(coord!(1, 1)), Some(&43));
+    /// ```
+    ///
+    pub fn get_mut(&mut self, coordinate: Coordinate) -> Option<&mut T> {
+        assert!(self.size.contains(coordinate), "index out of bounds");
+        self.elements.get_mut(&coordinate)
+    }
+
+    /// Set an element of the grid.
+    ///
+    /// This method sets an element of the grid from its coordinate and a
+    /// value, and returns the previous value if the element was already set.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    /// * `value` - New value of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::new(size!(3, 3));
+    ///
+    /// assert_eq!(grid.insert(coord!(1, 1), 1), None);
+    /// assert_eq!(grid.insert(coord!(1, 1), 2), Some(1));
+    /// ```
+    ///
+    pub fn insert(&mut self, coordinate: Coordinate, value: T) -> Option<T> {
+        assert!(self.size.contains(coordinate), "index out of bounds");
+        self.elements.insert(coordinate, value)
+    }
+
+    /// Unset an element of the grid.
+    ///
+    /// This method unsets an element of the grid from its coordinate, and
+    /// returns its value if it was set.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::new(size!(3, 3));
+    /// grid.insert(coord!(1, 1), 42);
+    ///
+    /// assert_eq!(grid.remove(coord!(1, 1)), Some(42));
+    /// assert_eq!(grid.remove(coord!(1, 1)), None);
+    /// ```
+    ///
+    pub fn remove(&mut self, coordinate: Coordinate) -> Option<T> {
+        assert!(self.size.contains(coordinate), "index out of bounds");
+        self.elements.remove(&coordinate)
+    }
+
+    /// Return the entry of an element for in-place manipulation.
+    ///
+    /// This method returns the entry of an element of the grid from its
+    /// coordinate, whether it's set or not, in order to conditionally
+    /// initialize or modify it.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::new(size!(3, 3));
+    ///
+    /// // Count the visits of each element.
+    /// for coordinate in vec![coord!(0, 0), coord!(2, 1), coord!(0, 0)] {
+    ///     grid.entry(coordinate).and_modify(|count| *count += 1).or_insert(1);
+    /// }
+    ///
+    /// assert_eq!(grid.get(coord!(0, 0)), Some(&2));
+    /// assert_eq!(grid.get(coord!(2, 1)), Some(&1));
+    /// ```
+    ///
+    pub fn entry(&mut self, coordinate: Coordinate) -> SparseEntry<'_, T> {
+        assert!(self.size.contains(coordinate), "index out of bounds");
+        SparseEntry { entry: self.elements.entry(coordinate) }
+    }
+
+    /// Returns an iterator over the elements that are set.
+    ///
+    /// This method returns an iterator over the elements that are set, along
+    /// with their coordinates, from left to right and top to bottom.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::new(size!(3, 3));
+    /// grid.insert(coord!(0, 2), 'b');
+    /// grid.insert(coord!(2, 0), 'a');
+    ///
+    /// let mut iterator = grid.iterator();
+    /// assert_eq!(iterator.next(), Some((coord!(2, 0), &'a')));
+    /// assert_eq!(iterator.next(), Some((coord!(0, 2), &'b')));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    ///
+    pub fn iterator(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.elements.iter().map(|(coordinate, value)| (*coordinate, value))
+    }
+}
+
+/// An entry of a sparse grid
+///
+/// This structure is a view into an element of a sparse grid, which may or
+/// may not be set. It's constructed with the `SparseGrid::entry()` method.
+///
+#[derive(Debug)]
+pub struct SparseEntry<'a, T> {
+    entry: btree_map::Entry<'a, Coordinate, T>
+}
+
+impl<'a, T> SparseEntry<'a, T> {
+
+    /// Return the coordinate of the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::<i32>::new(size!(3, 3));
+    /// assert_eq!(grid.entry(coord!(1, 2)).coordinate(), coord!(1, 2));
+    /// ```
+    ///
+    pub fn coordinate(&self) -> Coordinate {
+        *self.entry.key()
+    }
+
+    /// Set the element if it isn't set, and return a mutable reference to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `default` - The value to set the element with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::new(size!(3, 3));
+    ///
+    /// assert_eq!(grid.entry(coord!(1, 1)).or_insert(1), &1);
+    /// assert_eq!(grid.entry(coord!(1, 1)).or_insert(2), &1);
+    /// ```
+    ///
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        self.entry.or_insert(default)
+    }
+
+    /// Set the element with the result of a function if it isn't set, and
+    /// return a mutable reference to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `default` - The function computing the value of the element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::new(size!(3, 3));
+    ///
+    /// grid.entry(coord!(1, 1)).or_insert_with(Vec::new).push(42);
+    /// assert_eq!(grid.get(coord!(1, 1)), Some(&vec![42]));
+    /// ```
+    ///
+    pub fn or_insert_with<F: FnOnce() -> T>(self, default: F) -> &'a mut T {
+        self.entry.or_insert_with(default)
+    }
+
+    /// Set the element with its default value if it isn't set, and return a
+    /// mutable reference to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::<i32>::new(size!(3, 3));
+    ///
+    /// *grid.entry(coord!(1, 1)).or_default() += 42;
+    /// assert_eq!(grid.get(coord!(1, 1)), Some(&42));
+    /// ```
+    ///
+    pub fn or_default(self) -> &'a mut T where T: Default {
+        self.entry.or_default()
+    }
+
+    /// Modify the element if it's set.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The function modifying the element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, SparseGrid, coord, size};
+    /// #
+    /// let mut grid = SparseGrid::new(size!(3, 3));
+    ///
+    /// grid.entry(coord!(1, 1)).and_modify(|value| *value += 1).or_insert(42);
+    /// assert_eq!(grid.get(coord!(1, 1)), Some(&42));
+    ///
+    /// grid.entry(coord!(1, 1)).and_modify(|value| *value += 1).or_insert(42);
+    /// assert_eq!(grid.get(coord!(1, 1)), Some(&43));
+    /// ```
+    ///
+    pub fn and_modify<F: FnOnce(&mut T)>(self, function: F) -> SparseEntry<'a, T> {
+        SparseEntry { entry: self.entry.and_modify(function) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_grid_insert_remove() {
+        let mut grid = SparseGrid::new(size!(4, 3));
+        assert!(grid.is_empty());
+
+        assert_eq!(grid.insert(coord!(3, 2), 1), None);
+        assert_eq!(grid.insert(coord!(0, 1), 2), None);
+        assert_eq!(grid.insert(coord!(3, 2), 3), Some(1));
+        assert_eq!(grid.len(), 2);
+
+        assert_eq!(grid.get(coord!(3, 2)), Some(&3));
+        assert_eq!(grid.get(coord!(0, 1)), Some(&2));
+        assert_eq!(grid.get(coord!(1, 1)), None);
+
+        assert_eq!(grid.remove(coord!(0, 1)), Some(2));
+        assert_eq!(grid.remove(coord!(0, 1)), None);
+        assert_eq!(grid.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn sparse_grid_get() {
+        let grid = SparseGrid::<i32>::new(size!(4, 3));
+        grid.get(coord!(4, 0));
+    }
+
+    #[test]
+    fn sparse_grid_entry() {
+        let mut grid = SparseGrid::new(size!(3, 3));
+
+        let entry = grid.entry(coord!(2, 1));
+        assert_eq!(entry.coordinate(), coord!(2, 1));
+        assert_eq!(entry.and_modify(|value| *value = 0).or_insert(1), &1);
+
+        assert_eq!(grid.entry(coord!(2, 1)).and_modify(|value| *value += 1).or_insert(1), &2);
+        assert_eq!(grid.entry(coord!(2, 1)).or_insert_with(|| unreachable!()), &2);
+
+        let mut calls = 0;
+        grid.entry(coord!(0, 0)).or_insert_with(|| { calls += 1; 5 });
+        assert_eq!(calls, 1);
+        assert_eq!(grid.get(coord!(0, 0)), Some(&5));
+
+        assert_eq!(grid.entry(coord!(1, 0)).or_default(), &0);
+        assert_eq!(grid.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn sparse_grid_entry_out_of_bounds() {
+        let mut grid = SparseGrid::<i32>::new(size!(3, 3));
+        grid.entry(coord!(0, 3));
+    }
+
+    #[test]
+    fn sparse_grid_iterator() {
+        let mut grid = SparseGrid::new(size!(3, 3));
+        grid.insert(coord!(1, 2), 'c');
+        grid.insert(coord!(2, 0), 'a');
+        grid.insert(coord!(0, 1), 'b');
+
+        let mut iterator = grid.iterator();
+        assert_eq!(iterator.next(), Some((coord!(2, 0), &'a')));
+        assert_eq!(iterator.next(), Some((coord!(0, 1), &'b')));
+        assert_eq!(iterator.next(), Some((coord!(1, 2), &'c')));
+        assert_eq!(iterator.next(), None);
+    }
+}
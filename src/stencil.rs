@@ -0,0 +1,218 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::rect::Rect;
+use crate::grid::Grid;
+use crate::grid_view::GridView;
+use crate::border::BorderPolicy;
+
+impl<T: Clone> Grid<T> {
+
+    /// Return the grid computed from the neighborhood of each element.
+    ///
+    /// This method returns a new grid whose elements are computed by a
+    /// function from a window onto the neighborhood of each element of the
+    /// grid. The window is the square of elements within a given radius
+    /// around the element, clamped to the borders of the grid; therefore the
+    /// windows of the elements near the borders are smaller. The `rect` field
+    /// of the window tells where it lies in the grid.
+    ///
+    /// It's a general framework for convolutions, blurs or the rules of
+    /// cellular automata. Use `stencil_with_border()` to handle the borders
+    /// differently.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - The radius of the neighborhood
+    /// * `function` - The function computing an element from a window
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 0, 0],
+    ///                                 vec![0, 0, 0],
+    ///                                 vec![0, 0, 1]]);
+    ///
+    /// // Count the live cells of the neighborhood, including the cell itself.
+    /// let counts = grid.stencil(1, |window| window.values().into_iter().sum::<i32>());
+    /// assert_eq!(counts.row(0).values(), vec![&1, &1, &0]);
+    /// assert_eq!(counts.row(1).values(), vec![&1, &2, &1]);
+    /// assert_eq!(counts.row(2).values(), vec![&0, &1, &1]);
+    /// ```
+    ///
+    pub fn stencil<U, F>(&self, radius: usize, mut function: F) -> Grid<U>
+        where U: Clone, F: FnMut(GridView<'_, T>) -> U
+    {
+        let size = self.size();
+
+        let rows = (0..size.height).map(|y| {
+            let top = y.saturating_sub(radius);
+            let bottom = y.saturating_add(radius).min(size.height - 1);
+
+            (0..size.width).map(|x| {
+                let left = x.saturating_sub(radius);
+                let right = x.saturating_add(radius).min(size.width - 1);
+
+                let rect = Rect::new(Coordinate::new(left, top), Size::new(right - left + 1, bottom - top + 1));
+                function(self.view(rect))
+            }).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(size.width, rows)
+    }
+
+    /// Return the grid computed from the neighborhood of each element, with
+    /// a border policy.
+    ///
+    /// This method is similar to `stencil()`, but the elements of the window
+    /// outside of the grid are given by a border policy; therefore, all
+    /// windows have the same size, and the element is always at their center.
+    /// The windows are views onto a copy of the neighborhood, which is reused
+    /// for all elements, so their `rect` field starts at the origin. With the
+    /// skip policy, the windows are the same as with `stencil()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - The radius of the neighborhood
+    /// * `border` - The policy for the elements outside of the grid
+    /// * `function` - The function computing an element from a window
+    ///
+    /// # Panics
+    ///
+    /// It panics if the radius is too large for the windows to have twice
+    /// the radius plus one elements along each axis, unless the policy is
+    /// the skip policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, BorderPolicy};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 0, 0, 0],
+    ///                                 vec![0, 0, 0, 0],
+    ///                                 vec![0, 0, 0, 0],
+    ///                                 vec![0, 0, 0, 0]]);
+    ///
+    /// // Count the live cells of the neighborhood on a torus.
+    /// let counts = grid.stencil_with_border(1, BorderPolicy::Wrap, |window| {
+    ///     window.iterator().sum::<i32>()
+    /// });
+    /// assert_eq!(counts.row(0).values(), vec![&1, &1, &0, &1]);
+    /// assert_eq!(counts.row(2).values(), vec![&0, &0, &0, &0]);
+    /// assert_eq!(counts.row(3).values(), vec![&1, &1, &0, &1]);
+    /// ```
+    ///
+    pub fn stencil_with_border<U, F>(&self, radius: usize, border: BorderPolicy<T>, mut function: F) -> Grid<U>
+        where U: Clone, F: FnMut(GridView<'_, T>) -> U
+    {
+        if let BorderPolicy::Skip = border {
+            return self.stencil(radius, function);
+        }
+
+        let size = self.size();
+
+        // The elements of the windows along each axis are the same for all
+        // rows and all columns.
+        let columns = (0..size.width).map(|x| border.window(x, radius, size.width)).collect::<Vec<_>>();
+
+        // The neighborhoods are copied into the same grid, which is allocated
+        // for the first element only.
+        let mut buffer: Option<Grid<T>> = None;
+
+        let rows = (0..size.height).map(|y| {
+            let rows = border.window(y, radius, size.height);
+
+            columns.iter().map(|columns| {
+                let window = match buffer.as_mut() {
+                    Some(window) => {
+                        for (row, &y) in rows.iter().enumerate() {
+                            for (element, &x) in window.row_slice_mut(row).iter_mut().zip(columns) {
+                                element.clone_from(border.element(self, x, y));
+                            }
+                        }
+
+                        window
+                    },
+                    None => buffer.insert(Grid::from_row_vectors(columns.len(), rows.iter().map(|&y| {
+                        columns.iter().map(|&x| border.element(self, x, y).clone()).collect()
+                    }).collect()))
+                };
+
+                function(window.view(Rect::new(Coordinate::zero(), window.size())))
+            }).collect()
+        }).collect::<Vec<_>>();
+
+        Grid::from_row_vectors(size.width, rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_stencil() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3, 4],
+                                        vec![5, 6, 7, 8],
+                                        vec![9, 10, 11, 12]]);
+
+        let windows = grid.stencil(1, |window| window.rect);
+        assert_eq!(windows[coord!(0, 0)], rect!(0, 0, 2, 2));
+        assert_eq!(windows[coord!(1, 1)], rect!(0, 0, 3, 3));
+        assert_eq!(windows[coord!(3, 2)], rect!(2, 1, 2, 2));
+
+        let maximums = grid.stencil(1, |window| **window.values().iter().max().unwrap());
+        assert_eq!(maximums.row(0).values(), vec![&6, &7, &8, &8]);
+        assert_eq!(maximums.row(2).values(), vec![&10, &11, &12, &12]);
+
+        let identity = grid.stencil(0, |window| window[coord!(0, 0)]);
+        assert_eq!(identity, grid);
+
+        let whole = grid.stencil(usize::MAX, |window| window.size());
+        assert!(whole.all(|_, &size| size == grid.size()));
+
+        assert_eq!(Grid::<u8>::zero().stencil(1, |_| 0).size(), size!(0, 0));
+    }
+
+    #[test]
+    fn grid_stencil_with_border() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let windows = grid.stencil_with_border(1, BorderPolicy::Clamp, |window| window.values().into_iter().cloned().collect::<Vec<_>>());
+        assert_eq!(windows[coord!(0, 0)], vec![1, 1, 2, 1, 1, 2, 4, 4, 5]);
+        assert_eq!(windows[coord!(2, 1)], vec![2, 3, 3, 5, 6, 6, 5, 6, 6]);
+
+        let windows = grid.stencil_with_border(1, BorderPolicy::Wrap, |window| window.values().into_iter().cloned().collect::<Vec<_>>());
+        assert_eq!(windows[coord!(2, 1)], vec![2, 3, 1, 5, 6, 4, 2, 3, 1]);
+
+        let windows = grid.stencil_with_border(1, BorderPolicy::Mirror, |window| window.values().into_iter().cloned().collect::<Vec<_>>());
+        assert_eq!(windows[coord!(0, 1)], vec![2, 1, 2, 5, 4, 5, 2, 1, 2]);
+
+        let windows = grid.stencil_with_border(1, BorderPolicy::Constant(0), |window| window.values().into_iter().cloned().collect::<Vec<_>>());
+        assert_eq!(windows[coord!(1, 0)], vec![0, 0, 0, 1, 2, 3, 4, 5, 6]);
+
+        let windows = grid.stencil_with_border(1, BorderPolicy::Constant(0), |window| window.rect);
+        assert!(windows.all(|_, &rect| rect == rect!(0, 0, 3, 3)));
+
+        // Skipping the elements outside of the grid matches the clamped views.
+        let expected = grid.stencil(1, |window| window.values().into_iter().cloned().collect::<Vec<_>>());
+        let windows = grid.stencil_with_border(1, BorderPolicy::Skip, |window| window.iterator().cloned().collect::<Vec<_>>());
+        assert_eq!(windows, expected);
+
+        let whole = grid.stencil_with_border(usize::MAX, BorderPolicy::Skip, |window| window.size());
+        assert!(whole.all(|_, &size| size == grid.size()));
+
+        assert_eq!(Grid::<u8>::zero().stencil_with_border(1, BorderPolicy::Wrap, |_| 0).size(), size!(0, 0));
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use std::io::{self, BufRead, Write, ErrorKind};
+use std::string::String;
+use alloc::vec::Vec;
+use crate::grid::Grid;
+
+impl<T: Clone> Grid<T> {
+
+    /// Write the grid row by row.
+    ///
+    /// This method calls a function on each row of the grid, from top to
+    /// bottom, with the elements of the row and the writer. The function
+    /// writes the row in any format it likes, which allows to write huge grids
+    /// without building intermediate strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The writer to write the grid to
+    /// * `function` - The function writing a row
+    ///
+    /// # Errors
+    ///
+    /// It returns the first error returned by the function; the remaining
+    /// rows aren't written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// use std::io::Write;
+    ///
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 6]]);
+    ///
+    /// let mut output = Vec::new();
+    /// grid.write_rows(&mut output, |row, writer| {
+    ///     for value in row {
+    ///         write!(writer, "{} ", value)?;
+    ///     }
+    ///     writeln!(writer)
+    /// }).unwrap();
+    ///
+    /// assert_eq!(output, b"1 2 3 \n4 5 6 \n");
+    /// ```
+    ///
+    pub fn write_rows<W, F>(&self, mut writer: W, mut function: F) -> io::Result<()>
+        where W: Write, F: FnMut(&[T], &mut W) -> io::Result<()>
+    {
+        for row in self.row_vectors() {
+            function(row, &mut writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Read a grid line by line.
+    ///
+    /// This function reads a grid from a reader, one row per line, calling a
+    /// parser on each line to get the elements of the row. The line is
+    /// passed without its line terminator and the same buffer is reused for
+    /// all lines, which allows to read huge grids without building
+    /// intermediate strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The reader to read the grid from
+    /// * `parser` - The function parsing a line into the elements of a row
+    ///
+    /// # Errors
+    ///
+    /// It returns the first error returned by the reader or the parser, and
+    /// an error of kind `InvalidData` if the rows don't have the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// let input = "1 2 3\n4 5 6\n";
+    /// let grid = Grid::read_rows(input.as_bytes(), |line| {
+    ///     line.split_whitespace()
+    ///         .map(|word| word.parse().map_err(|_| Error::from(ErrorKind::InvalidData)))
+    ///         .collect::<Result<Vec<i32>, _>>()
+    /// }).unwrap();
+    ///
+    /// assert_eq!(grid.size(), size!(3, 2));
+    /// assert_eq!(grid[coord!(2, 1)], 6);
+    /// ```
+    ///
+    pub fn read_rows<R, F>(mut reader: R, mut parser: F) -> io::Result<Grid<T>>
+        where R: BufRead, F: FnMut(&str) -> io::Result<Vec<T>>
+    {
+        let mut rows = Vec::new();
+        let mut width = None;
+
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 0 {
+            let content = line.strip_suffix('\n').unwrap_or(&line);
+            let content = content.strip_suffix('\r').unwrap_or(content);
+
+            let row = parser(content)?;
+            if *width.get_or_insert(row.len()) != row.len() {
+                return Err(io::Error::new(ErrorKind::InvalidData, "rows don't have the same length"));
+            }
+
+            rows.push(row);
+            line.clear();
+        }
+
+        Ok(Grid::from_row_vectors(width.unwrap_or(0), rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+    use crate::size::Size;
+
+    // Parse a line of digits into a row.
+    fn digits(line: &str) -> io::Result<Vec<u32>> {
+        line.chars()
+            .map(|character| character.to_digit(10).ok_or_else(|| io::Error::from(ErrorKind::InvalidData)))
+            .collect()
+    }
+
+    #[test]
+    fn grid_write_rows() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3],
+                                        vec![4, 5, 6]]);
+
+        let mut output = Vec::new();
+        let mut count = 0;
+        grid.write_rows(&mut output, |row, writer| {
+            count += 1;
+            for value in row {
+                write!(writer, "{}", value)?;
+            }
+            writeln!(writer)
+        }).unwrap();
+
+        assert_eq!(count, 2);
+        assert_eq!(output, b"123\n456\n");
+
+        let result = grid.write_rows(Vec::new(), |_, _| {
+            Err(io::Error::other("failed"))
+        });
+        assert_eq!(result.unwrap_err().to_string(), "failed");
+    }
+
+    #[test]
+    fn grid_read_rows() {
+        let grid = Grid::read_rows("123\r\n456\n789".as_bytes(), digits).unwrap();
+        assert_eq!(grid.size(), size!(3, 3));
+        assert_eq!(grid.row(0).values(), vec![&1, &2, &3]);
+        assert_eq!(grid.row(1).values(), vec![&4, &5, &6]);
+        assert_eq!(grid.row(2).values(), vec![&7, &8, &9]);
+
+        let grid = Grid::read_rows("".as_bytes(), digits).unwrap();
+        assert_eq!(grid.size(), size!(0, 0));
+
+        let error = Grid::read_rows("12\n345\n".as_bytes(), digits).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "rows don't have the same length");
+
+        let error = Grid::read_rows("12\n3x\n".as_bytes(), digits).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn grid_write_read_rows() {
+        let grid = Grid::from_rows(vec![vec![1, 0, 2],
+                                        vec![3, 4, 0]]);
+
+        let mut output = Vec::new();
+        grid.write_rows(&mut output, |row, writer| {
+            for value in row {
+                write!(writer, "{}", value)?;
+            }
+            writeln!(writer)
+        }).unwrap();
+
+        assert_eq!(Grid::read_rows(output.as_slice(), digits).unwrap(), grid);
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use crate::grid::Grid;
+
+impl<T: Clone + PartialEq> Grid<T> {
+
+    /// Check whether the grid is horizontally symmetric.
+    ///
+    /// This method checks whether the grid is left unchanged when flipped
+    /// horizontally, that is, whether each row reads the same from both ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 1],
+    ///                                 vec![3, 4, 3]]);
+    /// assert!(grid.is_symmetric_horizontal());
+    /// assert!(!grid.is_symmetric_vertical());
+    /// ```
+    ///
+    pub fn is_symmetric_horizontal(&self) -> bool {
+        self.row_vectors().iter().all(|row| {
+            row.iter().eq(row.iter().rev())
+        })
+    }
+
+    /// Check whether the grid is vertically symmetric.
+    ///
+    /// This method checks whether the grid is left unchanged when flipped
+    /// vertically, that is, whether each column reads the same from both ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2],
+    ///                                 vec![3, 4],
+    ///                                 vec![1, 2]]);
+    /// assert!(grid.is_symmetric_vertical());
+    /// assert!(!grid.is_symmetric_horizontal());
+    /// ```
+    ///
+    pub fn is_symmetric_vertical(&self) -> bool {
+        let rows = self.row_vectors();
+        rows.iter().eq(rows.iter().rev())
+    }
+
+    /// Check whether the grid is rotationally symmetric.
+    ///
+    /// This method checks whether the grid is left unchanged when rotated by a
+    /// half-turn, that is, whether it reads the same from both ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::Grid;
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3],
+    ///                                 vec![4, 5, 4],
+    ///                                 vec![3, 2, 1]]);
+    /// assert!(grid.is_rotationally_symmetric());
+    /// assert!(!grid.is_symmetric_horizontal());
+    /// assert!(!grid.is_symmetric_vertical());
+    /// ```
+    ///
+    pub fn is_rotationally_symmetric(&self) -> bool {
+        let values = self.row_vectors().iter().flatten();
+        values.clone().eq(values.rev())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::size::Size;
+
+    #[test]
+    fn grid_is_symmetric_horizontal() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 2, 1],
+                                        vec![3, 4, 4, 3]]);
+        assert!(grid.is_symmetric_horizontal());
+
+        let grid = Grid::from_rows(vec![vec![1, 2, 1],
+                                        vec![3, 4, 5]]);
+        assert!(!grid.is_symmetric_horizontal());
+
+        assert!(Grid::<u8>::zero().is_symmetric_horizontal());
+    }
+
+    #[test]
+    fn grid_is_symmetric_vertical() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![1, 2]]);
+        assert!(grid.is_symmetric_vertical());
+
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4],
+                                        vec![1, 3]]);
+        assert!(!grid.is_symmetric_vertical());
+
+        assert!(Grid::<u8>::zero().is_symmetric_vertical());
+    }
+
+    #[test]
+    fn grid_is_rotationally_symmetric() {
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 4],
+                                        vec![2, 1]]);
+        assert!(!grid.is_rotationally_symmetric());
+
+        let grid = Grid::from_rows(vec![vec![1, 2],
+                                        vec![3, 3],
+                                        vec![2, 1]]);
+        assert!(grid.is_rotationally_symmetric());
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        assert!(grid.is_rotationally_symmetric());
+
+        grid[(0, 0)] = 1;
+        assert!(!grid.is_rotationally_symmetric());
+
+        grid[(2, 2)] = 1;
+        assert!(grid.is_rotationally_symmetric());
+    }
+}
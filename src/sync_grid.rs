@@ -0,0 +1,433 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, PoisonError};
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::rect::Rect;
+use crate::grid::Grid;
+use crate::grid_view_mut::GridViewMut;
+
+/// A grid that can be shared and updated across threads
+///
+/// This structure is a grid which is partitioned into bands of rows, each of
+/// them guarded by its own lock. Threads that work on different bands don't
+/// contend with each other, therefore, disjoint regions of the grid can be
+/// updated concurrently.
+///
+/// Elements are accessed individually with `read()` and `write()`, or by
+/// band with `read_band()` and `write_band()` which give access to the band
+/// as a grid or a mutable view of its own, locking it only once. Note that
+/// the coordinates within a band are relative to the band; use `band_rect()`
+/// to know which region of the grid a band covers.
+///
+/// Poisoned locks are recovered; if a thread panics while updating a band,
+/// the other threads still see the band as it was left. Bands can't be
+/// resized, so they're always consistent with the size of the grid.
+///
+/// # Examples
+///
+/// ```
+/// # use ingrid::{Coordinate, Size, Grid, SyncGrid, coord, size};
+/// #
+/// let grid = SyncGrid::new(Grid::with_size(size!(4, 4), 0), 2);
+///
+/// std::thread::scope(|scope| {
+///     for band in 0..grid.bands() {
+///         let grid = &grid;
+///         scope.spawn(move || {
+///             grid.write_band(band, |rows| rows.fill(band + 1));
+///         });
+///     }
+/// });
+///
+/// assert_eq!(grid.read(coord!(0, 1)), 1);
+/// assert_eq!(grid.read(coord!(3, 2)), 2);
+/// ```
+///
+#[derive(Debug)]
+pub struct SyncGrid<T> {
+    size: Size,
+    band_height: usize,
+    bands: Vec<RwLock<Grid<T>>>
+}
+
+impl<T: Clone> SyncGrid<T> {
+
+    /// Construct a concurrent grid from a grid.
+    ///
+    /// This function constructs a concurrent grid from a grid and the number
+    /// of rows per band; the last band may have fewer rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid to partition
+    /// * `band_height` - The number of rows per band
+    ///
+    /// # Panics
+    ///
+    /// It panics if the number of rows per band is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, SyncGrid, size};
+    /// #
+    /// let grid = SyncGrid::new(Grid::with_size(size!(3, 5), 0), 2);
+    ///
+    /// assert_eq!(grid.size(), size!(3, 5));
+    /// assert_eq!(grid.bands(), 3);
+    /// ```
+    ///
+    pub fn new(grid: Grid<T>, band_height: usize) -> SyncGrid<T> {
+        assert!(band_height > 0, "band height is zero");
+
+        let size = grid.size();
+
+        let mut rows = grid.into_row_vectors();
+        let mut bands = Vec::new();
+        while !rows.is_empty() {
+            let rest = rows.split_off(band_height.min(rows.len()));
+            bands.push(RwLock::new(Grid::from_row_vectors(size.width, rows)));
+            rows = rest;
+        }
+
+        SyncGrid { size, band_height, bands }
+    }
+
+    /// Return the size of the grid.
+    ///
+    /// This method returns the size of the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, SyncGrid, size};
+    /// #
+    /// let grid = SyncGrid::new(Grid::with_size(size!(2, 3), 0), 1);
+    /// assert_eq!(grid.size(), size!(2, 3));
+    /// ```
+    ///
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Return the number of bands.
+    ///
+    /// This method returns the number of bands the grid is partitioned into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, SyncGrid, size};
+    /// #
+    /// let grid = SyncGrid::new(Grid::with_size(size!(2, 4), 0), 2);
+    /// assert_eq!(grid.bands(), 2);
+    /// ```
+    ///
+    pub fn bands(&self) -> usize {
+        self.bands.len()
+    }
+
+    /// Return the region covered by a band.
+    ///
+    /// This method returns the region of the grid that is covered by a band.
+    ///
+    /// # Arguments
+    ///
+    /// * `band` - Index of the band
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Rect, Grid, SyncGrid, size, rect};
+    /// #
+    /// let grid = SyncGrid::new(Grid::with_size(size!(3, 5), 0), 2);
+    ///
+    /// assert_eq!(grid.band_rect(0), rect!(0, 0, 3, 2));
+    /// assert_eq!(grid.band_rect(2), rect!(0, 4, 3, 1));
+    /// ```
+    ///
+    pub fn band_rect(&self, band: usize) -> Rect {
+        assert!(band < self.bands.len(), "index out of bounds");
+
+        let top = band * self.band_height;
+        let height = self.band_height.min(self.size.height - top);
+
+        Rect::new(Coordinate::new(0, top), Size::new(self.size.width, height))
+    }
+
+    /// Return a copy of an element of the grid.
+    ///
+    /// This method returns a copy of an element of the grid from its
+    /// coordinate. It locks the band of the element for reading.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Grid, SyncGrid, coord};
+    /// #
+    /// let grid = SyncGrid::new(Grid::from_rows(vec![vec![1, 2],
+    ///                                               vec![3, 4]]), 1);
+    ///
+    /// assert_eq!(grid.read(coord!(1, 1)), 4);
+    /// ```
+    ///
+    pub fn read(&self, coordinate: Coordinate) -> T {
+        assert!(self.size.contains(coordinate), "index out of bounds");
+
+        let band = coordinate.y / self.band_height;
+        let row = coordinate.y % self.band_height;
+
+        self.lock_read(band).value(Coordinate::new(coordinate.x, row)).clone()
+    }
+
+    /// Replace an element of the grid.
+    ///
+    /// This method replaces the value of an element of the grid from its
+    /// coordinate and a new value. It locks the band of the element for
+    /// writing.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinate` - Coordinate of the element
+    /// * `value` - New value of the element
+    ///
+    /// # Panics
+    ///
+    /// It panics if the coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, SyncGrid, coord, size};
+    /// #
+    /// let grid = SyncGrid::new(Grid::with_size(size!(2, 2), 0), 1);
+    ///
+    /// grid.write(coord!(1, 0), 42);
+    /// assert_eq!(grid.read(coord!(1, 0)), 42);
+    /// ```
+    ///
+    pub fn write(&self, coordinate: Coordinate, value: T) {
+        assert!(self.size.contains(coordinate), "index out of bounds");
+
+        let band = coordinate.y / self.band_height;
+        let row = coordinate.y % self.band_height;
+
+        self.lock_write(band).set_value(Coordinate::new(coordinate.x, row), value);
+    }
+
+    /// Read a band of the grid.
+    ///
+    /// This method locks a band for reading and calls a closure with the band
+    /// as a grid. Coordinates within the band are relative to the band.
+    ///
+    /// # Arguments
+    ///
+    /// * `band` - Index of the band
+    /// * `function` - The closure to call with the band
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Grid, SyncGrid};
+    /// #
+    /// let grid = SyncGrid::new(Grid::from_rows(vec![vec![1, 2],
+    ///                                               vec![3, 4]]), 1);
+    ///
+    /// let sum: i32 = grid.read_band(1, |band| band.iterator().sum());
+    /// assert_eq!(sum, 7);
+    /// ```
+    ///
+    pub fn read_band<F, R>(&self, band: usize, function: F) -> R
+        where F: FnOnce(&Grid<T>) -> R
+    {
+        assert!(band < self.bands.len(), "index out of bounds");
+        function(&self.lock_read(band))
+    }
+
+    /// Update a band of the grid.
+    ///
+    /// This method locks a band for writing and calls a closure with a
+    /// mutable view onto the band. Coordinates within the band are relative to
+    /// the band.
+    ///
+    /// # Arguments
+    ///
+    /// * `band` - Index of the band
+    /// * `function` - The closure to call with the band
+    ///
+    /// # Panics
+    ///
+    /// It panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, SyncGrid, coord, size};
+    /// #
+    /// let grid = SyncGrid::new(Grid::with_size(size!(2, 4), 0), 2);
+    ///
+    /// grid.write_band(1, |band| band[coord!(0, 0)] = 42);
+    /// assert_eq!(grid.read(coord!(0, 2)), 42);
+    /// ```
+    ///
+    pub fn write_band<F, R>(&self, band: usize, function: F) -> R
+        where F: FnOnce(&mut GridViewMut<'_, T>) -> R
+    {
+        assert!(band < self.bands.len(), "index out of bounds");
+        function(&mut self.lock_write(band).view_mut())
+    }
+
+    /// Convert the concurrent grid into a regular grid.
+    ///
+    /// This method reassembles the bands into a regular grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Size, Grid, SyncGrid, size};
+    /// #
+    /// let grid = SyncGrid::new(Grid::with_size(size!(2, 3), 42), 2);
+    /// assert_eq!(grid.into_grid(), Grid::with_size(size!(2, 3), 42));
+    /// ```
+    ///
+    pub fn into_grid(self) -> Grid<T> {
+        let mut rows = Vec::with_capacity(self.size.height);
+        for band in self.bands {
+            let grid = band.into_inner().unwrap_or_else(PoisonError::into_inner);
+            rows.extend(grid.into_row_vectors());
+        }
+
+        Grid::from_row_vectors(self.size.width, rows)
+    }
+
+    fn lock_read(&self, band: usize) -> RwLockReadGuard<'_, Grid<T>> {
+        self.bands[band].read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn lock_write(&self, band: usize) -> RwLockWriteGuard<'_, Grid<T>> {
+        self.bands[band].write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use std::panic::{self, AssertUnwindSafe};
+    use alloc::string::String;
+
+    #[test]
+    fn sync_grid_new() {
+        let grid = SyncGrid::new(Grid::with_size(size!(3, 5), 0), 2);
+        assert_eq!(grid.bands(), 3);
+        assert_eq!(grid.band_rect(0), rect!(0, 0, 3, 2));
+        assert_eq!(grid.band_rect(1), rect!(0, 2, 3, 2));
+        assert_eq!(grid.band_rect(2), rect!(0, 4, 3, 1));
+
+        let grid = SyncGrid::new(Grid::with_size(size!(3, 2), 0), 5);
+        assert_eq!(grid.bands(), 1);
+        assert_eq!(grid.band_rect(0), rect!(0, 0, 3, 2));
+
+        let grid = SyncGrid::new(Grid::<i32>::zero(), 1);
+        assert_eq!(grid.bands(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "band height is zero")]
+    fn sync_grid_new_zero_band_height() {
+        SyncGrid::new(Grid::with_size(size!(2, 2), 0), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn sync_grid_read_write() {
+        let grid = SyncGrid::new(Grid::from_rows(vec![vec![1, 2],
+                                                      vec![3, 4],
+                                                      vec![5, 6]]), 2);
+
+        assert_eq!(grid.read(coord!(0, 0)), 1);
+        assert_eq!(grid.read(coord!(1, 1)), 4);
+        assert_eq!(grid.read(coord!(0, 2)), 5);
+
+        grid.write(coord!(1, 2), 42);
+        assert_eq!(grid.read(coord!(1, 2)), 42);
+
+        grid.write(coord!(2, 0), 42);
+    }
+
+    #[test]
+    fn sync_grid_write_band() {
+        let grid = SyncGrid::new(Grid::with_size(size!(2, 4), 0), 2);
+
+        grid.write_band(1, |band| band.fill(1));
+        assert_eq!(grid.read_band(1, |band| band.values().into_iter().sum::<i32>()), 4);
+        assert_eq!(grid.read_band(0, |band| band.values().into_iter().sum::<i32>()), 0);
+
+        assert_eq!(grid.write_band(1, |band| band.size()), size!(2, 2));
+    }
+
+    #[test]
+    fn sync_grid_write_band_panic() {
+        let grid = SyncGrid::new(Grid::with_size(size!(4, 4), String::from("ingrid")), 2);
+
+        // The band is left as it was when the closure panicked.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            grid.write_band(0, |band| {
+                band.set_value(coord!(3, 1), String::from("grid"));
+                panic!("oops");
+            })
+        }));
+        assert!(result.is_err());
+
+        assert_eq!(grid.read(coord!(3, 1)), "grid");
+
+        let grid = grid.into_grid();
+        assert_eq!(grid.size(), size!(4, 4));
+        assert_eq!(grid.column(3).iterator().filter(|value| *value == "ingrid").count(), 3);
+    }
+
+    #[test]
+    fn sync_grid_concurrent_writes() {
+        let grid = SyncGrid::new(Grid::with_size(size!(8, 8), 0), 2);
+
+        thread::scope(|scope| {
+            for band in 0..grid.bands() {
+                let grid = &grid;
+                scope.spawn(move || {
+                    for coordinate in grid.band_rect(band).coordinates() {
+                        grid.write(coordinate, coordinate.y * 8 + coordinate.x);
+                    }
+                });
+            }
+        });
+
+        let grid = grid.into_grid();
+        for coordinate in rect!(0, 0, 8, 8).coordinates() {
+            assert_eq!(grid[coordinate], coordinate.y * 8 + coordinate.x);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use core::mem;
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::size::Size;
+use crate::rect::Rect;
+use crate::grid::Grid;
+
+impl<T: Clone> Grid<T> {
+
+    /// Remove the border rows and columns made of background elements.
+    ///
+    /// This method removes the leading and trailing rows and columns whose
+    /// elements are all background elements, as told by a predicate. It
+    /// returns the coordinate of the remaining region in the original grid,
+    /// which is handy to relate both grids after parsing sparse inputs.
+    ///
+    /// If the grid is made of background elements only, it becomes empty and
+    /// the returned coordinate is the origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_background` - Predicate telling whether an element is a background element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Grid, coord, size};
+    /// #
+    /// let mut grid = Grid::from_rows(vec![vec!['.', '.', '.', '.'],
+    ///                                     vec!['.', '#', '.', '.'],
+    ///                                     vec!['.', '.', '#', '.']]);
+    ///
+    /// let offset = grid.trim(|&value| value == '.');
+    /// assert_eq!(offset, coord!(1, 1));
+    /// assert_eq!(grid.size(), size!(2, 2));
+    /// assert_eq!(grid.row(0).values(), vec![&'#', &'.']);
+    /// assert_eq!(grid.row(1).values(), vec![&'.', &'#']);
+    /// ```
+    ///
+    pub fn trim<F>(&mut self, mut is_background: F) -> Coordinate
+        where F: FnMut(&T) -> bool
+    {
+        let rect = match self.bounding_box(|value| !is_background(value)) {
+            Some(rect) => rect,
+            None => {
+                *self = Grid::zero();
+                return Coordinate::new(0, 0);
+            }
+        };
+
+        let (left, right) = (rect.coordinate.x, rect.coordinate.x + rect.size.width);
+        let rows = mem::take(self).into_row_vectors().into_iter()
+            .skip(rect.coordinate.y)
+            .take(rect.size.height)
+            .map(|mut row| {
+                row.truncate(right);
+                row.drain(..left);
+                row
+            })
+            .collect::<Vec<_>>();
+
+        *self = Grid::from_row_vectors(rect.size.width, rows);
+
+        rect.coordinate
+    }
+
+    /// Return the smallest region containing the matching elements.
+    ///
+    /// This method returns the smallest rectangle of the grid that contains all
+    /// the elements matching a predicate, or `None` if no element matches.
+    /// It's the usual first step to crop or center the content of a grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Predicate telling whether an element matches
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Size, Rect, Grid, rect};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec![0, 0, 0, 0],
+    ///                                 vec![0, 1, 0, 0],
+    ///                                 vec![0, 0, 1, 0]]);
+    ///
+    /// assert_eq!(grid.bounding_box(|&value| value == 1), Some(rect!(1, 1, 2, 2)));
+    /// assert_eq!(grid.bounding_box(|&value| value == 2), None);
+    /// ```
+    ///
+    pub fn bounding_box<F>(&self, mut predicate: F) -> Option<Rect>
+        where F: FnMut(&T) -> bool
+    {
+        let rows = self.row_vectors();
+
+        let top = rows.iter().position(|row| row.iter().any(&mut predicate))?;
+        let bottom = rows.iter().rposition(|row| row.iter().any(&mut predicate)).unwrap() + 1;
+
+        let mut left = self.size().width;
+        let mut right = 0;
+        for row in &rows[top..bottom] {
+            if let Some(x) = row.iter().position(&mut predicate) {
+                left = left.min(x);
+                right = right.max(row.iter().rposition(&mut predicate).unwrap() + 1);
+            }
+        }
+
+        Some(Rect::new(Coordinate::new(left, top), Size::new(right - left, bottom - top)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_trim() {
+        let mut grid = Grid::from_rows(vec![vec![0, 0, 0, 0, 0],
+                                            vec![0, 0, 1, 0, 0],
+                                            vec![0, 2, 0, 0, 0],
+                                            vec![0, 0, 0, 0, 0]]);
+
+        assert_eq!(grid.trim(|&value| value == 0), coord!(1, 1));
+        assert_eq!(grid.size(), size!(2, 2));
+        assert_eq!(grid.row(0).values(), vec![&0, &1]);
+        assert_eq!(grid.row(1).values(), vec![&2, &0]);
+
+        // Nothing to trim.
+        assert_eq!(grid.trim(|&value| value == 0), coord!(0, 0));
+        assert_eq!(grid.size(), size!(2, 2));
+
+        let mut grid = Grid::from_rows(vec![vec![0, 0],
+                                            vec![0, 0]]);
+
+        assert_eq!(grid.trim(|&value| value == 0), coord!(0, 0));
+        assert_eq!(grid.size(), size!(0, 0));
+
+        let mut grid = Grid::<u8>::zero();
+
+        assert_eq!(grid.trim(|&value| value == 0), coord!(0, 0));
+        assert_eq!(grid.size(), size!(0, 0));
+    }
+
+    #[test]
+    fn grid_bounding_box() {
+        let grid = Grid::from_rows(vec![vec![0, 0, 0, 0, 0],
+                                        vec![0, 0, 1, 0, 0],
+                                        vec![0, 1, 0, 0, 0],
+                                        vec![0, 0, 0, 1, 0]]);
+
+        assert_eq!(grid.bounding_box(|&value| value == 1), Some(rect!(1, 1, 3, 3)));
+        assert_eq!(grid.bounding_box(|&value| value == 0), Some(rect!(0, 0, 5, 4)));
+        assert_eq!(grid.bounding_box(|&value| value == 2), None);
+
+        let mut grid = Grid::with_size(size!(3, 3), 0);
+        grid[coord!(2, 0)] = 1;
+
+        assert_eq!(grid.bounding_box(|&value| value == 1), Some(rect!(2, 0, 1, 1)));
+        assert_eq!(Grid::<u8>::zero().bounding_box(|_| true), None);
+    }
+}
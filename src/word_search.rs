@@ -0,0 +1,167 @@
+// Copyright (c) 2020 - BytePlug
+//
+// This source file is part of Ingrid which is released under the MIT license.
+// Please refer to the LICENSE file that can be found at the root of the project
+// directory.
+//
+// Written by Jonathan De Wachter <dewachter.jonathan@gmail.com>, January 2020
+
+use alloc::vec::Vec;
+use crate::coordinate::Coordinate;
+use crate::offset::Offset;
+use crate::grid::Grid;
+use crate::grid_iterator::GridIterator;
+
+// The 8 directions a word can be read in, clockwise from the right.
+const DIRECTIONS: [Offset; 8] = [
+    Offset { x:  1, y:  0 },
+    Offset { x:  1, y:  1 },
+    Offset { x:  0, y:  1 },
+    Offset { x: -1, y:  1 },
+    Offset { x: -1, y:  0 },
+    Offset { x: -1, y: -1 },
+    Offset { x:  0, y: -1 },
+    Offset { x:  1, y: -1 }
+];
+
+impl Grid<char> {
+
+    /// Find the occurrences of a word.
+    ///
+    /// This method searches a grid of characters for a word, like in a word
+    /// search puzzle, and returns the coordinate of the first letter and the
+    /// direction of every occurrence. Words are read in all 8 directions;
+    /// horizontally, vertically and diagonally, forwards and backwards. The
+    /// directions are unit offsets, for instance `offset!(1, 0)` for a word
+    /// read from left to right.
+    ///
+    /// If wrapping is enabled, words continue on the opposite side of the grid
+    /// when they reach an edge.
+    ///
+    /// The occurrences are ordered by the coordinate of their first letter,
+    /// from left to right and top to bottom, then clockwise by direction,
+    /// starting from the right. A word of a single letter is reported once
+    /// per occurrence, with the right direction. An empty word has no
+    /// occurrence.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The word to search for
+    /// * `wrapping` - Whether words wrap around the edges of the grid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ingrid::{Coordinate, Offset, Grid, coord, offset};
+    /// #
+    /// let grid = Grid::from_rows(vec![vec!['c', 'a', 't'],
+    ///                                 vec!['a', 'a', 'x'],
+    ///                                 vec!['t', 'x', 'c']]);
+    ///
+    /// assert_eq!(grid.find_word("cat", false), vec![(coord!(0, 0), offset!(1, 0)),
+    ///                                               (coord!(0, 0), offset!(0, 1))]);
+    ///
+    /// // Reading down from the 'c' at the bottom right, wrapping to the top.
+    /// assert!(grid.find_word("ctx", true).contains(&(coord!(2, 2), offset!(0, 1))));
+    /// assert!(grid.find_word("ctx", false).is_empty());
+    /// ```
+    ///
+    pub fn find_word(&self, word: &str, wrapping: bool) -> Vec<(Coordinate, Offset)> {
+        let letters = word.chars().collect::<Vec<_>>();
+        let directions = if letters.len() == 1 { &DIRECTIONS[..1] } else { &DIRECTIONS[..] };
+
+        let mut occurrences = Vec::new();
+        if letters.is_empty() {
+            return occurrences;
+        }
+
+        for (coordinate, letter) in self.iterator().enumerate_coordinate() {
+            if *letter != letters[0] {
+                continue;
+            }
+
+            for &direction in directions {
+                if self.reads_word(coordinate, direction, &letters, wrapping) {
+                    occurrences.push((coordinate, direction));
+                }
+            }
+        }
+
+        occurrences
+    }
+
+    // Check whether the letters are read from a coordinate in a direction;
+    // the first letter is assumed to match already.
+    fn reads_word(&self, mut coordinate: Coordinate, direction: Offset, letters: &[char], wrapping: bool) -> bool {
+        let size = self.size();
+
+        for letter in &letters[1..] {
+            let next = if wrapping {
+                let x = (coordinate.x as isize + direction.x).rem_euclid(size.width as isize);
+                let y = (coordinate.y as isize + direction.y).rem_euclid(size.height as isize);
+                Some(Coordinate::new(x as usize, y as usize))
+            }
+            else {
+                coordinate.checked_apply(direction, size)
+            };
+
+            match next {
+                Some(next) if self[next] == *letter => coordinate = next,
+                _ => return false
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_find_word() {
+        let grid = Grid::from_rows(vec!["abc".chars().collect(),
+                                        "bbb".chars().collect(),
+                                        "cba".chars().collect()]);
+
+        assert_eq!(grid.find_word("abc", false), vec![(coord!(0, 0), offset!(1, 0)),
+                                                      (coord!(0, 0), offset!(0, 1)),
+                                                      (coord!(2, 2), offset!(-1, 0)),
+                                                      (coord!(2, 2), offset!(0, -1))]);
+
+        assert_eq!(grid.find_word("ab", false), vec![(coord!(0, 0), offset!(1, 0)),
+                                                     (coord!(0, 0), offset!(1, 1)),
+                                                     (coord!(0, 0), offset!(0, 1)),
+                                                     (coord!(2, 2), offset!(-1, 0)),
+                                                     (coord!(2, 2), offset!(-1, -1)),
+                                                     (coord!(2, 2), offset!(0, -1))]);
+
+        assert_eq!(grid.find_word("c", false), vec![(coord!(2, 0), offset!(1, 0)),
+                                                    (coord!(0, 2), offset!(1, 0))]);
+
+        assert!(grid.find_word("", false).is_empty());
+        assert!(grid.find_word("abcd", false).is_empty());
+        assert!(Grid::<char>::zero().find_word("a", false).is_empty());
+    }
+
+    #[test]
+    fn grid_find_word_wrapping() {
+        let grid = Grid::from_rows(vec!["xyz".chars().collect(),
+                                        "...".chars().collect()]);
+
+        assert!(grid.find_word("zx", false).is_empty());
+        assert_eq!(grid.find_word("zx", true), vec![(coord!(2, 0), offset!(1, 0))]);
+        assert_eq!(grid.find_word("xz", true), vec![(coord!(0, 0), offset!(-1, 0))]);
+
+        // With two rows, reading up or down wraps through the same cells.
+        assert_eq!(grid.find_word(".y.y", true), vec![(coord!(1, 1), offset!(0, 1)),
+                                                      (coord!(1, 1), offset!(0, -1))]);
+        assert_eq!(grid.find_word("x.", true), vec![(coord!(0, 0), offset!(1, 1)),
+                                                    (coord!(0, 0), offset!(0, 1)),
+                                                    (coord!(0, 0), offset!(-1, 1)),
+                                                    (coord!(0, 0), offset!(-1, -1)),
+                                                    (coord!(0, 0), offset!(0, -1)),
+                                                    (coord!(0, 0), offset!(1, -1))]);
+    }
+}